@@ -0,0 +1,78 @@
+#ifndef TEXT_CONVERTER_H
+#define TEXT_CONVERTER_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct TextConverterRegistry TextConverterRegistry;
+
+// Bytes allocated by the library, to be freed with [`text_converter_buffer_free`]
+//
+// They are UTF-8, and not NUL-terminated
+typedef struct TextConverterBuffer {
+  // Start of the bytes
+  uint8_t *data;
+  // Number of bytes
+  uintptr_t len;
+} TextConverterBuffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Creates a registry with all the built-in converters registered, see
+// [`ConverterRegistry::with_builtins`]
+//
+// It must be freed with [`text_converter_registry_free`]
+TextConverterRegistry *text_converter_registry_new(void);
+
+// Frees a registry created by [`text_converter_registry_new`]. Does nothing if it is null
+//
+// # Safety
+// `registry` must come from [`text_converter_registry_new`], and not be used anymore
+void text_converter_registry_free(TextConverterRegistry *registry);
+
+// Converts the UTF-8 `input` of `len` bytes with the converter registered under `name`
+//
+// # Safety
+// - `registry` must be a live registry from [`text_converter_registry_new`]
+// - `name` must be a NUL-terminated string
+// - `input` must point to `len` readable bytes, it may be null if `len` is 0
+// - `output` must point to a [`Buffer`] that can be written
+//
+// # Returns
+// `true` with the converted text written to `output`. `false` with an error message written
+// there instead if an argument is null or not UTF-8, no converter is registered under `name`,
+// or the conversion failed. Either way, `output` must then be freed with
+// [`text_converter_buffer_free`]
+bool text_converter_convert(const TextConverterRegistry *registry,
+                            const char *name,
+                            const uint8_t *input,
+                            uintptr_t len,
+                            TextConverterBuffer *output);
+
+// Writes the names of the converters in `registry` to `output`, one per line in alphabetical
+// order
+//
+// # Safety
+// - `registry` must be a live registry from [`text_converter_registry_new`]
+// - `output` must point to a [`Buffer`] that can be written
+//
+// # Returns
+// Same as [`text_converter_convert`]
+bool text_converter_registry_names(const TextConverterRegistry *registry,
+                                   TextConverterBuffer *output);
+
+// Frees a buffer written by this library. Does nothing if its data is null
+//
+// # Safety
+// `buffer` must have been written by this library, and not be used anymore
+void text_converter_buffer_free(TextConverterBuffer buffer);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* TEXT_CONVERTER_H */
@@ -0,0 +1,209 @@
+use crate::{atomic, BatchSummary, ConversionError, OutputOptions, TextConverter};
+use std::{
+    ffi::OsStr,
+    fs::File,
+    io::{Cursor, Read, Write},
+    path::{Path, PathBuf},
+    str,
+};
+use zip::{ZipArchive, ZipWriter};
+
+/// Most bytes reserved up front for an entry, since the size in its header cannot be trusted
+const MAX_PREALLOCATION: u64 = 1 << 20;
+
+/// Options for [`TextConverter::new_from_archive`]
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::ArchiveOptions;
+///
+/// let options = ArchiveOptions::new()
+///     .extensions(["md", "txt"])
+///     .output("docs_upper.zip");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ArchiveOptions {
+    extensions: Vec<String>,
+    output: Option<PathBuf>,
+}
+
+impl ArchiveOptions {
+    /// Converts every text entry, writing `originalname_converted.zip` next to the archive
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only converts entries with one of these extensions. All entries are converted if empty
+    pub fn extensions(mut self, extensions: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.extensions = extensions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Path of the new archive, instead of one next to the original
+    pub fn output(mut self, output: impl Into<PathBuf>) -> Self {
+        self.output = Some(output.into());
+        self
+    }
+
+    /// Path the new archive for `input` would be written to
+    pub fn output_path(&self, input: impl AsRef<Path>) -> PathBuf {
+        match &self.output {
+            Some(output) => output.clone(),
+            None => OutputOptions::new().keep_extension().output_path(input),
+        }
+    }
+
+    fn matches(&self, name: &Path) -> bool {
+        self.extensions.is_empty()
+            || name
+                .extension()
+                .and_then(OsStr::to_str)
+                .is_some_and(|ext| self.extensions.iter().any(|e| e == ext))
+    }
+}
+
+/// Implementation of [`TextConverter::new_from_archive`]
+pub(crate) fn convert_archive<C: TextConverter + ?Sized>(
+    converter: &C,
+    path: &Path,
+    options: &ArchiveOptions,
+) -> Result<BatchSummary, ConversionError> {
+    let mut archive = ZipArchive::new(File::open(path)?)?;
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    let mut summary = BatchSummary::default();
+
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let name = PathBuf::from(entry.name()?.as_ref());
+
+        if entry.is_dir() || !options.matches(&name) {
+            writer.raw_copy_file(entry)?;
+            summary.skipped.push(name);
+            continue;
+        }
+
+        let mut bytes = Vec::with_capacity(entry.size().min(MAX_PREALLOCATION) as usize);
+        let entry_options = entry.options();
+        let converted = entry
+            .read_to_end(&mut bytes)
+            .map_err(ConversionError::from)
+            .and_then(|_| convert_entry(converter, &bytes));
+        drop(entry);
+
+        match converted {
+            Ok(Some(output)) => {
+                writer.start_file(name.to_string_lossy(), entry_options)?;
+                writer.write_all(output.as_bytes())?;
+                summary.converted.push(name);
+            }
+            // Entries that are not text or fail to convert are kept as they were
+            Ok(None) => {
+                writer.raw_copy_file(archive.by_index(i)?)?;
+                summary.skipped.push(name);
+            }
+            Err(e) => {
+                writer.raw_copy_file(archive.by_index(i)?)?;
+                summary.failed.push((name, e));
+            }
+        }
+    }
+
+    let bytes = writer.finish()?.into_inner();
+    atomic::persist(&options.output_path(path), &bytes, true)?;
+
+    Ok(summary)
+}
+
+/// Output of `converter` for the entry, or `None` if the entry is not text
+fn convert_entry<C: TextConverter + ?Sized>(
+    converter: &C,
+    bytes: &[u8],
+) -> Result<Option<String>, ConversionError> {
+    // Only UTF-8 entries are converted, without their byte order mark. Checked apart from the
+    // conversion, so encoding errors of the converter itself still count as failures
+    let Ok(input) = str::from_utf8(bytes) else {
+        return Ok(None);
+    };
+    converter
+        .try_converter(input.strip_prefix('\u{feff}').unwrap_or(input))
+        .map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, Uppercase};
+    use zip::write::SimpleFileOptions;
+
+    #[test]
+    fn converts_matching_entries() {
+        let dir = fixtures::temp_dir("archive");
+        let path = dir.join("docs.zip");
+
+        let mut writer = ZipWriter::new(File::create(&path).unwrap());
+        writer
+            .add_directory("guide/", SimpleFileOptions::default())
+            .unwrap();
+        for (name, contents) in [
+            ("guide/intro.md", &b"# intro"[..]),
+            ("notes.txt", b"notes"),
+            ("logo.md", b"\xff\xd8"),
+        ] {
+            writer
+                .start_file(name, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(contents).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let options = ArchiveOptions::new().extensions(["md"]);
+        let summary = convert_archive(&Uppercase, &path, &options).unwrap();
+        assert_eq!(vec![PathBuf::from("guide/intro.md")], summary.converted);
+        assert_eq!(3, summary.skipped.len());
+
+        let mut converted =
+            ZipArchive::new(File::open(dir.join("docs_converted.zip")).unwrap()).unwrap();
+        assert_eq!(4, converted.len());
+        let read = |archive: &mut ZipArchive<File>, name| {
+            let mut contents = Vec::new();
+            archive
+                .by_name(name)
+                .unwrap()
+                .read_to_end(&mut contents)
+                .unwrap();
+            contents
+        };
+        assert_eq!(b"# INTRO", &read(&mut converted, "guide/intro.md")[..]);
+        assert_eq!(b"notes", &read(&mut converted, "notes.txt")[..]);
+        assert_eq!(b"\xff\xd8", &read(&mut converted, "logo.md")[..]);
+    }
+
+    #[cfg(feature = "converters")]
+    #[test]
+    fn converter_encoding_errors_fail() {
+        let dir = fixtures::temp_dir("archive_decode");
+        let path = dir.join("encoded.zip");
+
+        let mut writer = ZipWriter::new(File::create(&path).unwrap());
+        // `/w==` decodes to 0xff, which is not UTF-8
+        for (name, contents) in [
+            ("a.b64", &b"aGk="[..]),
+            ("b.b64", b"/w=="),
+            ("c.b64", b"\xff"),
+        ] {
+            writer
+                .start_file(name, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(contents).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let decoder = crate::converters::Base64Decode::default();
+        let summary = convert_archive(&decoder, &path, &ArchiveOptions::new()).unwrap();
+        assert_eq!(vec![PathBuf::from("a.b64")], summary.converted);
+        assert_eq!(vec![PathBuf::from("c.b64")], summary.skipped);
+        assert_eq!(PathBuf::from("b.b64"), summary.failed[0].0);
+        assert!(matches!(summary.failed[0].1, ConversionError::Encoding(_)));
+    }
+}
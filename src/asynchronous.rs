@@ -0,0 +1,201 @@
+//! Async input sources for tokio users
+//!
+//! Enabled by the `tokio` feature
+
+#[cfg(feature = "clipboard")]
+use crate::TextClipboard;
+use crate::{write_atomic, Bom, ConversionError, OutputOptions, TextConverter};
+use std::{future::Future, io, path::Path};
+use tokio::{fs, task};
+
+/// Async versions of the [`TextConverter`] input sources, for use inside a tokio runtime
+///
+/// Automatically implemented for every [`TextConverter`] that is [`Sync`]. File IO goes through
+/// [`tokio::fs`] and clipboard access runs on the blocking thread pool, so the executor is never
+/// blocked. The conversion itself still runs on the calling task
+///
+/// Method names match the ones from [`TextConverter`], which is why this trait is not re-exported
+/// from the crate root. Only import one of the two traits in a given scope, or call the methods
+/// with fully qualified syntax
+///
+/// # Examples
+///
+/// ```no_run
+/// use text_converter::{asynchronous::AsyncTextConverter, converters::Uppercase};
+///
+/// # async fn run() -> Result<(), text_converter::ConversionError> {
+/// let shout = Uppercase.try_new_from_file("input.txt").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait AsyncTextConverter: TextConverter + Sync {
+    /// Fetches clipboard contents and converts them with the
+    /// [converter](TextConverter::converter()) method
+    ///
+    /// Will return an empty string if the clipboard contains something other than text
+    ///
+    /// # Panics
+    /// Will panic if it fails to fetch the clipboard
+    #[cfg(feature = "clipboard")]
+    fn new_from_clipboard(&self) -> impl Future<Output = String> + Send {
+        async move {
+            let input = task::spawn_blocking(|| {
+                let mut clipboard =
+                    TextClipboard::new().expect("Could not fetch the clipboard contents");
+                clipboard.get_text().unwrap_or_default()
+            })
+            .await
+            .expect("Clipboard task failed");
+
+            self.converter(input)
+        }
+    }
+
+    /// Fetches clipboard contents and converts them with the
+    /// [try_converter](TextConverter::try_converter()) method
+    ///
+    /// # Errors
+    /// - [`ConversionError::ClipboardUnavailable`] if there is no clipboard, see [`TextClipboard`]
+    /// - [`ConversionError::Clipboard`] if the clipboard does not contain text
+    /// - Whatever error the converter reports
+    #[cfg(feature = "clipboard")]
+    fn try_new_from_clipboard(
+        &self,
+    ) -> impl Future<Output = Result<String, ConversionError>> + Send {
+        async move {
+            let input = task::spawn_blocking(|| TextClipboard::new()?.get_text())
+                .await
+                .map_err(io::Error::other)??;
+
+            self.try_converter(input)
+        }
+    }
+
+    /// Converts the clipboard contents with the [try_converter](TextConverter::try_converter())
+    /// method and puts the result back into the clipboard
+    ///
+    /// # Errors
+    /// - [`ConversionError::ClipboardUnavailable`] if there is no clipboard, see [`TextClipboard`]
+    /// - [`ConversionError::Clipboard`] if the clipboard does not contain text or cannot be
+    ///   written to
+    /// - Whatever error the converter reports
+    #[cfg(feature = "clipboard")]
+    fn try_convert_clipboard_in_place(
+        &self,
+    ) -> impl Future<Output = Result<String, ConversionError>> + Send {
+        async move {
+            let input = task::spawn_blocking(|| TextClipboard::new()?.get_text())
+                .await
+                .map_err(io::Error::other)??;
+            let output = self.try_converter(input)?;
+
+            let text = output.clone();
+            task::spawn_blocking(move || TextClipboard::new()?.set_text(text))
+                .await
+                .map_err(io::Error::other)??;
+
+            Ok(output)
+        }
+    }
+
+    /// Fetches file contents and converts them with the [converter](TextConverter::converter())
+    /// method
+    ///
+    /// # Panics
+    /// - If file is inaccessible or if it is not in text format (.txt, .md...)
+    /// - If it fails to create the output file
+    ///
+    /// # Returns
+    /// - The conversion string from the file contents
+    /// - Outputs the conversion next to the original, in a file called originalname_converted
+    ///   with the original extension, unless the converter has an
+    ///   [output extension](TextConverter::output_extension())
+    fn new_from_file(&self, path: impl AsRef<Path> + Send) -> impl Future<Output = String> + Send {
+        async move {
+            let bytes = fs::read(path.as_ref())
+                .await
+                .expect("Failed to read file contents");
+            let (input, _) = Bom::decode(&bytes).expect("Failed to decode file contents");
+            let output = self.converter(input);
+
+            let output_path = OutputOptions::new()
+                .for_converter(self)
+                .output_path(path.as_ref());
+            let bytes = output.clone().into_bytes();
+            task::spawn_blocking(move || write_atomic(output_path, bytes))
+                .await
+                .expect("Writing task failed")
+                .expect("Failed to write to the output file");
+
+            output
+        }
+    }
+
+    /// Fetches file contents and converts them with the
+    /// [try_converter](TextConverter::try_converter()) method
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if the file is inaccessible or the output file cannot be written
+    /// - [`ConversionError::Encoding`] if the file is not valid UTF-8
+    /// - Whatever error the converter reports
+    ///
+    /// # Returns
+    /// - The conversion string from the file contents
+    /// - Outputs the conversion next to the original, in a file called originalname_converted
+    ///   with the original extension, unless the converter has an
+    ///   [output extension](TextConverter::output_extension())
+    fn try_new_from_file(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = Result<String, ConversionError>> + Send {
+        async move {
+            let bytes = fs::read(path.as_ref()).await?;
+            let (input, _) = Bom::decode(&bytes)?;
+            let output = self.try_converter(input)?;
+
+            let output_path = OutputOptions::new()
+                .for_converter(self)
+                .output_path(path.as_ref());
+            let bytes = output.clone().into_bytes();
+            task::spawn_blocking(move || write_atomic(output_path, bytes))
+                .await
+                .map_err(io::Error::other)??;
+
+            Ok(output)
+        }
+    }
+}
+
+impl<T: TextConverter + Sync + ?Sized> AsyncTextConverter for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    struct Exclaim;
+
+    impl TextConverter for Exclaim {
+        fn converter(&self, input: impl AsRef<str>) -> String {
+            input.as_ref().to_owned() + "!"
+        }
+    }
+
+    #[tokio::test]
+    async fn async_conversion_from_file() {
+        let path = env::temp_dir().join("text_converter_async_exclaim.txt");
+        fs::write(&path, "hello").await.unwrap();
+
+        // `TextConverter` is in scope too, so this needs fully qualified syntax
+        let output = AsyncTextConverter::try_new_from_file(&Exclaim, &path)
+            .await
+            .unwrap();
+        assert_eq!("hello!", output);
+        assert_eq!(
+            output,
+            fs::read_to_string(OutputOptions::new().output_path(&path))
+                .await
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{trace::timed, ConversionError};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, ErrorKind, Write},
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Writes `contents` to the file at `path` so that it never holds a partial write, replacing the
+/// file if it exists
+///
+/// The contents go to a temporary file in the same directory first, which is flushed to disk and
+/// then renamed over `path`. After a crash, `path` holds either its old contents or the new ones.
+/// A file that is replaced keeps its permissions
+///
+/// # Errors
+/// [`ConversionError::Io`] if the temporary file cannot be written or renamed. The temporary
+/// file is removed then
+///
+/// # Examples
+///
+/// ```no_run
+/// text_converter::write_atomic("notes_converted.md", "# Notes\n").unwrap();
+/// ```
+pub fn write_atomic(
+    path: impl AsRef<Path>,
+    contents: impl AsRef<[u8]>,
+) -> Result<(), ConversionError> {
+    Ok(persist(path.as_ref(), contents.as_ref(), true)?)
+}
+
+/// Same as [`write_atomic`], failing with [`ErrorKind::AlreadyExists`] instead of replacing an
+/// existing file unless `replace` is set
+pub(crate) fn persist(path: &Path, contents: &[u8], replace: bool) -> io::Result<()> {
+    timed!("write_file", path = %path.display(), bytes = contents.len());
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let (temp_path, mut temp) = temp_file(dir, path)?;
+
+    let result = (|| {
+        if let Ok(metadata) = fs::metadata(path) {
+            temp.set_permissions(metadata.permissions())?;
+        }
+        temp.write_all(contents)?;
+        temp.sync_all()?;
+        // Files have nothing to close on WebAssembly
+        #[cfg_attr(target_arch = "wasm32", allow(clippy::drop_non_drop))]
+        drop(temp);
+
+        match replace {
+            true => fs::rename(&temp_path, path),
+            // Linking fails if the file exists, which renaming would not
+            false => match fs::hard_link(&temp_path, path) {
+                Err(e) if e.kind() != ErrorKind::AlreadyExists && !path.exists() => {
+                    fs::rename(&temp_path, path)
+                }
+                linked => linked.and_then(|()| fs::remove_file(&temp_path)),
+            },
+        }
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result?;
+
+    // Makes the rename itself durable. Directories cannot be opened as files on every platform
+    #[cfg(unix)]
+    File::open(dir)?.sync_all()?;
+
+    Ok(())
+}
+
+/// Creates a new hidden file next to `path`, named after it
+fn temp_file(dir: &Path, path: &Path) -> io::Result<(PathBuf, File)> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+
+    loop {
+        let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let temp_path = dir.join(format!(".{name}.{}.{count}.tmp", process::id()));
+
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&temp_path)
+        {
+            Ok(file) => return Ok((temp_path, file)),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn replaces_without_leftovers() {
+        let dir = env::temp_dir().join("text_converter_atomic");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("output.txt");
+
+        write_atomic(&path, "first").unwrap();
+        write_atomic(&path, "second").unwrap();
+        assert_eq!("second", fs::read_to_string(&path).unwrap());
+
+        let error = persist(&path, b"third", false).unwrap_err();
+        assert_eq!(ErrorKind::AlreadyExists, error.kind());
+        assert_eq!("second", fs::read_to_string(&path).unwrap());
+
+        persist(&dir.join("new.txt"), b"new", false).unwrap();
+        let mut names: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        names.sort();
+        assert_eq!(vec!["new.txt", "output.txt"], names);
+    }
+}
@@ -0,0 +1,525 @@
+use crate::{
+    observer::{self, Observers},
+    output,
+    progress::Tracker,
+    trace, write_atomic, Bom, CancellationToken, Conversion, ConversionError, ConversionObserver,
+    OutputOptions, PlannedWrite, Progress, TextConverter,
+};
+use std::{
+    ffi::OsStr,
+    fmt, fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// Options for [`TextConverter::new_from_dir`]
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::DirOptions;
+///
+/// let options = DirOptions::new()
+///     .extensions(["txt", "md"])
+///     .recursive(false)
+///     .output_dir("converted");
+/// ```
+#[derive(Clone)]
+pub struct DirOptions {
+    extensions: Vec<String>,
+    recursive: bool,
+    output_dir: Option<PathBuf>,
+    dry_run: bool,
+    cancellation: Option<CancellationToken>,
+    observers: Observers,
+}
+
+impl DirOptions {
+    /// Converts every file, walking subdirectories, with outputs written next to the originals
+    pub fn new() -> Self {
+        Self {
+            extensions: Vec::new(),
+            recursive: true,
+            output_dir: None,
+            dry_run: false,
+            cancellation: None,
+            observers: Vec::new(),
+        }
+    }
+
+    /// Only converts files with one of these extensions. All files are converted if empty
+    pub fn extensions(mut self, extensions: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.extensions = extensions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Whether to walk subdirectories too
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Writes the outputs into this directory instead, mirroring the layout of the input
+    /// directory and keeping the original file names, with the
+    /// [extension of the converter](TextConverter::output_extension()) if it has one
+    pub fn output_dir(mut self, output_dir: impl Into<PathBuf>) -> Self {
+        self.output_dir = Some(output_dir.into());
+        self
+    }
+
+    /// Converts the files in memory without writing anything, listing what would be written in
+    /// [`BatchSummary::planned`] instead
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Stops the batch before the next file once `token` is cancelled. Outputs written until
+    /// then are kept
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Notifies `observer` as each file is converted: it starts with the contents of the file,
+    /// and ends with the path of the output written or with the error that made the file fail.
+    /// Files that are skipped are not reported
+    pub fn observe(mut self, observer: impl ConversionObserver + 'static) -> Self {
+        self.observers.push(Arc::new(observer));
+        self
+    }
+
+    pub(crate) fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        let is_output = OutputOptions::new().is_output(path);
+        let has_extension = self.extensions.is_empty()
+            || path
+                .extension()
+                .and_then(OsStr::to_str)
+                .is_some_and(|ext| self.extensions.iter().any(|e| e == ext));
+
+        !is_output && has_extension
+    }
+}
+
+impl fmt::Debug for DirOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DirOptions")
+            .field("extensions", &self.extensions)
+            .field("recursive", &self.recursive)
+            .field("output_dir", &self.output_dir)
+            .field("dry_run", &self.dry_run)
+            .field("cancellation", &self.cancellation)
+            .field("observers", &self.observers.len())
+            .finish()
+    }
+}
+
+impl Default for DirOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What happened to each file during [`TextConverter::new_from_dir`]
+#[derive(Debug, Default)]
+pub struct BatchSummary {
+    /// Files that were converted, or would be on a dry run
+    pub converted: Vec<PathBuf>,
+    /// Files that were left alone, because they did not match the options, are outputs of a
+    /// previous conversion, or are not valid UTF-8 text, or UTF-16 text after a
+    /// [byte order mark](Bom). Symbolic links to directories come first, as they are never
+    /// walked into
+    pub skipped: Vec<PathBuf>,
+    /// Files that could not be converted, with the reason why
+    pub failed: Vec<(PathBuf, ConversionError)>,
+    /// Outputs a [dry run](DirOptions::dry_run) would have written, empty otherwise
+    pub planned: Vec<PlannedWrite>,
+}
+
+/// Implementation of [`TextConverter::new_from_dir_with_progress`]
+pub(crate) fn convert_dir<C: TextConverter + ?Sized>(
+    converter: &C,
+    dir: &Path,
+    options: &DirOptions,
+    progress: impl FnMut(Progress),
+) -> Result<BatchSummary, ConversionError> {
+    // Collected up front so outputs written next to the originals are never walked into
+    let mut files = Vec::new();
+    let mut links = Vec::new();
+    collect_files(dir, options.recursive, &mut files, &mut links)?;
+    files.sort();
+    links.sort();
+
+    let sizes: Vec<_> = files
+        .iter()
+        .map(|file| match options.matches(file) {
+            true => fs::metadata(file).map_or(0, |metadata| metadata.len()),
+            false => 0,
+        })
+        .collect();
+    let mut tracker = Tracker::new(Some(sizes.iter().sum()), Some(files.len()), progress);
+    let mut summary = BatchSummary {
+        skipped: links,
+        ..BatchSummary::default()
+    };
+
+    for (file, size) in files.into_iter().zip(sizes) {
+        if let Some(token) = &options.cancellation {
+            token.check()?;
+        }
+        if !options.matches(&file) {
+            summary.skipped.push(file);
+            tracker.advance(0, 1);
+            continue;
+        }
+
+        match convert_file(converter, dir, &file, options) {
+            Ok(FileOutcome::Converted(planned)) => {
+                summary.converted.push(file);
+                summary.planned.extend(planned);
+            }
+            Ok(FileOutcome::NotText) => summary.skipped.push(file),
+            Err(e) => {
+                observer::notify(&options.observers, |o| o.on_error(&e));
+                summary.failed.push((file, e));
+            }
+        }
+        tracker.advance(size, 1);
+    }
+
+    Ok(summary)
+}
+
+/// Files in `dir` that [`convert_dir`] would convert, with the output it would write for each
+pub(crate) fn planned_outputs<C: TextConverter + ?Sized>(
+    converter: &C,
+    dir: &Path,
+    options: &DirOptions,
+) -> Result<Vec<(PathBuf, PathBuf)>, ConversionError> {
+    let mut files = Vec::new();
+    collect_files(dir, options.recursive, &mut files, &mut Vec::new())?;
+    files.retain(|file| options.matches(file));
+
+    Ok(files
+        .into_iter()
+        .map(|file| {
+            let output = output_path(converter, dir, &file, options);
+            (file, output)
+        })
+        .collect())
+}
+
+fn output_path<C: TextConverter + ?Sized>(
+    converter: &C,
+    dir: &Path,
+    file: &Path,
+    options: &DirOptions,
+) -> PathBuf {
+    let output = OutputOptions::new().for_converter(converter);
+    match &options.output_dir {
+        Some(output_dir) => {
+            let relative_parent = file
+                .strip_prefix(dir)
+                .unwrap_or(file)
+                .parent()
+                .unwrap_or(Path::new(""));
+            output
+                .suffix("")
+                .dir(output_dir.join(relative_parent))
+                .output_path(file)
+        }
+        None => output.output_path(file),
+    }
+}
+
+/// Adds the files in `dir` to `files`, and the links to directories it would have walked into
+/// to `links`, since following them could loop forever
+fn collect_files(
+    dir: &Path,
+    recursive: bool,
+    files: &mut Vec<PathBuf>,
+    links: &mut Vec<PathBuf>,
+) -> Result<(), ConversionError> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let path = entry.path();
+
+        if file_type.is_dir() {
+            if recursive {
+                collect_files(&path, recursive, files, links)?;
+            }
+        } else if file_type.is_symlink() && path.is_dir() {
+            if recursive {
+                links.push(path);
+            }
+        } else {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// What [`convert_file`] did with a file
+enum FileOutcome {
+    /// Converted, with the write planned instead on a dry run
+    Converted(Option<PlannedWrite>),
+    /// Left alone for not being text the [byte order mark](Bom) tells the encoding of
+    NotText,
+}
+
+fn convert_file<C: TextConverter + ?Sized>(
+    converter: &C,
+    dir: &Path,
+    file: &Path,
+    options: &DirOptions,
+) -> Result<FileOutcome, ConversionError> {
+    let bytes = trace::read_file(file)?;
+    // Decoded apart from the conversion, so encoding errors of the converter itself still count
+    // as failures
+    let Ok((input, bom)) = Bom::decode(&bytes) else {
+        return Ok(FileOutcome::NotText);
+    };
+    observer::notify(&options.observers, |o| o.on_start(&input));
+    let output = converter.try_converter(&input)?;
+
+    let output_path = output_path(converter, dir, file, options);
+
+    if options.dry_run {
+        let size = output::with_bom(&output, bom).len();
+        return Ok(FileOutcome::Converted(Some(PlannedWrite::new(
+            output_path,
+            size,
+            Conversion::new(input, output),
+        ))));
+    }
+
+    if let Some(parent) = output_path
+        .parent()
+        .filter(|_| options.output_dir.is_some())
+    {
+        fs::create_dir_all(parent)?;
+    }
+    // Written in the encoding of the input, starting with the same mark
+    write_atomic(&output_path, output::with_bom(&output, bom))?;
+    observer::notify(&options.observers, |o| o.on_output_written(&output_path));
+    Ok(FileOutcome::Converted(None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, Uppercase};
+    use std::sync::Mutex;
+
+    #[test]
+    fn converts_matching_files() {
+        let dir = fixtures::temp_dir("batch");
+        let output_dir = fixtures::temp_dir("batch_output");
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("a.txt"), "a").unwrap();
+        fs::write(dir.join("image.png"), [0x89, 0x50]).unwrap();
+        fs::write(dir.join("binary.txt"), [0xff, 0x00]).unwrap();
+        fs::write(dir.join("nested/b.txt"), "b").unwrap();
+        fs::write(dir.join("nested/c.txt"), Bom::Utf16Le.encode("c")).unwrap();
+
+        let options = DirOptions::new()
+            .extensions(["txt"])
+            .output_dir(&output_dir);
+        let summary = Uppercase.new_from_dir(&dir, &options).unwrap();
+
+        assert_eq!(
+            vec![
+                dir.join("a.txt"),
+                dir.join("nested/b.txt"),
+                dir.join("nested/c.txt")
+            ],
+            summary.converted
+        );
+        assert_eq!(
+            vec![dir.join("binary.txt"), dir.join("image.png")],
+            summary.skipped
+        );
+        assert!(summary.failed.is_empty());
+        assert_eq!(
+            "B",
+            fs::read_to_string(output_dir.join("nested/b.txt")).unwrap()
+        );
+        assert_eq!(
+            Bom::Utf16Le.encode("C"),
+            fs::read(output_dir.join("nested/c.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn dry_run_writes_nothing() {
+        let dir = fixtures::temp_dir("batch_dry_run");
+        let output_dir = dir.with_extension("output");
+        fs::write(dir.join("a.txt"), "a\n").unwrap();
+
+        let options = DirOptions::new().output_dir(&output_dir).dry_run(true);
+        let summary = Uppercase.new_from_dir(&dir, &options).unwrap();
+
+        assert_eq!(vec![dir.join("a.txt")], summary.converted);
+        let planned = &summary.planned[0];
+        assert_eq!(
+            (output_dir.join("a.txt"), 2),
+            (planned.path.clone(), planned.size)
+        );
+        assert_eq!("A\n", planned.conversion.output);
+        assert!(!output_dir.exists());
+    }
+
+    #[cfg(feature = "converters")]
+    #[test]
+    fn output_dir_uses_converter_extension() {
+        let dir = fixtures::temp_dir("batch_extension");
+        let output_dir = dir.join("out");
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("nested/a.txt"), "a").unwrap();
+
+        let options = DirOptions::new().output_dir(&output_dir).dry_run(true);
+        let summary = crate::converters::HexDump::default()
+            .new_from_dir(&dir, &options)
+            .unwrap();
+
+        assert_eq!(output_dir.join("nested/a.hex"), summary.planned[0].path);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn skips_linked_directories() {
+        let dir = fixtures::temp_dir("batch_links");
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("nested/a.txt"), "a").unwrap();
+        // Walking into it would never end
+        std::os::unix::fs::symlink(&dir, dir.join("nested/loop")).unwrap();
+
+        let options = DirOptions::new().dry_run(true);
+        let summary = Uppercase.new_from_dir(&dir, &options).unwrap();
+
+        assert_eq!(vec![dir.join("nested/a.txt")], summary.converted);
+        assert_eq!(vec![dir.join("nested/loop")], summary.skipped);
+    }
+
+    #[test]
+    fn reports_progress() {
+        let dir = fixtures::temp_dir("batch_progress");
+        fs::write(dir.join("a.txt"), "aaa").unwrap();
+        fs::write(dir.join("b.png"), "b").unwrap();
+
+        let mut reports = Vec::new();
+        let options = DirOptions::new().extensions(["txt"]).dry_run(true);
+        Uppercase
+            .new_from_dir_with_progress(&dir, &options, |progress| reports.push(progress))
+            .unwrap();
+
+        let done: Vec<_> = reports
+            .iter()
+            .map(|p| (p.bytes_processed, p.files_completed))
+            .collect();
+        assert_eq!(vec![(3, 1), (3, 2)], done);
+        assert_eq!(
+            (Some(3), Some(2)),
+            (reports[0].total_bytes, reports[0].total_files)
+        );
+    }
+
+    #[test]
+    fn stops_when_cancelled() {
+        let dir = fixtures::temp_dir("batch_cancel");
+        fs::write(dir.join("a.txt"), "a").unwrap();
+        fs::write(dir.join("b.txt"), "b").unwrap();
+
+        let token = CancellationToken::new();
+        let options = DirOptions::new().cancellation(token.clone());
+        let result = Uppercase.new_from_dir_with_progress(&dir, &options, |_| token.cancel());
+
+        assert!(matches!(result, Err(ConversionError::Cancelled)));
+        assert!(dir.join("a_converted.txt").exists());
+        assert!(!dir.join("b_converted.txt").exists());
+    }
+
+    #[test]
+    fn skips_previous_outputs() {
+        let dir = fixtures::temp_dir("batch_rerun");
+        fs::write(dir.join("a.txt"), "a").unwrap();
+
+        let options = DirOptions::new().recursive(false);
+        let first = Uppercase.new_from_dir(&dir, &options).unwrap();
+        let second = Uppercase.new_from_dir(&dir, &options).unwrap();
+
+        assert_eq!(1, first.converted.len());
+        assert_eq!(1, second.converted.len());
+        assert_eq!(vec![dir.join("a_converted.txt")], second.skipped);
+    }
+
+    #[derive(Default)]
+    struct Events(Mutex<Vec<String>>);
+
+    impl ConversionObserver for Events {
+        fn on_start(&self, input: &str) {
+            self.0.lock().unwrap().push(format!("start {input}"));
+        }
+
+        fn on_error(&self, _error: &ConversionError) {
+            self.0.lock().unwrap().push("error".to_owned());
+        }
+
+        fn on_output_written(&self, path: &Path) {
+            let name = path.file_name().unwrap().to_string_lossy();
+            self.0.lock().unwrap().push(format!("wrote {name}"));
+        }
+    }
+
+    #[test]
+    fn notifies_observers() {
+        let dir = fixtures::temp_dir("batch_observer");
+        fs::write(dir.join("a.txt"), "a").unwrap();
+        // The output path is taken by a directory, so writing it fails
+        fs::create_dir_all(dir.join("b_converted.txt")).unwrap();
+        fs::write(dir.join("b.txt"), "b").unwrap();
+
+        let events = Arc::new(Events::default());
+        let options = DirOptions::new()
+            .recursive(false)
+            .observe(Arc::clone(&events));
+        let summary = Uppercase.new_from_dir(&dir, &options).unwrap();
+
+        assert_eq!(1, summary.failed.len());
+        assert_eq!(
+            vec!["start a", "wrote a_converted.txt", "start b", "error"],
+            *events.0.lock().unwrap()
+        );
+    }
+
+    #[cfg(feature = "converters")]
+    #[test]
+    fn converter_encoding_errors_fail() {
+        let dir = fixtures::temp_dir("batch_decode");
+        fs::write(dir.join("a.b64"), "aGk=").unwrap();
+        // Decodes to 0xff, which is not UTF-8
+        fs::write(dir.join("b.b64"), "/w==").unwrap();
+        fs::write(dir.join("c.b64"), [0xff]).unwrap();
+
+        let events = Arc::new(Events::default());
+        let options = DirOptions::new().dry_run(true).observe(Arc::clone(&events));
+        let summary = crate::converters::Base64Decode::default()
+            .new_from_dir(&dir, &options)
+            .unwrap();
+
+        assert_eq!(vec![dir.join("a.b64")], summary.converted);
+        assert_eq!(vec![dir.join("c.b64")], summary.skipped);
+        assert_eq!(dir.join("b.b64"), summary.failed[0].0);
+        assert!(matches!(summary.failed[0].1, ConversionError::Encoding(_)));
+        assert_eq!(
+            vec!["start aGk=", "start /w==", "error"],
+            *events.0.lock().unwrap()
+        );
+    }
+}
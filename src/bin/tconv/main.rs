@@ -0,0 +1,1149 @@
+//! Command line interface for the built-in converters
+//!
+//! ```text
+//! tconv upper file.txt
+//! tconv reverse --clipboard --to-clipboard
+//! cat file.txt | tconv title -
+//! tconv trim --in-place notes.md
+//! ```
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::{fs, io, path::PathBuf, process::ExitCode, time::Duration};
+#[cfg(feature = "config")]
+use text_converter::Profile;
+use text_converter::{
+    converters::*, read_stdin, repl::Repl, write_atomic, write_to_stdout, Backup, Bom,
+    ClipboardTarget, Conversion, ConversionError, ConversionMode, ConverterRegistry,
+    ReversibleConverter, TextClipboard, TextConverter,
+};
+#[cfg(feature = "tui")]
+use text_converter::{Tui, TuiSource};
+
+#[derive(Parser)]
+#[command(
+    name = "tconv",
+    version,
+    about = "Convert text from files, stdin or the clipboard"
+)]
+struct Cli {
+    #[command(subcommand)]
+    converter: ConverterCommand,
+}
+
+#[derive(Subcommand)]
+enum ConverterCommand {
+    /// Convert every character to uppercase
+    Upper(IoArgs),
+    /// Convert every character to lowercase
+    Lower(IoArgs),
+    /// Capitalize the first letter of every word
+    Title(IoArgs),
+    /// Swap uppercase and lowercase characters
+    SwapCase(IoArgs),
+    /// Alternate between lowercase and uppercase letters, like "mocking SpongeBob"
+    Mocking {
+        /// Pick the case of every letter at random instead
+        #[arg(short, long)]
+        random: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Convert identifiers to snake_case
+    Snake(IoArgs),
+    /// Convert identifiers to SCREAMING_SNAKE_CASE
+    ScreamingSnake(IoArgs),
+    /// Convert identifiers to kebab-case
+    Kebab(IoArgs),
+    /// Convert identifiers to camelCase
+    Camel(IoArgs),
+    /// Convert identifiers to PascalCase
+    Pascal(IoArgs),
+    /// Remove leading and trailing whitespace
+    Trim(IoArgs),
+    /// Remove leading and trailing whitespace from every line
+    TrimLines(IoArgs),
+    /// Replace every run of whitespace with a single space
+    CollapseWhitespace(IoArgs),
+    /// Collapse runs of spaces and tabs and trim every line
+    NormalizeWhitespace {
+        /// Keep runs of spaces and tabs
+        #[arg(long)]
+        no_collapse: bool,
+        /// Keep spaces and tabs at the start and end of lines
+        #[arg(long)]
+        no_trim: bool,
+        /// Replace Unicode spaces, like no-break spaces, with ASCII spaces
+        #[arg(short, long)]
+        unicode: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Replace accented and non-Latin characters with ASCII, needs the `deunicode` feature
+    #[cfg(feature = "deunicode")]
+    Transliterate {
+        /// Character to use for characters without an ASCII version
+        #[arg(short, long, default_value_t = '?')]
+        replacement: char,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Draw text as a large ASCII-art banner, needs the `figlet-rs` feature
+    #[cfg(feature = "figlet-rs")]
+    Banner {
+        /// FIGlet font to draw the text in
+        #[arg(short, long, value_enum, default_value_t = FontArg::Standard)]
+        font: FontArg,
+        /// Maximum width of the banner in columns, 0 for no limit
+        #[arg(short, long, default_value_t = 80)]
+        width: usize,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Bring text to a Unicode normalization form, needs the `unicode-normalization` feature
+    #[cfg(feature = "unicode-normalization")]
+    Normalize {
+        /// Normalization form to use
+        #[arg(short, long, value_enum, default_value_t = FormArg::Nfc)]
+        form: FormArg,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Reflow paragraphs to a maximum line width
+    Wrap {
+        /// Maximum number of characters on a line
+        #[arg(short, long, default_value_t = 80)]
+        width: usize,
+        /// Number of spaces before every line of a paragraph but the first
+        #[arg(long, default_value_t = 0)]
+        hanging_indent: usize,
+        /// Split words longer than a line
+        #[arg(short, long)]
+        break_words: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Make every line end the same way
+    LineEndings {
+        /// Line ending to use
+        #[arg(short, long, value_enum, default_value_t = EndingArg::Lf)]
+        ending: EndingArg,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Remove all whitespace
+    RemoveWhitespace(IoArgs),
+    /// Remove empty lines
+    RemoveEmptyLines(IoArgs),
+    /// Reverse the order of the characters
+    Reverse(IoArgs),
+    /// Reverse the order of the words
+    ReverseWords(IoArgs),
+    /// Reverse the order of the lines
+    ReverseLines(IoArgs),
+    /// Sort the lines
+    SortLines {
+        /// Compare the numbers lines start with
+        #[arg(short, long, conflicts_with = "ignore_case")]
+        numeric: bool,
+        /// Compare lines regardless of case
+        #[arg(short, long)]
+        ignore_case: bool,
+        /// Sort from largest to smallest
+        #[arg(short, long)]
+        reverse: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Put the line number before every line
+    NumberLines {
+        /// Minimum width of the numbers
+        #[arg(short, long, default_value_t = 0)]
+        width: usize,
+        /// Text between the number and the line
+        #[arg(short, long, default_value = ": ")]
+        separator: String,
+        /// Number of the first line
+        #[arg(long, default_value_t = 1)]
+        start: usize,
+        /// Remove line numbers instead, from any numbered snippet
+        #[arg(short, long)]
+        decode: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Remove repeated lines
+    DedupLines {
+        /// Only remove lines equal to the line right before them
+        #[arg(short, long)]
+        adjacent: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Translate words to Pig Latin
+    PigLatin(IoArgs),
+    /// Shift letters 13 places in the alphabet
+    Rot13(IoArgs),
+    /// Shift letters a number of places in the alphabet
+    Caesar {
+        /// Number of places to shift letters by
+        #[arg(short, long)]
+        shift: u8,
+        /// Shift letters back instead, to decode
+        #[arg(short, long)]
+        decode: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Shift letters by the letters of a keyword in turn
+    Vigenere {
+        /// Keyword whose letters give the shift of each letter
+        #[arg(short, long)]
+        key: String,
+        /// Shift letters back instead, to decode
+        #[arg(short, long)]
+        decode: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Escape characters that have a meaning in HTML
+    Html {
+        /// Replace character references with the characters they stand for instead
+        #[arg(short, long)]
+        decode: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Turn HTML into plain text, keeping paragraphs, lists and tables readable
+    HtmlToText(IoArgs),
+    /// Pretty-print JSON, needs the `serde_json` feature
+    #[cfg(feature = "serde_json")]
+    Json {
+        /// Remove all whitespace instead
+        #[arg(short, long, conflicts_with = "indent")]
+        minify: bool,
+        /// Number of spaces per nesting level
+        #[arg(short, long, default_value_t = 2)]
+        indent: usize,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Convert YAML to JSON, needs the `serde_yaml` feature
+    #[cfg(feature = "serde_yaml")]
+    Yaml {
+        /// Convert JSON to YAML instead
+        #[arg(short, long)]
+        decode: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Replace text matching a regular expression, needs the `regex` feature
+    #[cfg(feature = "regex")]
+    Replace {
+        /// Regular expression to search for
+        pattern: String,
+        /// Text to replace matches with, `$1` or `${name}` insert capture groups
+        replacement: String,
+        /// Match letters regardless of case
+        #[arg(short, long)]
+        ignore_case: bool,
+        /// Make `^` and `$` match at line boundaries
+        #[arg(short, long)]
+        multi_line: bool,
+        /// Only replace the first match
+        #[arg(short, long)]
+        first: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Convert with a Rhai script defining `convert(input)`, needs the `rhai` feature
+    #[cfg(feature = "rhai")]
+    Script {
+        /// File holding the script
+        script: PathBuf,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Replace `{name}` placeholders with values
+    Template {
+        /// Value of a placeholder, as `name=value`
+        #[arg(short, long = "var", value_name = "NAME=VALUE", value_parser = parse_var)]
+        vars: Vec<(String, String)>,
+        /// Fail on placeholders without a value and on unclosed braces
+        #[arg(short, long)]
+        strict: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Turn CSV into an aligned Markdown table
+    Table {
+        /// Character separating fields
+        #[arg(long, default_value_t = ',')]
+        delimiter: char,
+        /// Whether the first row holds the column names
+        #[arg(long, value_enum, default_value_t = HeaderArg::Detect)]
+        header: HeaderArg,
+        /// Turn Markdown tables into CSV instead
+        #[arg(short, long)]
+        decode: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Percent-encode characters that aren't allowed in URLs
+    Url {
+        /// Part of the URL the text goes in, which decides the characters left unencoded
+        #[arg(short, long, value_enum, default_value_t = UrlSetArg::Query)]
+        set: UrlSetArg,
+        /// Decode percent-encoded text instead
+        #[arg(short, long)]
+        decode: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Replace letters with look-alike digits and symbols
+    Leet {
+        /// How many letters to replace, from 1 (only vowels) to 3 (every letter)
+        #[arg(short, long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=3))]
+        intensity: u8,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Replace :shortcodes: with emoji
+    Emojize {
+        /// Replace emoji with shortcodes instead
+        #[arg(short, long)]
+        decode: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Convert ASCII characters to their fullwidth forms
+    Fullwidth {
+        /// Put a space between characters
+        #[arg(short, long)]
+        spacing: bool,
+        /// Convert fullwidth forms back to ASCII instead
+        #[arg(short, long)]
+        decode: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Style letters and digits with Unicode mathematical symbols, like bold or monospace text
+    Style {
+        /// Style of the letters and digits
+        #[arg(short, long, value_enum, default_value_t = StyleArg::Bold)]
+        style: StyleArg,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Turn letters and digits in any Unicode mathematical style back into plain ones
+    Unstyle(IoArgs),
+    /// Stack random combining marks on every character
+    Zalgo {
+        /// How many marks to stack, from 1 (a few) to 3 (a lot)
+        #[arg(short, long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=3))]
+        intensity: u8,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Remove combining marks, like the ones added by zalgo
+    ZalgoStrip(IoArgs),
+    /// Encode text as Morse code
+    Morse {
+        /// Decode Morse code instead
+        #[arg(short, long)]
+        decode: bool,
+        /// What to do with characters without a Morse code, and with unknown codes
+        #[arg(short, long, value_enum, default_value_t = ModeArg::Skip)]
+        mode: ModeArg,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Spell text out in the NATO phonetic alphabet, like "Alfa Bravo Charlie"
+    Nato {
+        /// Decode NATO phonetic alphabet words instead
+        #[arg(short, long)]
+        decode: bool,
+        /// What to do with characters without a code word, and with unknown words
+        #[arg(short, long, value_enum, default_value_t = ModeArg::Skip)]
+        mode: ModeArg,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Write text as Unicode Braille patterns, in uncontracted (Grade 1) braille
+    Braille {
+        /// Turn Braille patterns back into text instead
+        #[arg(short, long)]
+        decode: bool,
+        /// What to do with characters without a Braille pattern, and with unknown patterns
+        #[arg(short, long, value_enum, default_value_t = ModeArg::Skip)]
+        mode: ModeArg,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Encode text as Base64
+    Base64 {
+        /// Use the URL-safe alphabet, without padding
+        #[arg(short, long)]
+        url_safe: bool,
+        /// Decode Base64 text instead
+        #[arg(short, long)]
+        decode: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Dump text as offsets, hex bytes and ASCII characters, like `hexdump -C`
+    HexDump {
+        /// Number of bytes on each line
+        #[arg(short, long, default_value_t = 16)]
+        width: usize,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// Decode a string of hexadecimal digits
+    HexDecode(IoArgs),
+    /// Write the bytes of text as groups of 8 binary digits
+    Binary {
+        /// Number of bytes in each group
+        #[arg(short, long, default_value_t = 1)]
+        group: usize,
+        /// Written between groups
+        #[arg(short, long, default_value = " ")]
+        separator: String,
+        /// Decode binary digits instead
+        #[arg(short, long)]
+        decode: bool,
+        #[command(flatten)]
+        io: IoArgs,
+    },
+    /// List the converters that `watch-clipboard`, `repl` and `tui` take, with what they do
+    List {
+        /// Only list the converters in this category, like `case` or `encoding`
+        #[arg(short, long)]
+        category: Option<String>,
+    },
+    /// Keep running, converting every new text copied to the clipboard and putting the output
+    /// back into it
+    WatchClipboard {
+        /// Name of the built-in converter to run, like `trim-lines`
+        converter: String,
+        /// Milliseconds between two checks of the clipboard
+        #[arg(short, long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Convert every line typed with the selected built-in converters, type `:help` for the
+    /// commands to change them
+    Repl {
+        /// Names of the built-in converters to start with, like `trim upper`
+        converters: Vec<String>,
+    },
+    /// Run a profile saved with `Profile::save`, or list the saved profiles without a name
+    #[cfg(feature = "config")]
+    Run {
+        /// Name of the profile, like `clean-logs`
+        name: Option<String>,
+    },
+    /// Browse the built-in converters, previewing their output on the clipboard or a file and
+    /// applying the highlighted one with Enter
+    #[cfg(feature = "tui")]
+    Tui {
+        /// File to convert instead of the clipboard
+        file: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum StyleArg {
+    Bold,
+    Italic,
+    BoldItalic,
+    Script,
+    BoldScript,
+    Fraktur,
+    DoubleStruck,
+    BoldFraktur,
+    SansSerif,
+    SansSerifBold,
+    SansSerifItalic,
+    SansSerifBoldItalic,
+    Monospace,
+}
+
+impl From<StyleArg> for MathStyle {
+    fn from(style: StyleArg) -> Self {
+        match style {
+            StyleArg::Bold => Self::Bold,
+            StyleArg::Italic => Self::Italic,
+            StyleArg::BoldItalic => Self::BoldItalic,
+            StyleArg::Script => Self::Script,
+            StyleArg::BoldScript => Self::BoldScript,
+            StyleArg::Fraktur => Self::Fraktur,
+            StyleArg::DoubleStruck => Self::DoubleStruck,
+            StyleArg::BoldFraktur => Self::BoldFraktur,
+            StyleArg::SansSerif => Self::SansSerif,
+            StyleArg::SansSerifBold => Self::SansSerifBold,
+            StyleArg::SansSerifItalic => Self::SansSerifItalic,
+            StyleArg::SansSerifBoldItalic => Self::SansSerifBoldItalic,
+            StyleArg::Monospace => Self::Monospace,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ModeArg {
+    Strict,
+    Lossy,
+    Skip,
+}
+
+impl From<ModeArg> for ConversionMode {
+    fn from(mode: ModeArg) -> Self {
+        match mode {
+            ModeArg::Strict => Self::Strict,
+            ModeArg::Lossy => Self::Lossy,
+            ModeArg::Skip => Self::Skip,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum UrlSetArg {
+    Path,
+    Query,
+    Form,
+}
+
+impl From<UrlSetArg> for UrlEncodeSet {
+    fn from(set: UrlSetArg) -> Self {
+        match set {
+            UrlSetArg::Path => Self::Path,
+            UrlSetArg::Query => Self::Query,
+            UrlSetArg::Form => Self::Form,
+        }
+    }
+}
+
+#[cfg(feature = "figlet-rs")]
+#[derive(Clone, Copy, ValueEnum)]
+enum FontArg {
+    Standard,
+    Small,
+    Big,
+    Slant,
+}
+
+#[cfg(feature = "figlet-rs")]
+impl From<FontArg> for FigletFont {
+    fn from(font: FontArg) -> Self {
+        match font {
+            FontArg::Standard => Self::Standard,
+            FontArg::Small => Self::Small,
+            FontArg::Big => Self::Big,
+            FontArg::Slant => Self::Slant,
+        }
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+#[derive(Clone, Copy, ValueEnum)]
+enum FormArg {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl From<FormArg> for NormalizationForm {
+    fn from(form: FormArg) -> Self {
+        match form {
+            FormArg::Nfc => Self::Nfc,
+            FormArg::Nfd => Self::Nfd,
+            FormArg::Nfkc => Self::Nfkc,
+            FormArg::Nfkd => Self::Nfkd,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum EndingArg {
+    Lf,
+    Crlf,
+    Cr,
+}
+
+impl From<EndingArg> for LineEnding {
+    fn from(ending: EndingArg) -> Self {
+        match ending {
+            EndingArg::Lf => Self::Lf,
+            EndingArg::Crlf => Self::CrLf,
+            EndingArg::Cr => Self::Cr,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum HeaderArg {
+    Detect,
+    FirstRow,
+    None,
+}
+
+impl From<HeaderArg> for CsvHeader {
+    fn from(header: HeaderArg) -> Self {
+        match header {
+            HeaderArg::Detect => Self::Detect,
+            HeaderArg::FirstRow => Self::FirstRow,
+            HeaderArg::None => Self::None,
+        }
+    }
+}
+
+/// Where to read the input from and where to write the output to
+#[derive(Args)]
+struct IoArgs {
+    /// File to convert, `-` or nothing to read from stdin
+    #[arg(conflicts_with_all = ["text", "clipboard"])]
+    file: Option<PathBuf>,
+
+    /// Convert this text instead of reading a file
+    #[arg(short, long, conflicts_with = "clipboard")]
+    text: Option<String>,
+
+    /// Convert the clipboard contents
+    #[arg(short, long)]
+    clipboard: bool,
+
+    /// Convert the text at this URL
+    #[cfg(feature = "http")]
+    #[arg(short, long, conflicts_with_all = ["file", "text", "clipboard", "lines"])]
+    url: Option<String>,
+
+    /// Write the output to this file instead of stdout
+    #[arg(short, long, conflicts_with = "to_clipboard", group = "destination")]
+    output: Option<PathBuf>,
+
+    /// Write the output to the clipboard instead of stdout
+    #[arg(long)]
+    to_clipboard: bool,
+
+    /// Convert stdin or the file line by line, writing each line to stdout as soon as it is
+    /// converted
+    #[arg(
+        short,
+        long,
+        conflicts_with_all = [
+            "text",
+            "clipboard",
+            "output",
+            "to_clipboard",
+            "verbose",
+            "html",
+            "primary",
+        ]
+    )]
+    lines: bool,
+
+    /// Show what the conversion changes as a unified diff instead of writing the output
+    #[arg(long, conflicts_with_all = ["output", "to_clipboard", "lines"])]
+    diff: bool,
+
+    /// Print statistics about the conversion to stderr
+    #[arg(long)]
+    verbose: bool,
+
+    /// Read the HTML version of the clipboard with --clipboard, and write the output as HTML,
+    /// with the input as its plain-text version, with --to-clipboard
+    #[arg(long)]
+    html: bool,
+
+    /// Recognize the text in the clipboard image with tesseract, with --clipboard
+    #[cfg(feature = "ocr")]
+    #[arg(long, requires = "clipboard", conflicts_with = "html")]
+    ocr: bool,
+
+    /// Replace the file with the output, keeping a copy of the original as `FILE.bak`
+    #[arg(
+        long,
+        requires = "file",
+        conflicts_with_all = ["output", "to_clipboard", "lines", "diff"],
+        group = "destination"
+    )]
+    in_place: bool,
+
+    /// Suffix of the backup kept by --in-place
+    #[arg(long, value_name = "SUFFIX", requires = "in_place")]
+    backup: Option<String>,
+
+    /// Don't keep a backup with --in-place
+    #[arg(long, requires = "in_place", conflicts_with = "backup")]
+    no_backup: bool,
+
+    /// Show what --output or --in-place would change as a unified diff, and what would be
+    /// written, without writing anything
+    #[arg(long, requires = "destination")]
+    dry_run: bool,
+
+    /// Use the primary selection, pasted with the middle click, instead of the regular clipboard
+    #[arg(long)]
+    primary: bool,
+
+    /// Start the output file with the byte order mark of the input file, if it had one
+    #[arg(long, requires = "output")]
+    keep_bom: bool,
+}
+
+fn main() -> ExitCode {
+    let result = match Cli::parse().converter {
+        ConverterCommand::Upper(args) => run(Uppercase, args),
+        ConverterCommand::Lower(args) => run(Lowercase, args),
+        ConverterCommand::Title(args) => run(TitleCase, args),
+        ConverterCommand::SwapCase(args) => run(SwapCase, args),
+        ConverterCommand::Mocking { random, io } => match random {
+            true => run(MockingCase::Random, io),
+            false => run(MockingCase::Alternate, io),
+        },
+        ConverterCommand::Snake(args) => run(SnakeCase, args),
+        ConverterCommand::ScreamingSnake(args) => run(ScreamingSnakeCase, args),
+        ConverterCommand::Kebab(args) => run(KebabCase, args),
+        ConverterCommand::Camel(args) => run(CamelCase, args),
+        ConverterCommand::Pascal(args) => run(PascalCase, args),
+        ConverterCommand::Trim(args) => run(Trim, args),
+        ConverterCommand::TrimLines(args) => run(TrimLines, args),
+        ConverterCommand::CollapseWhitespace(args) => run(CollapseWhitespace, args),
+        #[cfg(feature = "deunicode")]
+        ConverterCommand::Transliterate { replacement, io } => {
+            run(Transliterate::new(Unmappable::Replace(replacement)), io)
+        }
+        #[cfg(feature = "figlet-rs")]
+        ConverterCommand::Banner { font, width, io } => {
+            run(Banner::new(FigletFont::from(font), width), io)
+        }
+        #[cfg(feature = "unicode-normalization")]
+        ConverterCommand::Normalize { form, io } => run(Normalize::new(form.into()), io),
+        ConverterCommand::Wrap {
+            width,
+            hanging_indent,
+            break_words,
+            io,
+        } => run(
+            WordWrap {
+                width,
+                hanging_indent,
+                break_words,
+            },
+            io,
+        ),
+        ConverterCommand::LineEndings { ending, io } => run(LineEndings::new(ending.into()), io),
+        ConverterCommand::NormalizeWhitespace {
+            no_collapse,
+            no_trim,
+            unicode,
+            io,
+        } => run(
+            NormalizeWhitespace {
+                collapse: !no_collapse,
+                trim_lines: !no_trim,
+                unicode_spaces: unicode,
+            },
+            io,
+        ),
+        ConverterCommand::RemoveWhitespace(args) => run(RemoveWhitespace, args),
+        ConverterCommand::RemoveEmptyLines(args) => run(RemoveEmptyLines, args),
+        ConverterCommand::Reverse(args) => run(Reverse, args),
+        ConverterCommand::ReverseWords(args) => run(ReverseWords, args),
+        ConverterCommand::ReverseLines(args) => run(ReverseLines, args),
+        ConverterCommand::SortLines {
+            numeric,
+            ignore_case,
+            reverse,
+            io,
+        } => {
+            let key = match (numeric, ignore_case) {
+                (true, _) => SortKey::Numeric,
+                (_, true) => SortKey::CaseInsensitive,
+                _ => SortKey::Lexicographic,
+            };
+            run(SortLines { key, reverse }, io)
+        }
+        ConverterCommand::NumberLines {
+            width,
+            separator,
+            start,
+            decode,
+            io,
+        } => match decode {
+            true => run(StripLineNumbers, io),
+            false => run(
+                NumberLines {
+                    width,
+                    separator,
+                    start,
+                },
+                io,
+            ),
+        },
+        ConverterCommand::DedupLines { adjacent, io } => match adjacent {
+            true => run(DedupLines::new(DedupScope::Adjacent), io),
+            false => run(DedupLines::new(DedupScope::Global), io),
+        },
+        ConverterCommand::PigLatin(args) => run(PigLatin, args),
+        ConverterCommand::Rot13(args) => run(Rot13, args),
+        ConverterCommand::Caesar { shift, decode, io } => match decode {
+            true => run(CaesarCipher::new(shift).decoder(), io),
+            false => run(CaesarCipher::new(shift), io),
+        },
+        ConverterCommand::Vigenere { key, decode, io } => match decode {
+            true => run(Vigenere::new(key).decoder(), io),
+            false => run(Vigenere::new(key), io),
+        },
+        ConverterCommand::Html { decode, io } => match decode {
+            true => run(HtmlUnescape, io),
+            false => run(HtmlEscape, io),
+        },
+        ConverterCommand::HtmlToText(args) => run(HtmlToText, args),
+        #[cfg(feature = "serde_json")]
+        ConverterCommand::Json { minify, indent, io } => match minify {
+            true => run(JsonMinify, io),
+            false => run(JsonPretty::new(indent), io),
+        },
+        #[cfg(feature = "serde_yaml")]
+        ConverterCommand::Yaml { decode, io } => match decode {
+            true => run(JsonToYaml, io),
+            false => run(YamlToJson, io),
+        },
+        #[cfg(feature = "regex")]
+        ConverterCommand::Replace {
+            pattern,
+            replacement,
+            ignore_case,
+            multi_line,
+            first,
+            io,
+        } => {
+            let options = RegexOptions {
+                case_insensitive: ignore_case,
+                multi_line,
+                first_only: first,
+            };
+            RegexReplace::with_options(&pattern, replacement, options)
+                .and_then(|converter| run(converter, io))
+        }
+        #[cfg(feature = "rhai")]
+        ConverterCommand::Script { script, io } => {
+            ScriptConverter::from_file(&script).and_then(|converter| run(converter, io))
+        }
+        ConverterCommand::Template { vars, strict, io } => match strict {
+            true => run(TemplateConverter::strict(vars), io),
+            false => run(TemplateConverter::new(vars), io),
+        },
+        ConverterCommand::Table {
+            delimiter,
+            header,
+            decode,
+            io,
+        } => match decode {
+            true => run(MarkdownToCsv::new(delimiter), io),
+            false => run(
+                CsvToMarkdown {
+                    delimiter,
+                    header: header.into(),
+                },
+                io,
+            ),
+        },
+        ConverterCommand::Url { set, decode, io } => match decode {
+            true => run(UrlDecode::new(set.into()), io),
+            false => run(UrlEncode::new(set.into()), io),
+        },
+        ConverterCommand::Leet { intensity, io } => {
+            let intensity = match intensity {
+                1 => LeetIntensity::Basic,
+                2 => LeetIntensity::Advanced,
+                _ => LeetIntensity::Full,
+            };
+            run(Leet::new(intensity), io)
+        }
+        ConverterCommand::Emojize { decode, io } => match decode {
+            true => run(Emojize.decoder(), io),
+            false => run(Emojize, io),
+        },
+        ConverterCommand::Fullwidth {
+            spacing,
+            decode,
+            io,
+        } => match decode {
+            true => run(Fullwidth::new(spacing).decoder(), io),
+            false => run(Fullwidth::new(spacing), io),
+        },
+        ConverterCommand::Style { style, io } => run(UnicodeStyle::new(style.into()), io),
+        ConverterCommand::Unstyle(args) => run(UnicodeUnstyle, args),
+        ConverterCommand::Zalgo { intensity, io } => {
+            let intensity = match intensity {
+                1 => ZalgoIntensity::Mild,
+                2 => ZalgoIntensity::Moderate,
+                _ => ZalgoIntensity::Max,
+            };
+            run(Zalgo::new(intensity), io)
+        }
+        ConverterCommand::ZalgoStrip(args) => run(ZalgoStrip, args),
+        ConverterCommand::Morse { decode, mode, io } => match decode {
+            true => run(Morse::new(ConversionMode::from(mode)).decoder(), io),
+            false => run(Morse::new(ConversionMode::from(mode)), io),
+        },
+        ConverterCommand::Nato { decode, mode, io } => match decode {
+            true => run(Nato::new(ConversionMode::from(mode)).decoder(), io),
+            false => run(Nato::new(ConversionMode::from(mode)), io),
+        },
+        ConverterCommand::Braille { decode, mode, io } => match decode {
+            true => run(Braille::new(ConversionMode::from(mode)).decoder(), io),
+            false => run(Braille::new(ConversionMode::from(mode)), io),
+        },
+        ConverterCommand::Base64 {
+            url_safe,
+            decode,
+            io,
+        } => {
+            let encoder = match url_safe {
+                true => Base64Encode::url_safe(),
+                false => Base64Encode::new(),
+            };
+            match decode {
+                true => run(encoder.decoder(), io),
+                false => run(encoder, io),
+            }
+        }
+        ConverterCommand::HexDump { width, io } => run(HexDump::new(width), io),
+        ConverterCommand::HexDecode(io) => run(HexDecode, io),
+        ConverterCommand::Binary {
+            group,
+            separator,
+            decode,
+            io,
+        } => match decode {
+            true => run(Binary::new(group, separator).decoder(), io),
+            false => run(Binary::new(group, separator), io),
+        },
+        ConverterCommand::List { category } => {
+            list(category.as_deref());
+            Ok(())
+        }
+        ConverterCommand::WatchClipboard {
+            converter,
+            interval,
+        } => watch_clipboard(&converter, Duration::from_millis(interval)),
+        ConverterCommand::Repl { converters } => repl(converters),
+        #[cfg(feature = "config")]
+        ConverterCommand::Run { name } => run_profile(name.as_deref()),
+        #[cfg(feature = "tui")]
+        ConverterCommand::Tui { file } => {
+            let source = file.map_or(TuiSource::Clipboard, TuiSource::File);
+            Tui::new(registry(), source).and_then(Tui::run)
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("tconv: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_var(var: &str) -> Result<(String, String), String> {
+    var.split_once('=')
+        .map(|(name, value)| (name.to_owned(), value.to_owned()))
+        .ok_or_else(|| format!("expected NAME=VALUE, got `{var}`"))
+}
+
+/// Opens the clipboard, or the primary selection
+fn clipboard(primary: bool) -> Result<TextClipboard, ConversionError> {
+    let target = match primary {
+        true => ClipboardTarget::Primary,
+        false => ClipboardTarget::Clipboard,
+    };
+    Ok(TextClipboard::new()?.target(target))
+}
+
+/// The built-in converters, and the ones registered by the crates linked in
+#[cfg(feature = "inventory")]
+fn registry() -> ConverterRegistry {
+    ConverterRegistry::with_registered()
+}
+
+#[cfg(not(feature = "inventory"))]
+fn registry() -> ConverterRegistry {
+    ConverterRegistry::with_builtins()
+}
+
+fn list(category: Option<&str>) {
+    let registry = registry();
+    let infos: Vec<_> = registry
+        .infos()
+        .filter(|info| category.is_none_or(|category| info.in_category(category)))
+        .collect();
+    let width = infos
+        .iter()
+        .map(|info| info.name().len())
+        .max()
+        .unwrap_or(0);
+
+    for info in infos {
+        let reversible = match info.is_reversible() {
+            true => ", reversible",
+            false => "",
+        };
+        println!(
+            "{:width$}  {} [{}{reversible}]",
+            info.name(),
+            info.description(),
+            info.categories().join(", ")
+        );
+    }
+}
+
+fn repl(converters: Vec<String>) -> Result<(), ConversionError> {
+    let mut repl = Repl::new(registry());
+    if !converters.is_empty() {
+        println!(
+            "{}",
+            repl.eval(&format!(":use {}", converters.join(" ")))
+                .unwrap_or_default()
+        );
+    }
+    repl.run(io::stdin().lock(), io::stdout())
+}
+
+#[cfg(feature = "config")]
+fn run_profile(name: Option<&str>) -> Result<(), ConversionError> {
+    match name {
+        Some(name) => Profile::run(name).map(drop),
+        None => {
+            let names = Profile::names()?;
+            match names.is_empty() {
+                true => eprintln!("tconv: no profile in {}", Profile::dir()?.display()),
+                false => println!("{}", names.join("\n")),
+            }
+            Ok(())
+        }
+    }
+}
+
+fn watch_clipboard(name: &str, interval: Duration) -> Result<(), ConversionError> {
+    let registry = registry();
+    let converter = registry
+        .get(name)
+        .ok_or_else(|| ConversionError::UnknownConverter(name.to_owned()))?;
+
+    eprintln!("tconv: converting every copy with `{name}`, press Ctrl+C to stop");
+    for conversion in converter.watch_clipboard(interval)? {
+        match conversion {
+            Ok(conversion) if conversion.is_unchanged() => {}
+            Ok(conversion) => eprintln!(
+                "tconv: converted {} characters",
+                conversion.input.chars().count()
+            ),
+            Err(e) => eprintln!("tconv: {e}"),
+        }
+    }
+
+    Ok(())
+}
+
+fn run(converter: impl TextConverter, args: IoArgs) -> Result<(), ConversionError> {
+    let reads_stdin = args
+        .file
+        .as_ref()
+        .is_none_or(|path| path.as_os_str() == "-");
+    if args.lines {
+        return match args.file.as_deref().filter(|_| !reads_stdin) {
+            Some(path) => {
+                converter.convert_reader_to_stdout(io::BufReader::new(fs::File::open(path)?))
+            }
+            None => converter.convert_stdin_to_stdout(),
+        };
+    }
+
+    if args.in_place {
+        let path = args
+            .file
+            .as_deref()
+            .filter(|_| !reads_stdin)
+            .ok_or_else(|| {
+                ConversionError::InvalidInput("--in-place needs a file, not stdin".to_owned())
+            })?;
+        let backup = match args.backup {
+            _ if args.no_backup => Backup::None,
+            Some(suffix) => Backup::Suffix(suffix),
+            None => Backup::default(),
+        };
+        if args.dry_run {
+            let bytes = fs::read(path)?;
+            let conversion = Conversion::with_diff(&converter, Bom::decode(&bytes)?.0)?;
+            match backup.path(path) {
+                Some(backup) => eprintln!(
+                    "tconv: would replace {}, keeping the original as {}",
+                    path.display(),
+                    backup.display()
+                ),
+                None => eprintln!("tconv: would replace {}", path.display()),
+            }
+            return write_to_stdout(conversion.diff);
+        }
+
+        converter.try_convert_file_in_place_with(path, &backup)?;
+        return Ok(());
+    }
+
+    let (input, bom) = match (args.text, &args.file) {
+        (Some(text), _) => (text, None),
+        #[cfg(feature = "http")]
+        _ if args.url.is_some() => (
+            text_converter::fetch_url(args.url.as_deref().unwrap_or_default())?,
+            None,
+        ),
+        #[cfg(feature = "ocr")]
+        _ if args.clipboard && args.ocr => (text_converter::recognize_clipboard_image()?, None),
+        _ if args.clipboard && args.html => (clipboard(args.primary)?.get_html()?, None),
+        _ if args.clipboard => (clipboard(args.primary)?.get_text()?, None),
+        (None, Some(path)) if path.as_os_str() != "-" => {
+            let bytes = fs::read(path)?;
+            let (input, bom) = Bom::decode(&bytes)?;
+            (input.into_owned(), bom)
+        }
+        (None, _) => (read_stdin()?, None),
+    };
+
+    if args.diff {
+        return write_to_stdout(Conversion::with_diff(&converter, input)?.diff);
+    }
+
+    let (output, mut report) = converter.try_new_from_text_with_report(&input)?;
+    report.bom = bom;
+    if args.verbose {
+        eprintln!("tconv: {report}");
+    }
+
+    if let Some(path) = args.output.as_ref().filter(|_| args.dry_run) {
+        let size = match bom.filter(|_| args.keep_bom) {
+            Some(bom) => bom.encode(&output).len(),
+            None => output.len(),
+        };
+        eprintln!("tconv: would write {size} bytes to {}", path.display());
+        return write_to_stdout(Conversion::new(input, output).diff);
+    }
+
+    if let Some(path) = &args.output {
+        match bom.filter(|_| args.keep_bom) {
+            Some(bom) => write_atomic(path, bom.encode(&output))?,
+            None => write_atomic(path, output)?,
+        }
+    } else if args.to_clipboard && args.html {
+        clipboard(args.primary)?.set_html(output, input)?;
+    } else if args.to_clipboard {
+        clipboard(args.primary)?.set_text(output)?;
+    } else {
+        write_to_stdout(output)?;
+    }
+
+    Ok(())
+}
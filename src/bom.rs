@@ -0,0 +1,140 @@
+use crate::ConversionError;
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::{fmt, str};
+
+/// Byte order mark at the start of a file, which tells apart UTF-8 and both byte orders of
+/// UTF-16
+///
+/// Files are read with the mark removed, so converters never see it. Windows tools often write
+/// one, and [OutputOptions::keep_bom](crate::OutputOptions::keep_bom()) puts it back in the
+/// output
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::Bom;
+///
+/// let (text, bom) = Bom::decode(b"\xef\xbb\xbfhello").unwrap();
+/// assert_eq!(("hello", Some(Bom::Utf8)), (text.as_ref(), bom));
+/// assert_eq!(b"\xff\xfeh\0i\0".as_slice(), Bom::Utf16Le.encode("hi"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bom {
+    /// `EF BB BF`
+    Utf8,
+    /// `FF FE`, UTF-16 with the least significant byte first
+    Utf16Le,
+    /// `FE FF`, UTF-16 with the most significant byte first
+    Utf16Be,
+}
+
+impl Bom {
+    /// The byte order mark `bytes` start with, if any
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        [Self::Utf8, Self::Utf16Le, Self::Utf16Be]
+            .into_iter()
+            .find(|bom| bytes.starts_with(bom.as_bytes()))
+    }
+
+    /// The bytes of the mark
+    pub fn as_bytes(self) -> &'static [u8] {
+        match self {
+            Self::Utf8 => &[0xef, 0xbb, 0xbf],
+            Self::Utf16Le => &[0xff, 0xfe],
+            Self::Utf16Be => &[0xfe, 0xff],
+        }
+    }
+
+    /// Decodes file contents, removing the byte order mark if there is one
+    ///
+    /// Contents without a mark are read as UTF-8, and ones with a UTF-16 mark as UTF-16
+    ///
+    /// # Errors
+    /// [`ConversionError::Encoding`] if the contents are not valid UTF-8, or not valid UTF-16
+    /// after a UTF-16 mark
+    pub fn decode(bytes: &[u8]) -> Result<(Cow<'_, str>, Option<Self>), ConversionError> {
+        let bom = Self::detect(bytes);
+        let content = &bytes[bom.map_or(0, |bom| bom.as_bytes().len())..];
+
+        let text = match bom {
+            None | Some(Self::Utf8) => Cow::Borrowed(str::from_utf8(content)?),
+            Some(utf16) => {
+                let units: Option<Vec<_>> = content
+                    .chunks(2)
+                    .map(|pair| match (pair, utf16) {
+                        (&[low, high], Self::Utf16Le) => Some(u16::from_le_bytes([low, high])),
+                        (&[high, low], _) => Some(u16::from_be_bytes([high, low])),
+                        _ => None,
+                    })
+                    .collect();
+
+                match units.and_then(|units| String::from_utf16(&units).ok()) {
+                    Some(text) => Cow::Owned(text),
+                    // The mark itself is never valid UTF-8, which gives the error to report
+                    None => Cow::Borrowed(str::from_utf8(bytes)?),
+                }
+            }
+        };
+
+        Ok((text, bom))
+    }
+
+    /// Encodes `text` in the encoding of this mark, starting with the mark
+    pub fn encode(self, text: &str) -> Vec<u8> {
+        let mut bytes = self.as_bytes().to_vec();
+        match self {
+            Self::Utf8 => bytes.extend_from_slice(text.as_bytes()),
+            Self::Utf16Le | Self::Utf16Be => encode_utf16(text, self == Self::Utf16Le, &mut bytes),
+        }
+
+        bytes
+    }
+}
+
+impl fmt::Display for Bom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Utf8 => "UTF-8",
+            Self::Utf16Le => "UTF-16LE",
+            Self::Utf16Be => "UTF-16BE",
+        })
+    }
+}
+
+/// Appends `text` encoded as UTF-16 in either byte order to `bytes`
+pub(crate) fn encode_utf16(text: &str, little_endian: bool, bytes: &mut Vec<u8>) {
+    bytes.reserve(text.len() * 2);
+    for unit in text.encode_utf16() {
+        bytes.extend(match little_endian {
+            true => unit.to_le_bytes(),
+            false => unit.to_be_bytes(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_every_mark() {
+        assert_eq!(("plain".into(), None), Bom::decode(b"plain").unwrap());
+        assert_eq!(
+            ("hé".into(), Some(Bom::Utf16Be)),
+            Bom::decode(b"\xfe\xff\0h\0\xe9").unwrap()
+        );
+        assert_eq!(
+            ("🦀".into(), Some(Bom::Utf16Le)),
+            Bom::decode(&Bom::Utf16Le.encode("🦀")).unwrap()
+        );
+
+        assert!(matches!(
+            Bom::decode(b"\xff\xfeh"),
+            Err(ConversionError::Encoding(_))
+        ));
+        assert!(matches!(
+            Bom::decode(b"\xef\xbb\xbf\xff"),
+            Err(ConversionError::Encoding(_))
+        ));
+    }
+}
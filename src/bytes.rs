@@ -0,0 +1,297 @@
+#[cfg(feature = "std")]
+use crate::OutputOptions;
+#[cfg(feature = "clipboard")]
+use crate::TextClipboard;
+use crate::{ConversionError, TextConverter};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::str;
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+/// Trait for converters that work on raw bytes rather than text, like hex dumps or encodings of
+/// binary files
+///
+/// It mirrors [`TextConverter`], with bytes in and bytes out. [`AsText`] and [`AsBytes`] turn one
+/// kind of converter into the other, for the input sources only one of them has or to chain them
+/// in a [pipeline](crate::pipeline)
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{BytesConverter, TextConverter};
+///
+/// struct Xor(u8);
+///
+/// impl BytesConverter for Xor {
+///     fn converter(&self, input: &[u8]) -> Vec<u8> {
+///         input.iter().map(|byte| byte ^ self.0).collect()
+///     }
+/// }
+///
+/// assert_eq!(vec![0x69, 0x68], Xor(1).new_from_bytes(b"hi"));
+/// assert_eq!("ih", Xor(1).as_text().new_from_text("hi"));
+/// ```
+pub trait BytesConverter {
+    /// Converts the bytes of `input`
+    fn converter(&self, input: &[u8]) -> Vec<u8>;
+
+    /// Fallible version of the [converter](Self::converter()) method, used by all `try_*`
+    /// methods. Defaults to calling [converter](Self::converter())
+    fn try_converter(&self, input: &[u8]) -> Result<Vec<u8>, ConversionError> {
+        Ok(self.converter(input))
+    }
+
+    /// Extension of the files written by the `*_file` methods, like `hex`. Defaults to the
+    /// extension of the input file
+    fn output_extension(&self) -> Option<&str> {
+        None
+    }
+
+    /// Adapter that converts text through this converter, see [`AsText`]
+    fn as_text(&self) -> AsText<&Self> {
+        AsText(self)
+    }
+
+    /// Converts given input with the [converter](Self::converter()) method
+    fn new_from_bytes(&self, input: impl AsRef<[u8]>) -> Vec<u8> {
+        self.converter(input.as_ref())
+    }
+
+    /// Converts given input with the [try_converter](Self::try_converter()) method
+    ///
+    /// # Errors
+    /// Returns whatever error the converter reports
+    fn try_new_from_bytes(&self, input: impl AsRef<[u8]>) -> Result<Vec<u8>, ConversionError> {
+        self.try_converter(input.as_ref())
+    }
+
+    /// Fetches the text in the clipboard and converts its UTF-8 bytes with the
+    /// [converter](Self::converter()) method
+    ///
+    /// # Panics
+    /// Will panic if it fails to fetch the clipboard
+    #[cfg(feature = "clipboard")]
+    fn new_from_clipboard(&self) -> Vec<u8> {
+        let mut clipboard = TextClipboard::new().expect("Could not fetch the clipboard contents");
+        let input = clipboard.get_text().unwrap_or_default();
+
+        self.converter(input.as_bytes())
+    }
+
+    /// Fetches the text in the clipboard and converts its UTF-8 bytes with the
+    /// [try_converter](Self::try_converter()) method
+    ///
+    /// # Errors
+    /// - [`ConversionError::ClipboardUnavailable`] if there is no clipboard, see [`TextClipboard`]
+    /// - [`ConversionError::Clipboard`] if the clipboard does not contain text
+    /// - Whatever error the converter reports
+    #[cfg(feature = "clipboard")]
+    fn try_new_from_clipboard(&self) -> Result<Vec<u8>, ConversionError> {
+        let input = TextClipboard::new()?.get_text()?;
+
+        self.try_converter(input.as_bytes())
+    }
+
+    /// Fetches file contents, whatever they are, and converts them with the
+    /// [converter](Self::converter()) method
+    ///
+    /// # Panics
+    /// - If file is inaccessible
+    /// - If it fails to create the output file
+    ///
+    /// # Returns
+    /// - The converted bytes
+    /// - Outputs them next to the original, named like the outputs of
+    ///   [TextConverter::new_from_file]
+    #[cfg(feature = "std")]
+    fn new_from_file(&self, path: impl AsRef<Path>) -> Vec<u8> {
+        self.new_from_file_with(path, &OutputOptions::new())
+    }
+
+    /// Fetches file contents, whatever they are, and converts them with the
+    /// [try_converter](Self::try_converter()) method
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if the file is inaccessible or the output file cannot be written
+    /// - Whatever error the converter reports
+    ///
+    /// # Returns
+    /// - The converted bytes
+    /// - Outputs them next to the original, named like the outputs of
+    ///   [TextConverter::try_new_from_file]
+    #[cfg(feature = "std")]
+    fn try_new_from_file(&self, path: impl AsRef<Path>) -> Result<Vec<u8>, ConversionError> {
+        self.try_new_from_file_with(path, &OutputOptions::new())
+    }
+
+    /// Fetches file contents and converts them with the [converter](Self::converter()) method,
+    /// writing the output as configured by `options`
+    ///
+    /// # Panics
+    /// - If file is inaccessible
+    /// - If it fails to write the output file, or if it exists and the
+    ///   [overwrite policy](crate::OverwritePolicy::Fail) forbids replacing it
+    #[cfg(feature = "std")]
+    fn new_from_file_with(&self, path: impl AsRef<Path>, options: &OutputOptions) -> Vec<u8> {
+        let input = fs::read(path.as_ref()).expect("Failed to read file contents");
+        let output = self.converter(&input);
+
+        options
+            .with_converter_extension(self.output_extension())
+            .write(path.as_ref(), &output)
+            .expect("Failed to write to the output file");
+
+        output
+    }
+
+    /// Fetches file contents and converts them with the [try_converter](Self::try_converter())
+    /// method, writing the output as configured by `options`
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if the file is inaccessible, the output file cannot be written,
+    ///   or it exists and the [overwrite policy](crate::OverwritePolicy::Fail) forbids replacing
+    ///   it
+    /// - Whatever error the converter reports
+    #[cfg(feature = "std")]
+    fn try_new_from_file_with(
+        &self,
+        path: impl AsRef<Path>,
+        options: &OutputOptions,
+    ) -> Result<Vec<u8>, ConversionError> {
+        let input = fs::read(path.as_ref())?;
+        let output = self.try_converter(&input)?;
+
+        options
+            .with_converter_extension(self.output_extension())
+            .write(path.as_ref(), &output)?;
+
+        Ok(output)
+    }
+}
+
+impl<T: BytesConverter + ?Sized> BytesConverter for &T {
+    fn converter(&self, input: &[u8]) -> Vec<u8> {
+        (**self).converter(input)
+    }
+
+    fn try_converter(&self, input: &[u8]) -> Result<Vec<u8>, ConversionError> {
+        (**self).try_converter(input)
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        (**self).output_extension()
+    }
+}
+
+impl<T: BytesConverter + ?Sized> BytesConverter for Box<T> {
+    fn converter(&self, input: &[u8]) -> Vec<u8> {
+        (**self).converter(input)
+    }
+
+    fn try_converter(&self, input: &[u8]) -> Result<Vec<u8>, ConversionError> {
+        (**self).try_converter(input)
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        (**self).output_extension()
+    }
+}
+
+/// Runs a [`BytesConverter`] as a [`TextConverter`], on the UTF-8 bytes of the text
+///
+/// The fallible methods fail with [`ConversionError::Encoding`] if the output is not valid
+/// UTF-8, while the infallible ones replace invalid sequences with `�`
+///
+/// Usually created with [`BytesConverter::as_text`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AsText<C>(pub C);
+
+impl<C: BytesConverter> TextConverter for AsText<C> {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let output = self.0.converter(input.as_ref().as_bytes());
+        String::from_utf8(output).unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into())
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let output = self.0.try_converter(input.as_ref().as_bytes())?;
+        Ok(String::from_utf8(output).map_err(|e| e.utf8_error())?)
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        self.0.output_extension()
+    }
+}
+
+/// Runs a [`TextConverter`] as a [`BytesConverter`], outputting the UTF-8 bytes of the text
+///
+/// The fallible methods fail with [`ConversionError::Encoding`] if the input is not valid
+/// UTF-8, while the infallible ones replace invalid sequences with `�`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AsBytes<C>(pub C);
+
+impl<C: TextConverter> BytesConverter for AsBytes<C> {
+    fn converter(&self, input: &[u8]) -> Vec<u8> {
+        self.0
+            .converter(String::from_utf8_lossy(input))
+            .into_bytes()
+    }
+
+    fn try_converter(&self, input: &[u8]) -> Result<Vec<u8>, ConversionError> {
+        Ok(self.0.try_converter(str::from_utf8(input)?)?.into_bytes())
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        self.0.output_extension()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::fixtures;
+    use crate::fixtures::Uppercase;
+
+    struct Hex;
+
+    impl BytesConverter for Hex {
+        fn converter(&self, input: &[u8]) -> Vec<u8> {
+            input
+                .iter()
+                .flat_map(|byte| format!("{byte:02x}").into_bytes())
+                .collect()
+        }
+
+        fn output_extension(&self) -> Option<&str> {
+            Some("hex")
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_file_conversion() {
+        let dir = fixtures::temp_dir("bytes");
+        let path = dir.join("bytes.bin");
+        fs::write(&path, [0x00, 0xff, 0x10]).unwrap();
+
+        assert_eq!(b"00ff10".as_slice(), Hex.try_new_from_file(&path).unwrap());
+        assert_eq!(
+            "00ff10",
+            fs::read_to_string(dir.join("bytes_converted.hex")).unwrap()
+        );
+    }
+
+    #[test]
+    fn adapters() {
+        assert_eq!("6869", Hex.as_text().try_new_from_text("hi").unwrap());
+        assert_eq!(b"HI".as_slice(), AsBytes(Uppercase).new_from_bytes("hi"));
+        assert_eq!(
+            b"\xef\xbf\xbd".as_slice(),
+            AsBytes(Uppercase).new_from_bytes([0xff])
+        );
+        assert!(matches!(
+            AsBytes(Uppercase).try_new_from_bytes([0xff]),
+            Err(ConversionError::Encoding(_))
+        ));
+    }
+}
@@ -0,0 +1,59 @@
+use crate::ConversionError;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// Stops a running conversion from another thread
+///
+/// Clones share the same state, so one clone can be handed to the conversion and another kept to
+/// cancel it. Conversions check the token between lines, files or watch events, finish the step
+/// they are on and fail with [`ConversionError::Cancelled`]. Output files are written
+/// [atomically](crate::write_atomic()), so none is ever left half-written
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::{thread, time::Duration};
+/// use text_converter::{converters::Trim, CancellationToken, ConversionError, DirOptions, TextConverter};
+///
+/// let token = CancellationToken::new();
+/// let canceller = token.clone();
+/// thread::spawn(move || {
+///     thread::sleep(Duration::from_secs(60));
+///     canceller.cancel();
+/// });
+///
+/// let options = DirOptions::new().cancellation(token);
+/// match Trim.new_from_dir("huge_tree", &options) {
+///     Err(ConversionError::Cancelled) => eprintln!("took too long"),
+///     result => println!("{:?}", result.unwrap().converted),
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// A token that is not cancelled yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks every conversion using this token, or a clone of it, to stop
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether [cancel](Self::cancel()) was called on this token or a clone of it
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Fails with [`ConversionError::Cancelled`] once the token is cancelled
+    pub(crate) fn check(&self) -> Result<(), ConversionError> {
+        match self.is_cancelled() {
+            true => Err(ConversionError::Cancelled),
+            false => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use crate::{
+    stdio,
+    trace::{event, timed},
+    ConversionError,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use arboard::{Clipboard, Get, ImageData, Set};
+#[cfg(all(
+    unix,
+    not(any(target_os = "macos", target_os = "android", target_os = "emscripten"))
+))]
+use arboard::{GetExtLinux, LinuxClipboardKind, SetExtLinux};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable naming a file to use when there is no system clipboard, like on CI or
+/// over SSH. `-` reads from stdin and writes to stdout instead
+pub const CLIPBOARD_FALLBACK_VAR: &str = "TEXT_CONVERTER_CLIPBOARD";
+
+/// Which of the clipboards to use, on systems that have more than one
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ClipboardTarget {
+    /// The regular clipboard, filled by copying and read by pasting
+    #[default]
+    Clipboard,
+    /// The primary selection of X11 and Wayland, filled by selecting text and read by
+    /// middle-clicking. Only available on Linux and other Unix desktops
+    Primary,
+}
+
+/// The system clipboard, or the fallback named by [`CLIPBOARD_FALLBACK_VAR`] where there is no
+/// system clipboard to open
+///
+/// Every clipboard method of [`TextConverter`](crate::TextConverter) goes through it, so code
+/// that uses the clipboard works headlessly too once the variable is set. On WebAssembly, there
+/// is never a system clipboard, and browsers only give access to theirs asynchronously
+///
+/// # Examples
+///
+/// ```no_run
+/// use text_converter::{ConversionError, TextClipboard};
+///
+/// match TextClipboard::new() {
+///     Ok(mut clipboard) => println!("{}", clipboard.get_text().unwrap()),
+///     Err(ConversionError::ClipboardUnavailable(reason)) => eprintln!("{reason}"),
+///     Err(e) => panic!("{e}"),
+/// }
+/// ```
+pub struct TextClipboard {
+    pub(crate) backend: Backend,
+    pub(crate) target: ClipboardTarget,
+}
+
+pub(crate) enum Backend {
+    #[cfg(not(target_arch = "wasm32"))]
+    System(Clipboard),
+    File(PathBuf),
+    Stdio,
+}
+
+impl TextClipboard {
+    /// Opens the regular system clipboard, or the fallback if it cannot be opened
+    ///
+    /// # Errors
+    /// [`ConversionError::ClipboardUnavailable`] if the system clipboard cannot be opened and no
+    /// fallback is set
+    pub fn new() -> Result<Self, ConversionError> {
+        let backend = match open_system() {
+            Ok(backend) => backend,
+            Err(e) => match env::var_os(CLIPBOARD_FALLBACK_VAR) {
+                Some(target) if target == "-" => Backend::Stdio,
+                Some(path) if !path.is_empty() => Backend::File(path.into()),
+                _ => {
+                    return Err(ConversionError::ClipboardUnavailable(format!(
+                        "{e}. Set {CLIPBOARD_FALLBACK_VAR} to a file to use instead, or to `-` \
+                         for stdin and stdout"
+                    )))
+                }
+            },
+        };
+
+        Ok(Self {
+            backend,
+            target: ClipboardTarget::default(),
+        })
+    }
+
+    /// Uses `target` instead of the regular clipboard. The fallback is the same for every
+    /// target
+    pub fn target(mut self, target: ClipboardTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Whether this is the system clipboard rather than the fallback
+    pub fn is_system(&self) -> bool {
+        match self.backend {
+            #[cfg(not(target_arch = "wasm32"))]
+            Backend::System(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether this is the stdin and stdout fallback, which can only be read once
+    pub(crate) fn is_stdio(&self) -> bool {
+        matches!(self.backend, Backend::Stdio)
+    }
+
+    /// Fetches the text in the clipboard
+    ///
+    /// # Errors
+    /// - [`ConversionError::Clipboard`] if the system clipboard does not contain text
+    /// - [`ConversionError::Io`] or [`ConversionError::Encoding`] if the fallback cannot be read
+    pub fn get_text(&mut self) -> Result<String, ConversionError> {
+        timed!("clipboard_get_text", target = ?self.target);
+        let text = match &mut self.backend {
+            #[cfg(not(target_arch = "wasm32"))]
+            Backend::System(clipboard) => get(clipboard, self.target)?.text()?,
+            Backend::File(path) => read_file(path)?,
+            Backend::Stdio => stdio::read_stdin()?,
+        };
+        event!(bytes = text.len(), "fetched");
+
+        Ok(text)
+    }
+
+    /// Puts `text` into the clipboard
+    ///
+    /// # Errors
+    /// [`ConversionError::Clipboard`] or [`ConversionError::Io`] if it cannot be written
+    pub fn set_text(&mut self, text: impl AsRef<str>) -> Result<(), ConversionError> {
+        timed!("clipboard_set_text", target = ?self.target, bytes = text.as_ref().len());
+        match &mut self.backend {
+            #[cfg(not(target_arch = "wasm32"))]
+            Backend::System(clipboard) => Ok(set(clipboard, self.target)?.text(text.as_ref())?),
+            Backend::File(path) => Ok(fs::write(path, text.as_ref())?),
+            Backend::Stdio => stdio::write_to_stdout(text),
+        }
+    }
+
+    /// Fetches the HTML version of the clipboard contents. The fallback holds no separate
+    /// version, so its text is taken as HTML
+    ///
+    /// # Errors
+    /// - [`ConversionError::Clipboard`] if the system clipboard has no HTML version
+    /// - [`ConversionError::Io`] or [`ConversionError::Encoding`] if the fallback cannot be read
+    pub fn get_html(&mut self) -> Result<String, ConversionError> {
+        timed!("clipboard_get_html", target = ?self.target);
+        let html = match &mut self.backend {
+            #[cfg(not(target_arch = "wasm32"))]
+            Backend::System(clipboard) => get(clipboard, self.target)?.html()?,
+            _ => self.get_text()?,
+        };
+        event!(bytes = html.len(), "fetched");
+
+        Ok(html)
+    }
+
+    /// Puts `html` into the clipboard, with `alt_text` as the plain-text version. The fallback
+    /// only gets the HTML
+    ///
+    /// # Errors
+    /// [`ConversionError::Clipboard`] or [`ConversionError::Io`] if it cannot be written
+    #[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+    pub fn set_html(
+        &mut self,
+        html: impl AsRef<str>,
+        alt_text: impl AsRef<str>,
+    ) -> Result<(), ConversionError> {
+        timed!("clipboard_set_html", target = ?self.target, bytes = html.as_ref().len());
+        match &mut self.backend {
+            #[cfg(not(target_arch = "wasm32"))]
+            Backend::System(clipboard) => {
+                Ok(set(clipboard, self.target)?.html(html.as_ref(), Some(alt_text.as_ref()))?)
+            }
+            _ => self.set_text(html),
+        }
+    }
+
+    /// Fetches the image in the clipboard
+    ///
+    /// Not available on WebAssembly
+    ///
+    /// # Errors
+    /// - [`ConversionError::Clipboard`] if the system clipboard has no image
+    /// - [`ConversionError::ClipboardUnavailable`] for the fallback, which only holds text
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn get_image(&mut self) -> Result<ImageData<'static>, ConversionError> {
+        timed!("clipboard_get_image", target = ?self.target);
+        match &mut self.backend {
+            Backend::System(clipboard) => Ok(get(clipboard, self.target)?.image()?),
+            _ => Err(ConversionError::ClipboardUnavailable(format!(
+                "the {CLIPBOARD_FALLBACK_VAR} fallback only holds text, not images"
+            ))),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn open_system() -> Result<Backend, arboard::Error> {
+    Clipboard::new().map(Backend::System)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn open_system() -> Result<Backend, &'static str> {
+    Err("there is no system clipboard on WebAssembly")
+}
+
+/// Starts fetching from `target`
+#[cfg(not(target_arch = "wasm32"))]
+fn get(clipboard: &mut Clipboard, target: ClipboardTarget) -> Result<Get<'_>, ConversionError> {
+    let get = clipboard.get();
+    #[cfg(all(
+        unix,
+        not(any(target_os = "macos", target_os = "android", target_os = "emscripten"))
+    ))]
+    let get = get.clipboard(linux_kind(target));
+    #[cfg(not(all(
+        unix,
+        not(any(target_os = "macos", target_os = "android", target_os = "emscripten"))
+    )))]
+    if target == ClipboardTarget::Primary {
+        return Err(no_primary_selection());
+    }
+
+    Ok(get)
+}
+
+/// Starts writing to `target`
+#[cfg(not(target_arch = "wasm32"))]
+fn set(clipboard: &mut Clipboard, target: ClipboardTarget) -> Result<Set<'_>, ConversionError> {
+    let set = clipboard.set();
+    #[cfg(all(
+        unix,
+        not(any(target_os = "macos", target_os = "android", target_os = "emscripten"))
+    ))]
+    let set = set.clipboard(linux_kind(target));
+    #[cfg(not(all(
+        unix,
+        not(any(target_os = "macos", target_os = "android", target_os = "emscripten"))
+    )))]
+    if target == ClipboardTarget::Primary {
+        return Err(no_primary_selection());
+    }
+
+    Ok(set)
+}
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "macos", target_os = "android", target_os = "emscripten"))
+))]
+fn linux_kind(target: ClipboardTarget) -> LinuxClipboardKind {
+    match target {
+        ClipboardTarget::Clipboard => LinuxClipboardKind::Clipboard,
+        ClipboardTarget::Primary => LinuxClipboardKind::Primary,
+    }
+}
+
+#[cfg(not(any(
+    target_arch = "wasm32",
+    all(
+        unix,
+        not(any(target_os = "macos", target_os = "android", target_os = "emscripten"))
+    )
+)))]
+fn no_primary_selection() -> ConversionError {
+    ConversionError::ClipboardUnavailable("there is no primary selection on this system".to_owned())
+}
+
+fn read_file(path: &Path) -> Result<String, ConversionError> {
+    let bytes = fs::read(path)?;
+    Ok(String::from_utf8(bytes).map_err(|e| e.utf8_error())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_fallback() {
+        let path = env::temp_dir().join("text_converter_clipboard.txt");
+        let mut clipboard = TextClipboard {
+            backend: Backend::File(path.clone()),
+            target: ClipboardTarget::Primary,
+        };
+        assert!(!clipboard.is_system());
+
+        clipboard.set_html("<b>bold</b>", "bold").unwrap();
+        assert_eq!("<b>bold</b>", clipboard.get_text().unwrap());
+        clipboard.set_text("plain").unwrap();
+        assert_eq!("plain", clipboard.get_html().unwrap());
+        assert_eq!("plain", fs::read_to_string(&path).unwrap());
+
+        assert!(matches!(
+            clipboard.get_image(),
+            Err(ConversionError::ClipboardUnavailable(_))
+        ));
+    }
+}
@@ -0,0 +1,150 @@
+//! Converting the clipboard every time something new is copied
+
+use crate::{CancellationToken, Conversion, ConversionError, TextClipboard, TextConverter};
+use std::{thread, time::Duration};
+
+/// Iterator over the conversions made while watching the clipboard, returned by
+/// [`TextConverter::watch_clipboard`]
+///
+/// The clipboard is polled at a fixed interval, since not every platform reports changes to it.
+/// Each call to [`next`](Iterator::next) blocks until new text is copied, converts it and puts the
+/// output back into the clipboard. Text that was already there when watching started, copies of
+/// something other than text and the outputs themselves are not converted. Failing to convert
+/// does not stop the watch, cancelling its [token](Self::cancellation()) does
+pub struct ClipboardWatcher<'a, C: ?Sized> {
+    converter: &'a C,
+    clipboard: TextClipboard,
+    interval: Duration,
+    // Last text seen in the clipboard, or written to it
+    last: Option<String>,
+    cancellation: Option<CancellationToken>,
+    stopped: bool,
+}
+
+impl<'a, C: TextConverter + ?Sized> ClipboardWatcher<'a, C> {
+    pub(crate) fn new(converter: &'a C, interval: Duration) -> Result<Self, ConversionError> {
+        let mut clipboard = TextClipboard::new()?;
+        if clipboard.is_stdio() {
+            return Err(ConversionError::ClipboardUnavailable(
+                "stdin cannot be watched for changes".to_owned(),
+            ));
+        }
+        let last = clipboard.get_text().ok();
+
+        Ok(Self {
+            converter,
+            clipboard,
+            interval,
+            last,
+            cancellation: None,
+            stopped: false,
+        })
+    }
+
+    /// Stops watching once `token` is cancelled, yielding [`ConversionError::Cancelled`] after
+    /// the current interval
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    fn convert(&mut self, input: String) -> Result<Conversion, ConversionError> {
+        self.last = Some(input.clone());
+        let output = self.converter.try_converter(&input)?;
+
+        if output != input {
+            self.clipboard.set_text(&output)?;
+            self.last = Some(output.clone());
+        }
+
+        Ok(Conversion::new(input, output))
+    }
+}
+
+impl<C: TextConverter + ?Sized> Iterator for ClipboardWatcher<'_, C> {
+    type Item = Result<Conversion, ConversionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stopped {
+            return None;
+        }
+
+        loop {
+            thread::sleep(self.interval);
+            if self
+                .cancellation
+                .as_ref()
+                .is_some_and(CancellationToken::is_cancelled)
+            {
+                self.stopped = true;
+                return Some(Err(ConversionError::Cancelled));
+            }
+
+            match self.clipboard.get_text() {
+                Ok(text) if !text.is_empty() && self.last.as_ref() != Some(&text) => {
+                    return Some(self.convert(text))
+                }
+                // Nothing new, or something other than text was copied
+                Ok(_) => {}
+                #[cfg(not(target_arch = "wasm32"))]
+                Err(ConversionError::Clipboard(_)) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        clipboard::Backend,
+        fixtures::{self, Uppercase},
+        ClipboardTarget,
+    };
+    use std::fs;
+
+    #[test]
+    fn converts_new_copies() {
+        let path = fixtures::temp_dir("clipboard_watch").join("clipboard.txt");
+        fs::write(&path, "already there").unwrap();
+        let mut clipboard = TextClipboard {
+            backend: Backend::File(path.clone()),
+            target: ClipboardTarget::Clipboard,
+        };
+        let mut watcher = ClipboardWatcher {
+            converter: &Uppercase,
+            last: clipboard.get_text().ok(),
+            clipboard,
+            interval: Duration::from_millis(10),
+            cancellation: None,
+            stopped: false,
+        };
+
+        let writer = thread::spawn({
+            let path = path.clone();
+            move || {
+                thread::sleep(Duration::from_millis(50));
+                fs::write(path, "copied").unwrap();
+            }
+        });
+
+        let conversion = watcher.next().unwrap().unwrap();
+        writer.join().unwrap();
+        assert_eq!(
+            ("copied", "COPIED"),
+            (conversion.input.as_str(), conversion.output.as_str())
+        );
+        assert_eq!("COPIED", fs::read_to_string(&path).unwrap());
+        assert_eq!(Some("COPIED"), watcher.last.as_deref());
+
+        let token = CancellationToken::new();
+        let mut watcher = watcher.cancellation(token.clone());
+        token.cancel();
+        assert!(matches!(
+            watcher.next(),
+            Some(Err(ConversionError::Cancelled))
+        ));
+        assert!(watcher.next().is_none());
+    }
+}
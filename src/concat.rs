@@ -0,0 +1,117 @@
+use crate::{write_atomic, Bom, ConversionError, TextConverter};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Options for [`TextConverter::new_from_files`]
+///
+/// The header is a template where `{path}` is replaced by the path of each file and `{name}` by
+/// its file name
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::ConcatOptions;
+///
+/// let options = ConcatOptions::new("report.md")
+///     .header("## {name}\n\n")
+///     .separator("\n---\n\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConcatOptions {
+    output: PathBuf,
+    separator: String,
+    header: String,
+}
+
+impl ConcatOptions {
+    /// Joins the files with nothing between them, writing the output to `output`
+    pub fn new(output: impl Into<PathBuf>) -> Self {
+        Self {
+            output: output.into(),
+            separator: String::new(),
+            header: String::new(),
+        }
+    }
+
+    /// Text put between the contents of two files
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Template put before the contents of each file
+    pub fn header(mut self, header: impl Into<String>) -> Self {
+        self.header = header.into();
+        self
+    }
+
+    /// Path the output is written to
+    pub fn output(&self) -> &Path {
+        &self.output
+    }
+
+    fn header_for(&self, path: &Path) -> String {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        self.header
+            .replace("{path}", &path.display().to_string())
+            .replace("{name}", &name)
+    }
+}
+
+/// Reads every file in `paths` and joins them into one input, as configured by `options`
+pub(crate) fn concat(
+    paths: impl IntoIterator<Item = impl AsRef<Path>>,
+    options: &ConcatOptions,
+) -> Result<String, ConversionError> {
+    let mut input = String::new();
+
+    for (i, path) in paths.into_iter().enumerate() {
+        let path = path.as_ref();
+        let bytes = fs::read(path)?;
+        let (contents, _) = Bom::decode(&bytes)?;
+
+        if i > 0 {
+            input.push_str(&options.separator);
+        }
+        input.push_str(&options.header_for(path));
+        input.push_str(&contents);
+    }
+
+    Ok(input)
+}
+
+/// Implementation of [`TextConverter::try_new_from_files`]
+pub(crate) fn convert_files<C: TextConverter + ?Sized>(
+    converter: &C,
+    paths: impl IntoIterator<Item = impl AsRef<Path>>,
+    options: &ConcatOptions,
+) -> Result<String, ConversionError> {
+    let output = converter.try_converter(concat(paths, options)?)?;
+    write_atomic(&options.output, &output)?;
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, Uppercase};
+
+    #[test]
+    fn joins_files_with_headers() {
+        let dir = fixtures::temp_dir("concat");
+        fs::write(dir.join("a.txt"), "first\n").unwrap();
+        fs::write(dir.join("b.txt"), "\u{feff}second\n").unwrap();
+
+        let options = ConcatOptions::new(dir.join("all.txt"))
+            .header("# {name}\n")
+            .separator("\n");
+        let output =
+            convert_files(&Uppercase, [dir.join("a.txt"), dir.join("b.txt")], &options).unwrap();
+
+        assert_eq!("# A.TXT\nFIRST\n\n# B.TXT\nSECOND\n", output);
+        assert_eq!(output, fs::read_to_string(options.output()).unwrap());
+        assert!(convert_files(&Uppercase, [dir.join("missing.txt")], &options).is_err());
+    }
+}
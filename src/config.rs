@@ -0,0 +1,128 @@
+use crate::{pipeline::Pipeline, ConversionError, ConverterRegistry};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::{ffi::OsStr, fs, path::Path};
+
+/// Contents of a pipeline config file
+#[derive(Deserialize)]
+struct Recipe {
+    steps: Vec<Step>,
+}
+
+/// A converter of a pipeline, by name, and the fields it overrides
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Step {
+    pub(crate) converter: String,
+    #[serde(flatten)]
+    pub(crate) params: Map<String, Value>,
+}
+
+/// Implementation of [`Pipeline::from_config_with`]
+pub(crate) fn load(path: &Path, registry: &ConverterRegistry) -> Result<Pipeline, ConversionError> {
+    let text = fs::read_to_string(path)?;
+    let recipe = match path.extension().and_then(OsStr::to_str) {
+        Some("toml") => toml::from_str(&text).map_err(|e| toml_error(&text, e))?,
+        Some("yaml" | "yml") => {
+            serde_yaml::from_str(&text).map_err(crate::converters::yaml_error)?
+        }
+        _ => {
+            return Err(ConversionError::InvalidInput(format!(
+                "unknown config format for {}, expected a .toml, .yaml or .yml file",
+                path.display()
+            )))
+        }
+    };
+
+    build(recipe, registry)
+}
+
+fn build(recipe: Recipe, registry: &ConverterRegistry) -> Result<Pipeline, ConversionError> {
+    build_steps(&recipe.steps, registry)
+}
+
+/// Pipeline running `steps` in order, with the converters of `registry`
+pub(crate) fn build_steps(
+    steps: &[Step],
+    registry: &ConverterRegistry,
+) -> Result<Pipeline, ConversionError> {
+    let mut pipeline = Pipeline::new();
+    for step in steps {
+        pipeline.push_shared(registry.configure(&step.converter, &step.params)?);
+    }
+
+    Ok(pipeline)
+}
+
+pub(crate) fn toml_error(text: &str, e: toml::de::Error) -> ConversionError {
+    let start = e.span().map_or(0, |span| span.start);
+    let before = &text[..start.min(text.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+    ConversionError::Syntax {
+        format: "TOML",
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+        message: e.message().to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TextConverter;
+    use std::env;
+
+    #[test]
+    fn loads_toml_and_yaml() {
+        let dir = env::temp_dir().join("text_converter_config");
+        fs::create_dir_all(&dir).unwrap();
+
+        let toml = dir.join("recipe.toml");
+        fs::write(
+            &toml,
+            "[[steps]]\nconverter = \"trim\"\n\n[[steps]]\nconverter = \"wrap\"\nwidth = 5\n",
+        )
+        .unwrap();
+        let pipeline = Pipeline::from_config(&toml).unwrap();
+        assert_eq!(2, pipeline.len());
+        assert_eq!("ab cd\nef", pipeline.new_from_text("  ab cd ef  "));
+
+        let yaml = dir.join("recipe.yaml");
+        fs::write(
+            &yaml,
+            "steps:\n  - converter: upper\n  - converter: reverse\n",
+        )
+        .unwrap();
+        let pipeline = Pipeline::from_config(&yaml).unwrap();
+        assert_eq!("CBA", pipeline.new_from_text("abc"));
+    }
+
+    #[test]
+    fn invalid_configs() {
+        let registry = ConverterRegistry::with_builtins();
+        let recipe = |text| toml::from_str::<Recipe>(text).unwrap();
+
+        assert!(matches!(
+            build(recipe("[[steps]]\nconverter = \"shout\""), &registry),
+            Err(ConversionError::UnknownConverter(name)) if name == "shout"
+        ));
+        assert!(matches!(
+            build(
+                recipe("[[steps]]\nconverter = \"trim\"\nwidth = 1"),
+                &registry
+            ),
+            Err(ConversionError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            build(
+                recipe("[[steps]]\nconverter = \"wrap\"\nwidth = \"wide\""),
+                &registry
+            ),
+            Err(ConversionError::InvalidInput(_))
+        ));
+
+        let text = "[[steps]]\nconverter = \"trim\"\nwidth = \n";
+        let error = toml_error(text, toml::from_str::<Recipe>(text).err().unwrap());
+        assert!(matches!(error, ConversionError::Syntax { line: 3, .. }));
+    }
+}
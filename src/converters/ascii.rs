@@ -0,0 +1,103 @@
+//! Fast paths for the ASCII parts of a text, which big files are mostly made of
+//!
+//! ASCII bytes never appear inside the encoding of another character, so a text can be cut
+//! into [runs](runs()) of ASCII and non-ASCII characters without decoding it. ASCII runs are
+//! then handled a whole slice at a time, which the compiler vectorizes, and the others go
+//! through the usual Unicode path
+
+/// Bytes checked at once when looking for the end of an ASCII run
+const CHUNK: usize = 32;
+
+/// Part of a text made only of ASCII characters, or of no ASCII character at all
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Run<'a> {
+    Ascii(&'a str),
+    Other(&'a str),
+}
+
+/// Splits `input` into alternating runs of ASCII and non-ASCII characters, in order
+pub(crate) fn runs(input: &str) -> impl Iterator<Item = Run<'_>> {
+    let mut rest = input;
+    core::iter::from_fn(move || {
+        let bytes = rest.as_bytes();
+        let first = *bytes.first()?;
+
+        // Both ends fall on an ASCII byte or the end of the text, so on character boundaries
+        let len = match first.is_ascii() {
+            true => ascii_prefix_len(bytes),
+            false => non_ascii_prefix_len(bytes),
+        };
+        let (text, tail) = rest.split_at(len);
+        rest = tail;
+
+        Some(match first.is_ascii() {
+            true => Run::Ascii(text),
+            false => Run::Other(text),
+        })
+    })
+}
+
+/// Number of ASCII bytes `bytes` starts with
+fn ascii_prefix_len(bytes: &[u8]) -> usize {
+    let whole = bytes
+        .chunks_exact(CHUNK)
+        .take_while(|chunk| chunk.is_ascii())
+        .count()
+        * CHUNK;
+
+    whole
+        + bytes[whole..]
+            .iter()
+            .position(|byte| !byte.is_ascii())
+            .unwrap_or(bytes.len() - whole)
+}
+
+/// Number of non-ASCII bytes `bytes` starts with
+fn non_ascii_prefix_len(bytes: &[u8]) -> usize {
+    bytes.iter().position(u8::is_ascii).unwrap_or(bytes.len())
+}
+
+/// Replaces every ASCII byte of `text` with what `map` returns for it, when that is ASCII too
+pub(crate) fn map_in_place(text: &mut str, mut map: impl FnMut(u8) -> u8) {
+    // SAFETY: ASCII bytes are whole characters on their own, so replacing some with other ASCII
+    // bytes keeps the text valid UTF-8
+    let bytes = unsafe { text.as_bytes_mut() };
+    for byte in bytes.iter_mut().filter(|byte| byte.is_ascii()) {
+        let mapped = map(*byte);
+        if mapped.is_ascii() {
+            *byte = mapped;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_runs() {
+        let long = "a".repeat(CHUNK * 2 + 3);
+        let input = format!("{long}éà b😀");
+
+        assert_eq!(
+            vec![
+                Run::Ascii(&long),
+                Run::Other("éà"),
+                Run::Ascii(" b"),
+                Run::Other("😀")
+            ],
+            runs(&input).collect::<Vec<_>>()
+        );
+        assert_eq!(None, runs("").next());
+    }
+
+    #[test]
+    fn maps_only_ascii() {
+        let mut text = String::from("aé😀b");
+        map_in_place(&mut text, |byte| match byte {
+            b'a' => b'A',
+            _ => 0xff,
+        });
+        assert_eq!("Aé😀b", text);
+    }
+}
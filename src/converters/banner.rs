@@ -0,0 +1,196 @@
+use crate::TextConverter;
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+use figlet_rs::FIGlet;
+use std::sync::LazyLock;
+
+/// FIGlet font a [`Banner`] is drawn in, all embedded in the binary
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FigletFont {
+    /// The usual FIGlet font, 6 rows high
+    #[default]
+    Standard,
+    /// Narrower and 5 rows high, to fit more on a line
+    Small,
+    /// Taller, 8 rows high
+    Big,
+    /// Leaning to the right, 6 rows high
+    Slant,
+}
+
+impl FigletFont {
+    /// The font parsed, the first time it is used
+    fn figlet(self) -> &'static FIGlet {
+        fn parsed(font: Result<FIGlet, String>) -> FIGlet {
+            font.unwrap_or_else(|e| panic!("Failed to parse an embedded FIGlet font: {e}"))
+        }
+
+        static STANDARD: LazyLock<FIGlet> = LazyLock::new(|| parsed(FIGlet::standard()));
+        static SMALL: LazyLock<FIGlet> = LazyLock::new(|| parsed(FIGlet::small()));
+        static BIG: LazyLock<FIGlet> = LazyLock::new(|| parsed(FIGlet::big()));
+        static SLANT: LazyLock<FIGlet> = LazyLock::new(|| parsed(FIGlet::slant()));
+
+        match self {
+            Self::Standard => &STANDARD,
+            Self::Small => &SMALL,
+            Self::Big => &BIG,
+            Self::Slant => &SLANT,
+        }
+    }
+}
+
+/// Draws text as a large ASCII-art banner in a [`FigletFont`], to paste into chats and comments
+///
+/// Enabled by the `figlet-rs` feature. Words are moved to a new banner line when the current one
+/// would get wider than [width](Self::width) columns, and words too wide on their own are broken
+/// between letters. Line breaks of the input start a new banner line, and characters the font
+/// has no drawing for are left out. Trailing spaces are trimmed from every row
+///
+/// ```
+/// use text_converter::{converters::Banner, TextConverter};
+///
+/// let banner = Banner::default().new_from_text("Hi");
+/// assert_eq!(" _   _ _\n| | | (_)\n| |_| | |\n|  _  | |\n|_| |_|_|\n\n", banner);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Banner {
+    /// Font the text is drawn in
+    pub font: FigletFont,
+    /// Maximum width of the banner in columns, or 0 for no limit
+    pub width: usize,
+}
+
+impl Banner {
+    /// Creates a banner drawn in `font`, at most `width` columns wide
+    pub fn new(font: FigletFont, width: usize) -> Self {
+        Self { font, width }
+    }
+
+    /// `text` drawn in the font, with the trailing spaces of its rows trimmed
+    fn draw(&self, text: &str) -> String {
+        let Some(figure) = self.font.figlet().convert(text) else {
+            return String::new();
+        };
+
+        let mut output = String::new();
+        for row in figure.to_string().lines() {
+            output.push_str(row.trim_end());
+            output.push('\n');
+        }
+        output
+    }
+
+    fn fits(&self, text: &str) -> bool {
+        self.width == 0
+            || self
+                .draw(text)
+                .lines()
+                .all(|row| row.chars().count() <= self.width)
+    }
+
+    /// Pieces of `line` that each fit in the width, broken between words when possible
+    fn break_line(&self, line: &str) -> Vec<String> {
+        let mut pieces = Vec::new();
+        let mut current = String::new();
+
+        for word in line.split_whitespace() {
+            let joined = match current.is_empty() {
+                true => word.to_owned(),
+                false => format!("{current} {word}"),
+            };
+            if self.fits(&joined) {
+                current = joined;
+                continue;
+            }
+
+            if !current.is_empty() {
+                pieces.push(core::mem::take(&mut current));
+            }
+            for c in word.chars() {
+                current.push(c);
+                if current.chars().count() > 1 && !self.fits(&current) {
+                    current.pop();
+                    pieces.push(core::mem::replace(&mut current, c.into()));
+                }
+            }
+        }
+
+        if !current.is_empty() {
+            pieces.push(current);
+        }
+        pieces
+    }
+}
+
+impl Default for Banner {
+    fn default() -> Self {
+        Self::new(FigletFont::Standard, 80)
+    }
+}
+
+impl TextConverter for Banner {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::new();
+        for line in input.as_ref().lines() {
+            for piece in self.break_line(line) {
+                output.push_str(&self.draw(&piece));
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breaks_lines_to_the_width() {
+        let banner = Banner::new(FigletFont::Small, 30);
+        let output = banner.new_from_text("hello big world\nok");
+        assert!(output.lines().all(|row| row.chars().count() <= 30));
+        assert_eq!(
+            [
+                banner.draw("hello"),
+                banner.draw("big"),
+                banner.draw("world"),
+                banner.draw("ok")
+            ]
+            .concat(),
+            output
+        );
+
+        let unlimited = Banner::new(FigletFont::Small, 0);
+        assert_eq!(
+            unlimited.draw("hello big world"),
+            unlimited.new_from_text("hello big world")
+        );
+    }
+
+    #[test]
+    fn breaks_long_words() {
+        let banner = Banner::new(FigletFont::Big, 20);
+        let output = banner.new_from_text("abcdefghij");
+        assert!(output.lines().all(|row| row.chars().count() <= 20));
+        assert!(output.lines().count() > 8);
+        assert_eq!("", banner.new_from_text(""));
+        assert_eq!("", banner.new_from_text("\u{1f600}"));
+    }
+
+    #[test]
+    fn fonts_differ() {
+        let drawings = [
+            FigletFont::Standard,
+            FigletFont::Small,
+            FigletFont::Big,
+            FigletFont::Slant,
+        ]
+        .map(|font| Banner::new(font, 0).new_from_text("Ship it"));
+        for (i, drawing) in drawings.iter().enumerate() {
+            assert!(!drawing.is_empty());
+            assert!(!drawings[i + 1..].contains(drawing));
+        }
+    }
+}
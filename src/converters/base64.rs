@@ -0,0 +1,254 @@
+use crate::{ConversionError, ReversibleConverter, TextConverter};
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+
+const STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Set of characters Base64 text is written with
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Base64Alphabet {
+    /// Uses `+` and `/`
+    #[default]
+    Standard,
+    /// Uses `-` and `_`, to be safe in URLs and file names
+    UrlSafe,
+}
+
+impl Base64Alphabet {
+    fn symbols(self) -> &'static [u8; 64] {
+        match self {
+            Self::Standard => STANDARD,
+            Self::UrlSafe => URL_SAFE,
+        }
+    }
+
+    fn value(self, symbol: u8) -> Option<u8> {
+        self.symbols()
+            .iter()
+            .position(|&s| s == symbol)
+            .map(|value| value as u8)
+    }
+}
+
+/// Encodes the UTF-8 bytes of the text as Base64
+///
+/// ```
+/// use text_converter::{converters::Base64Encode, ReversibleConverter, TextConverter};
+///
+/// assert_eq!("aGk/Pz8=", Base64Encode::new().new_from_text("hi???"));
+/// assert_eq!("aGk_Pz8", Base64Encode::url_safe().new_from_text("hi???"));
+/// assert_eq!("hi???", Base64Encode::url_safe().decode_from_text("aGk_Pz8"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Base64Encode {
+    /// Characters the output is written with
+    pub alphabet: Base64Alphabet,
+    /// Whether to pad the output with `=` to a multiple of 4 characters
+    pub padding: bool,
+}
+
+impl Base64Encode {
+    /// Standard alphabet, with padding
+    pub fn new() -> Self {
+        Self {
+            alphabet: Base64Alphabet::Standard,
+            padding: true,
+        }
+    }
+
+    /// URL-safe alphabet, without padding
+    pub fn url_safe() -> Self {
+        Self {
+            alphabet: Base64Alphabet::UrlSafe,
+            padding: false,
+        }
+    }
+}
+
+impl Default for Base64Encode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextConverter for Base64Encode {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let symbols = self.alphabet.symbols();
+        let bytes = input.as_ref().as_bytes();
+        let mut output = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+        for chunk in bytes.chunks(3) {
+            let group = chunk.iter().enumerate().fold(0u32, |group, (i, &byte)| {
+                group | u32::from(byte) << (16 - 8 * i)
+            });
+
+            for i in 0..=chunk.len() {
+                output.push(char::from(symbols[(group >> (18 - 6 * i) & 0x3f) as usize]));
+            }
+            if self.padding {
+                output.extend(core::iter::repeat_n('=', 3 - chunk.len()));
+            }
+        }
+
+        output
+    }
+}
+
+impl ReversibleConverter for Base64Encode {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        Base64Decode::new(self.alphabet).converter(input)
+    }
+
+    fn try_deconverter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        Base64Decode::new(self.alphabet).try_converter(input)
+    }
+}
+
+/// Decodes Base64 text, with or without padding. Whitespace, such as line breaks in wrapped
+/// Base64, is ignored
+///
+/// The [converter](TextConverter::converter()) method panics on invalid input, use the `try_*`
+/// methods to get an error instead
+///
+/// ```
+/// use text_converter::{converters::Base64Decode, TextConverter};
+///
+/// assert_eq!("hello", Base64Decode::default().new_from_text("aGVsbG8="));
+/// assert!(Base64Decode::default().try_new_from_text("not base64!").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Base64Decode {
+    /// Characters the input is expected to be written with
+    pub alphabet: Base64Alphabet,
+}
+
+impl Base64Decode {
+    /// Creates a decoder for text written with `alphabet`
+    pub fn new(alphabet: Base64Alphabet) -> Self {
+        Self { alphabet }
+    }
+
+    fn decode(&self, input: &str) -> Result<Vec<u8>, ConversionError> {
+        let symbols: Vec<u8> = input.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+        let data = symbols
+            .strip_suffix(b"==")
+            .or_else(|| symbols.strip_suffix(b"="))
+            .unwrap_or(&symbols);
+
+        let padded = data.len() != symbols.len();
+        if data.len() % 4 == 1 || (padded && !symbols.len().is_multiple_of(4)) {
+            return Err(ConversionError::InvalidInput(
+                "Base64 input has an invalid length".to_owned(),
+            ));
+        }
+
+        let mut output = Vec::with_capacity(data.len() / 4 * 3 + 2);
+        for chunk in data.chunks(4) {
+            let mut group = 0u32;
+            for (i, &symbol) in chunk.iter().enumerate() {
+                let value = self.alphabet.value(symbol).ok_or_else(|| {
+                    ConversionError::InvalidInput(format!(
+                        "`{}` is not a Base64 character",
+                        char::from(symbol)
+                    ))
+                })?;
+                group |= u32::from(value) << (18 - 6 * i);
+            }
+
+            output.extend(group.to_be_bytes()[1..chunk.len()].iter());
+        }
+
+        Ok(output)
+    }
+}
+
+impl TextConverter for Base64Decode {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Failed to decode Base64: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let bytes = self.decode(input.as_ref())?;
+        String::from_utf8(bytes).map_err(|e| e.utf8_error().into())
+    }
+}
+
+impl ReversibleConverter for Base64Decode {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        Base64Encode {
+            alphabet: self.alphabet,
+            padding: self.alphabet == Base64Alphabet::Standard,
+        }
+        .converter(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc_4648_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+
+        for (text, encoded) in vectors {
+            assert_eq!(encoded, Base64Encode::new().new_from_text(text));
+            assert_eq!(text, Base64Decode::default().new_from_text(encoded));
+            assert_eq!(
+                text,
+                Base64Decode::default().new_from_text(encoded.trim_end_matches('='))
+            );
+        }
+    }
+
+    #[test]
+    fn url_safe_and_unicode() {
+        let text = "ünïcödé ~~~???";
+        let encoded = Base64Encode::url_safe().new_from_text(text);
+        assert!(!encoded.contains(['+', '/', '=']));
+        assert_eq!(
+            text,
+            Base64Decode::new(Base64Alphabet::UrlSafe).new_from_text(encoded)
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        let decoder = Base64Decode::default();
+        assert_eq!("hello", decoder.new_from_text("aGVs\nbG8=\n"));
+        assert!(matches!(
+            decoder.try_new_from_text("aGVsbG8*"),
+            Err(ConversionError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            decoder.try_new_from_text("aGVsb"),
+            Err(ConversionError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            decoder.try_new_from_text("aGVsbG8"),
+            Ok(text) if text == "hello"
+        ));
+        assert!(matches!(
+            decoder.try_new_from_text("aGVsbG8=="),
+            Err(ConversionError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            decoder.try_new_from_text("/w=="),
+            Err(ConversionError::Encoding(_))
+        ));
+    }
+}
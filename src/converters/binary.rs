@@ -0,0 +1,161 @@
+use crate::{BytesConverter, ConversionError, ReversibleConverter, TextConverter};
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+
+/// Writes the UTF-8 bytes of text as groups of 8 binary digits, most significant bit first
+///
+/// Groups hold [group](Self::group) bytes each and are joined by the
+/// [separator](Self::separator). Decoding drops the separators and any whitespace, and needs the
+/// remaining digits to make whole bytes of valid UTF-8. As a [`BytesConverter`] it encodes any
+/// bytes
+///
+/// ```
+/// use text_converter::{converters::Binary, ReversibleConverter, TextConverter};
+///
+/// assert_eq!("01101000 01101001", Binary::default().new_from_text("hi"));
+/// assert_eq!("0110100001101001", Binary::new(2, ",").new_from_text("hi"));
+/// assert_eq!("hi", Binary::new(1, ",").decode_from_text("01101000,01101001"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Binary {
+    /// Number of bytes in each group, at least 1
+    pub group: usize,
+    /// Written between groups
+    pub separator: String,
+}
+
+impl Binary {
+    /// Creates an encoder writing `group` bytes at a time, joined by `separator`
+    pub fn new(group: usize, separator: impl Into<String>) -> Self {
+        Self {
+            group,
+            separator: separator.into(),
+        }
+    }
+
+    fn encode(&self, bytes: &[u8]) -> String {
+        let group = self.group.max(1);
+        let groups = bytes.len().div_ceil(group);
+        let mut output = String::with_capacity(
+            bytes.len() * 8 + groups.saturating_sub(1) * self.separator.len(),
+        );
+
+        for (i, chunk) in bytes.chunks(group).enumerate() {
+            if i > 0 {
+                output.push_str(&self.separator);
+            }
+            for byte in chunk {
+                output.push_str(&format!("{byte:08b}"));
+            }
+        }
+
+        output
+    }
+
+    fn decode(&self, input: &str) -> Result<Vec<u8>, ConversionError> {
+        let digits = match self.separator.is_empty() {
+            true => input.to_owned(),
+            false => input.replace(&*self.separator, ""),
+        };
+
+        let mut bits = Vec::with_capacity(digits.len());
+        for c in digits.chars().filter(|c| !c.is_whitespace()) {
+            match c {
+                '0' | '1' => bits.push(u8::from(c == '1')),
+                _ => {
+                    return Err(ConversionError::InvalidInput(format!(
+                        "`{c}` is not a binary digit"
+                    )))
+                }
+            }
+        }
+        if bits.len() % 8 != 0 {
+            return Err(ConversionError::InvalidInput(
+                "binary input is not a whole number of bytes".to_owned(),
+            ));
+        }
+
+        Ok(bits
+            .chunks(8)
+            .map(|bits| bits.iter().fold(0, |byte, bit| byte << 1 | bit))
+            .collect())
+    }
+}
+
+impl Default for Binary {
+    fn default() -> Self {
+        Self::new(1, " ")
+    }
+}
+
+impl TextConverter for Binary {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.encode(input.as_ref().as_bytes())
+    }
+}
+
+impl ReversibleConverter for Binary {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        self.try_deconverter(input)
+            .unwrap_or_else(|e| panic!("Failed to decode binary: {e}"))
+    }
+
+    fn try_deconverter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let bytes = self.decode(input.as_ref())?;
+        String::from_utf8(bytes).map_err(|e| e.utf8_error().into())
+    }
+}
+
+impl BytesConverter for Binary {
+    fn converter(&self, input: &[u8]) -> Vec<u8> {
+        self.encode(input).into_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_and_separators() {
+        assert_eq!("", Binary::default().new_from_text(""));
+        assert_eq!(
+            "11000011 10101001 00100001",
+            Binary::default().new_from_text("é!")
+        );
+        assert_eq!(
+            "1100001110101001 | 00100001",
+            Binary::new(2, " | ").new_from_text("é!")
+        );
+        assert_eq!("01100001-01100010", Binary::new(0, "-").new_from_text("ab"));
+        assert_eq!(
+            b"00000000 11111111".to_vec(),
+            Binary::default().new_from_bytes([0x00, 0xff])
+        );
+    }
+
+    #[test]
+    fn decodes() {
+        let binary = Binary::new(2, "|");
+        let text = "Hello, Wörld 😀\n";
+        assert_eq!(text, binary.decode_from_text(binary.new_from_text(text)));
+        assert_eq!(
+            "hi",
+            Binary::default().decode_from_text("0110 1000\n01101001")
+        );
+
+        assert!(matches!(
+            Binary::default().try_decode_from_text("01101000 0110100"),
+            Err(ConversionError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            Binary::default().try_decode_from_text("01101000 0110100é"),
+            Err(ConversionError::InvalidInput(message)) if message.contains('é')
+        ));
+        assert!(matches!(
+            Binary::default().try_decode_from_text("11111111"),
+            Err(ConversionError::Encoding(_))
+        ));
+    }
+}
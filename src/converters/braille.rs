@@ -0,0 +1,334 @@
+use super::Unmappable;
+use crate::{ConversionError, ReversibleConverter, TextConverter};
+use alloc::{string::String, string::ToString};
+use core::iter::Peekable;
+
+/// Raised dots of the letters, from `a` to `z`, numbered down the left column then the right one
+const LETTERS: [u32; 26] = [
+    1, 12, 14, 145, 15, 124, 1245, 125, 24, 245, 13, 123, 134, 1345, 135, 1234, 12345, 1235, 234,
+    2345, 136, 1236, 2456, 1346, 13456, 1356,
+];
+
+/// Raised dots of the punctuation written in a single cell
+const PUNCTUATION: &[(char, u32)] = &[
+    (',', 2),
+    (';', 23),
+    (':', 25),
+    ('.', 256),
+    ('!', 235),
+    ('?', 236),
+    ('\'', 3),
+    ('-', 36),
+];
+
+/// Punctuation written in two cells, the first of which is a prefix
+const PREFIXED: &[(char, u32, u32)] = &[('(', 5, 126), (')', 5, 345), ('/', 456, 34)];
+
+/// Dots of the indicators, which change how the cells after them read
+const CAPITAL: u32 = 6;
+const NUMERIC: u32 = 3456;
+const GRADE_1: u32 = 56;
+/// Dots of the cell after a capital indicator that ends a capitalized word early
+const CAPITALS_TERMINATOR: u32 = 3;
+
+/// Writes text as Unicode Braille patterns, in uncontracted (Grade 1) Unified English Braille
+///
+/// Capitals get the capital indicator `⠠`, or `⠠⠠` for words in capitals, and numbers the
+/// numeric indicator `⠼`, with the letters `a` to `j` as digits. A letter from `a` to `j` right
+/// after a number gets the Grade 1 indicator `⠰`, so it does not read as a digit. Whitespace is
+/// kept as is, and decoding also reads the blank pattern `⠀` as a space
+///
+/// ```
+/// use text_converter::{converters::Braille, ReversibleConverter, TextConverter};
+///
+/// assert_eq!("⠠⠓⠑⠇⠇⠕ ⠠⠠⠥⠝ ⠼⠁⠃", Braille::default().new_from_text("Hello UN 12"));
+/// assert_eq!("Hello UN 12", Braille::default().decode_from_text("⠠⠓⠑⠇⠇⠕ ⠠⠠⠥⠝ ⠼⠁⠃"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Braille {
+    /// What to do with characters that have no Braille pattern, and with unknown patterns when
+    /// decoding
+    pub unmappable: Unmappable,
+}
+
+impl Braille {
+    /// Creates a converter handling unmappable characters as given, or as a
+    /// [`ConversionMode`](crate::ConversionMode) says
+    pub fn new(unmappable: impl Into<Unmappable>) -> Self {
+        Self {
+            unmappable: unmappable.into(),
+        }
+    }
+
+    fn encode(&self, input: &str) -> Result<String, ConversionError> {
+        let mut output = String::with_capacity(input.len() * 3);
+        let (mut numeric, mut capitals) = (false, false);
+
+        for (i, c) in input.char_indices() {
+            let rest = &input[i + c.len_utf8()..];
+            match c {
+                _ if c.is_whitespace() => {
+                    output.push(c);
+                    (numeric, capitals) = (false, false);
+                }
+                '0'..='9' => {
+                    if !numeric {
+                        output.push(cell(NUMERIC));
+                        numeric = true;
+                    }
+                    let letter = match c {
+                        '0' => 'j',
+                        _ => (b'a' + (c as u8 - b'1')) as char,
+                    };
+                    output.push(letter_cell(letter));
+                }
+                'A'..='Z' => {
+                    let letter = c.to_ascii_lowercase();
+                    if capitals && numeric && letter <= 'j' {
+                        output.push(cell(GRADE_1));
+                    } else if !capitals {
+                        output.push(cell(CAPITAL));
+                        let word = rest.chars().take_while(char::is_ascii_alphabetic);
+                        if rest.starts_with(|next: char| next.is_ascii_uppercase())
+                            && word.clone().all(|next| next.is_ascii_uppercase())
+                        {
+                            output.push(cell(CAPITAL));
+                            capitals = true;
+                        }
+                    }
+                    output.push(letter_cell(letter));
+                    numeric = false;
+
+                    // Lowercase letters later in the word would read as capitals too
+                    let word_rest = rest.split(char::is_whitespace).next().unwrap_or_default();
+                    if capitals
+                        && !rest.starts_with(|next: char| next.is_ascii_alphabetic())
+                        && word_rest.contains(|next: char| next.is_ascii_lowercase())
+                    {
+                        output.extend([cell(CAPITAL), cell(CAPITALS_TERMINATOR)]);
+                        capitals = false;
+                    }
+                }
+                'a'..='z' => {
+                    if numeric && c <= 'j' {
+                        output.push(cell(GRADE_1));
+                    }
+                    output.push(letter_cell(c));
+                    numeric = false;
+                }
+                _ => {
+                    numeric = false;
+                    let cells = PUNCTUATION
+                        .iter()
+                        .find(|(punctuation, _)| *punctuation == c)
+                        .map(|&(_, dots)| cell(dots).to_string())
+                        .or_else(|| {
+                            PREFIXED
+                                .iter()
+                                .find(|(punctuation, _, _)| *punctuation == c)
+                                .map(|&(_, prefix, dots)| {
+                                    [cell(prefix), cell(dots)].iter().collect()
+                                })
+                        });
+                    if let Some(cells) = self.unmappable.resolve(cells, c.to_string(), "Braille")? {
+                        output.push_str(&cells);
+                    }
+                }
+            }
+        }
+
+        Ok(output)
+    }
+
+    fn decode(&self, input: &str) -> Result<String, ConversionError> {
+        let mut output = String::with_capacity(input.len() / 3);
+        let (mut numeric, mut capitals, mut capital) = (false, false, false);
+        let mut cells = input.chars().peekable();
+
+        while let Some(c) = cells.next() {
+            if c.is_whitespace() || c == cell(0) {
+                output.push(match c == cell(0) {
+                    true => ' ',
+                    false => c,
+                });
+                (numeric, capitals, capital) = (false, false, false);
+                continue;
+            }
+
+            let Some(raised) = dots(c) else {
+                self.push_unmappable(&mut output, c.to_string())?;
+                numeric = false;
+                continue;
+            };
+            match raised {
+                NUMERIC => numeric = true,
+                GRADE_1 => numeric = false,
+                CAPITAL => {
+                    numeric = false;
+                    match cells.peek().copied().and_then(dots) {
+                        Some(CAPITAL) => {
+                            cells.next();
+                            capitals = true;
+                        }
+                        Some(CAPITALS_TERMINATOR) if capitals => {
+                            cells.next();
+                            capitals = false;
+                        }
+                        _ => capital = true,
+                    }
+                }
+                _ => {
+                    let letter = LETTERS
+                        .iter()
+                        .position(|&letter| letter == raised)
+                        .map(|i| (b'a' + i as u8) as char);
+                    match letter {
+                        Some(letter) if numeric && letter <= 'j' => output.push(match letter {
+                            'j' => '0',
+                            _ => (b'1' + (letter as u8 - b'a')) as char,
+                        }),
+                        Some(letter) => {
+                            numeric = false;
+                            output.push(match capital || capitals {
+                                true => letter.to_ascii_uppercase(),
+                                false => letter,
+                            });
+                            capital = false;
+                        }
+                        None => {
+                            numeric = false;
+                            match punctuation(raised, &mut cells) {
+                                Some(punctuation) => output.push(punctuation),
+                                None => self.push_unmappable(&mut output, c.to_string())?,
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(output)
+    }
+
+    fn push_unmappable(
+        &self,
+        output: &mut String,
+        original: String,
+    ) -> Result<(), ConversionError> {
+        if let Some(text) = self
+            .unmappable
+            .resolve(None, original, "text from Braille")?
+        {
+            output.push_str(&text);
+        }
+        Ok(())
+    }
+}
+
+/// Braille pattern with the given dots raised, written as their digits like `1245`
+fn cell(mut dots: u32) -> char {
+    let mut bits = 0;
+    while dots > 0 {
+        bits |= 1 << (dots % 10 - 1);
+        dots /= 10;
+    }
+    char::from_u32(0x2800 + bits).unwrap_or(' ')
+}
+
+fn letter_cell(letter: char) -> char {
+    cell(LETTERS[(letter as u8 - b'a') as usize])
+}
+
+/// Dots raised in a Braille pattern, in the form [cell] takes, if `c` is one
+fn dots(c: char) -> Option<u32> {
+    let bits = (c as u32).checked_sub(0x2800).filter(|bits| *bits < 0x40)?;
+    Some(
+        (0..6)
+            .filter(|dot| bits & (1 << dot) != 0)
+            .fold(0, |dots, dot| dots * 10 + dot + 1),
+    )
+}
+
+/// Punctuation starting with the cell with `dots` raised, taking the second cell if it has two
+fn punctuation(dots: u32, cells: &mut Peekable<impl Iterator<Item = char>>) -> Option<char> {
+    let prefixed = cells.peek().copied().and_then(self::dots).and_then(|next| {
+        PREFIXED
+            .iter()
+            .find(|&&(_, prefix, second)| (prefix, second) == (dots, next))
+    });
+    if let Some(&(punctuation, _, _)) = prefixed {
+        cells.next();
+        return Some(punctuation);
+    }
+
+    PUNCTUATION
+        .iter()
+        .find(|&&(_, known)| known == dots)
+        .map(|&(punctuation, _)| punctuation)
+}
+
+impl TextConverter for Braille {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Failed to convert to Braille: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.encode(input.as_ref())
+    }
+}
+
+impl ReversibleConverter for Braille {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        self.try_deconverter(input)
+            .unwrap_or_else(|e| panic!("Failed to decode Braille: {e}"))
+    }
+
+    fn try_deconverter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.decode(input.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indicators() {
+        let braille = Braille::default();
+        assert_eq!("⠠⠁⠃⠉", braille.new_from_text("Abc"));
+        assert_eq!("⠠⠠⠁⠃⠉ ⠁", braille.new_from_text("ABC a"));
+        assert_eq!("⠠⠠⠁⠃⠠⠄⠤⠉", braille.new_from_text("AB-c"));
+        assert_eq!("⠠⠠⠁⠃⠤⠉", braille.new_from_text("AB-C"));
+        assert_eq!("⠼⠁⠚⠰⠁⠅", braille.new_from_text("10ak"));
+        assert_eq!("⠼⠁⠅ ⠼⠃⠠⠁", braille.new_from_text("1k 2A"));
+        assert_eq!("⠐⠣⠓⠊⠖⠐⠜ ⠸⠌", braille.new_from_text("(hi!) /"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let braille = Braille::default();
+        let text = "The QUICK brown FOX-jumps, over 13 lazy dogs.\nIt's 2024a (or 9z)? OK/NO; x1A:B AB1C'd";
+        assert_eq!(text, braille.decode_from_text(braille.new_from_text(text)));
+        assert_eq!("a b", braille.decode_from_text("⠁⠀⠃"));
+    }
+
+    #[test]
+    fn unmappable_policies() {
+        assert_eq!("⠁⠃", Braille::new(Unmappable::Skip).new_from_text("a#b"));
+        assert_eq!(
+            "⠁#⠃",
+            Braille::new(Unmappable::Passthrough).new_from_text("a#b")
+        );
+        assert!(Braille::new(Unmappable::Error)
+            .try_new_from_text("a#b")
+            .is_err());
+
+        assert_eq!("ab", Braille::new(Unmappable::Skip).decode_from_text("⠁⠿⠃"));
+        assert!(Braille::new(Unmappable::Error)
+            .try_decode_from_text("⠁⠿⠃")
+            .is_err());
+    }
+}
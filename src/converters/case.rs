@@ -0,0 +1,266 @@
+use super::{
+    ascii::{self, Run},
+    Rng,
+};
+use crate::TextConverter;
+use alloc::string::String;
+
+/// Converts every character to uppercase
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Uppercase;
+
+impl TextConverter for Uppercase {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+        self.converter_into(input.as_ref(), &mut output);
+        output
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+
+        for run in ascii::runs(input) {
+            match run {
+                Run::Ascii(text) => {
+                    let start = output.len();
+                    output.push_str(text);
+                    output[start..].make_ascii_uppercase();
+                }
+                Run::Other(text) => output.extend(text.chars().flat_map(char::to_uppercase)),
+            }
+        }
+    }
+
+    fn converter_in_place(&self, text: &mut str) -> bool {
+        if !text.is_ascii() {
+            return false;
+        }
+        text.make_ascii_uppercase();
+        true
+    }
+}
+
+/// Converts every character to lowercase
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lowercase;
+
+impl TextConverter for Lowercase {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+        self.converter_into(input.as_ref(), &mut output);
+        output
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+
+        for run in ascii::runs(input) {
+            match run {
+                Run::Ascii(text) => {
+                    let start = output.len();
+                    output.push_str(text);
+                    output[start..].make_ascii_lowercase();
+                }
+                // Whether a capital sigma becomes a final sigma depends on the letters around
+                // it, which can be in the runs next to it
+                Run::Other(text) if text.contains('Σ') => {
+                    *output = input.to_lowercase();
+                    return;
+                }
+                Run::Other(text) => output.extend(text.chars().flat_map(char::to_lowercase)),
+            }
+        }
+    }
+
+    fn converter_in_place(&self, text: &mut str) -> bool {
+        if !text.is_ascii() {
+            return false;
+        }
+        text.make_ascii_lowercase();
+        true
+    }
+}
+
+/// Capitalizes the first letter of every word and lowercases the rest
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TitleCase;
+
+impl TextConverter for TitleCase {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+        self.converter_into(input.as_ref(), &mut output);
+        output
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+
+        let mut word_start = true;
+
+        for c in input.chars() {
+            if word_start {
+                output.extend(c.to_uppercase());
+            } else {
+                output.extend(c.to_lowercase());
+            }
+            word_start = c.is_whitespace();
+        }
+    }
+}
+
+/// Turns uppercase characters into lowercase ones and vice versa
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwapCase;
+
+impl TextConverter for SwapCase {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+        self.converter_into(input.as_ref(), &mut output);
+        output
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+
+        for c in input.chars() {
+            if c.is_uppercase() {
+                output.extend(c.to_lowercase());
+            } else {
+                output.extend(c.to_uppercase());
+            }
+        }
+    }
+
+    fn converter_in_place(&self, text: &mut str) -> bool {
+        if !text.is_ascii() {
+            return false;
+        }
+        ascii::map_in_place(text, |byte| match byte.is_ascii_alphabetic() {
+            true => byte ^ 0x20,
+            false => byte,
+        });
+        true
+    }
+}
+
+/// "Mocking SpongeBob" case, switching between lowercase and uppercase letters. Characters that
+/// are not letters are kept and don't count towards the alternation
+///
+/// ```
+/// use text_converter::{converters::MockingCase, TextConverter};
+///
+/// assert_eq!("sPoNgE-bOb", MockingCase::Alternate.new_from_text("sponge-bob"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MockingCase {
+    /// Strictly alternates, starting with a lowercase letter
+    #[default]
+    Alternate,
+    /// Picks the case of every letter at random, differently on every conversion
+    Random,
+    /// Picks the case of every letter at random, the same way every time for a given seed
+    Seeded(u64),
+}
+
+impl TextConverter for MockingCase {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+        self.converter_into(input.as_ref(), &mut output);
+        output
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+
+        let mut rng = match self {
+            Self::Seeded(seed) => Rng::new(Some(*seed)),
+            _ => Rng::new(None),
+        };
+        let mut upper = true;
+
+        for c in input.chars() {
+            if !c.is_alphabetic() {
+                output.push(c);
+                continue;
+            }
+
+            upper = match self {
+                Self::Alternate => !upper,
+                Self::Random | Self::Seeded(_) => rng.next_u64() & 1 == 1,
+            };
+            if upper {
+                output.extend(c.to_uppercase());
+            } else {
+                output.extend(c.to_lowercase());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_cases() {
+        assert_eq!("HELLO, WORLD", Uppercase.new_from_text("Hello, World"));
+        assert_eq!("hello, world", Lowercase.new_from_text("Hello, World"));
+    }
+
+    #[test]
+    fn mixed_scripts() {
+        let input = "Straße İstanbul ÉTÉ ǅ";
+        assert_eq!(input.to_uppercase(), Uppercase.new_from_text(input));
+        assert_eq!(input.to_lowercase(), Lowercase.new_from_text(input));
+        assert_eq!("οδος ασa", Lowercase.new_from_text("ΟΔΟΣ ΑΣa"));
+    }
+
+    #[test]
+    fn in_place() {
+        let mut text = String::from("Hello, World");
+        assert!(Uppercase.converter_in_place(&mut text));
+        assert_eq!("HELLO, WORLD", text);
+        assert!(Lowercase.converter_in_place(&mut text));
+        assert_eq!("hello, world", text);
+        assert!(SwapCase.converter_in_place(&mut text));
+        assert_eq!("HELLO, WORLD", text);
+
+        let mut text = String::from("straße");
+        assert!(!SwapCase.converter_in_place(&mut text));
+        assert_eq!("straße", text);
+        SwapCase.convert_string_in_place(&mut text);
+        assert_eq!("STRASSE", text);
+    }
+
+    #[test]
+    fn title_case() {
+        assert_eq!(
+            "Hello World\nAgain",
+            TitleCase.new_from_text("hELLO world\nagain")
+        );
+    }
+
+    #[test]
+    fn mocking_case() {
+        assert_eq!(
+            "hElLo, WoRlD!",
+            MockingCase::Alternate.new_from_text("Hello, world!")
+        );
+
+        let text = "the quick brown fox jumps over the lazy dog";
+        let seeded = MockingCase::Seeded(42).new_from_text(text);
+        assert_eq!(seeded, MockingCase::Seeded(42).new_from_text(text));
+        assert_eq!(text, seeded.to_lowercase());
+        assert!(seeded.chars().any(char::is_uppercase));
+        assert!(seeded.chars().any(char::is_lowercase));
+
+        let random = MockingCase::Random.new_from_text(text);
+        assert_eq!(text, random.to_lowercase());
+    }
+
+    #[test]
+    fn swap_case() {
+        assert_eq!("hELLO wORLD 42", SwapCase.new_from_text("Hello World 42"));
+    }
+}
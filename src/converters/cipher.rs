@@ -0,0 +1,249 @@
+use super::ascii;
+use crate::{ReversibleConverter, TextConverter};
+use alloc::{string::String, vec::Vec};
+
+/// Shifts ASCII letters forward in the alphabet by `shift` places, wrapping around from `z` to
+/// `a`. Case is kept and other characters are left untouched
+///
+/// ```
+/// use text_converter::{converters::CaesarCipher, ReversibleConverter, TextConverter};
+///
+/// let cipher = CaesarCipher::new(3);
+/// assert_eq!("Khoor, Zruog!", cipher.new_from_text("Hello, World!"));
+/// assert_eq!("Hello, World!", cipher.decode_from_text("Khoor, Zruog!"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct CaesarCipher {
+    /// Number of places letters are shifted by
+    pub shift: u8,
+}
+
+impl CaesarCipher {
+    /// Creates a cipher shifting letters by `shift` places
+    pub fn new(shift: u8) -> Self {
+        Self { shift }
+    }
+
+    fn shifted(input: &str, shift: u8) -> String {
+        let mut output = String::with_capacity(input.len());
+        Self::shifted_into(input, shift, &mut output);
+        output
+    }
+
+    fn shifted_into(input: &str, shift: u8, output: &mut String) {
+        output.clear();
+        output.push_str(input);
+        ascii::map_in_place(output, |byte| shift_letter(byte, shift));
+    }
+}
+
+/// `byte` shifted by `shift` places if it is an ASCII letter
+fn shift_letter(byte: u8, shift: u8) -> u8 {
+    let base = match byte {
+        b'a'..=b'z' => b'a',
+        b'A'..=b'Z' => b'A',
+        _ => return byte,
+    };
+    (byte - base + shift % 26) % 26 + base
+}
+
+impl TextConverter for CaesarCipher {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        Self::shifted(input.as_ref(), self.shift)
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        Self::shifted_into(input, self.shift, output);
+    }
+
+    fn converter_in_place(&self, text: &mut str) -> bool {
+        ascii::map_in_place(text, |byte| shift_letter(byte, self.shift));
+        true
+    }
+}
+
+impl ReversibleConverter for CaesarCipher {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        Self::shifted(input.as_ref(), 26 - self.shift % 26)
+    }
+}
+
+/// [`CaesarCipher`] with a shift of 13, which is its own inverse
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rot13;
+
+impl TextConverter for Rot13 {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        CaesarCipher::new(13).converter(input)
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        CaesarCipher::new(13).converter_into(input, output);
+    }
+
+    fn converter_in_place(&self, text: &mut str) -> bool {
+        CaesarCipher::new(13).converter_in_place(text)
+    }
+}
+
+impl ReversibleConverter for Rot13 {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        self.converter(input)
+    }
+}
+
+/// Shifts ASCII letters by the letters of a keyword in turn, `a` shifting by 0 places and `z` by
+/// 25, like a [`CaesarCipher`] whose shift changes from one letter to the next
+///
+/// The keyword moves on to its next letter only after a letter of the text, and starts over once
+/// used up. Case is kept and other characters are left untouched, and so are characters of the
+/// keyword that are not ASCII letters. A keyword without letters leaves the text unchanged
+///
+/// ```
+/// use text_converter::{converters::Vigenere, ReversibleConverter, TextConverter};
+///
+/// let cipher = Vigenere::new("lemon");
+/// assert_eq!("Lxfopv, Ef Rnhr!", cipher.new_from_text("Attack, At Dawn!"));
+/// assert_eq!("Attack, At Dawn!", cipher.decode_from_text("Lxfopv, Ef Rnhr!"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Vigenere {
+    /// Keyword whose letters give the shift of each letter of the text, in any case
+    pub key: String,
+}
+
+impl Vigenere {
+    /// Creates a cipher shifting letters by the letters of `key`
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+
+    /// Places each letter of the key shifts by, forward or back to decode
+    fn shifts(&self, decode: bool) -> Vec<u8> {
+        self.key
+            .bytes()
+            .filter(u8::is_ascii_alphabetic)
+            .map(|byte| {
+                let shift = byte.to_ascii_lowercase() - b'a';
+                match decode {
+                    true => 26 - shift,
+                    false => shift,
+                }
+            })
+            .collect()
+    }
+
+    fn shifted(&self, input: &str, decode: bool) -> String {
+        let mut output = String::with_capacity(input.len());
+        self.shifted_into(input, decode, &mut output);
+        output
+    }
+
+    fn shifted_into(&self, input: &str, decode: bool, output: &mut String) {
+        output.clear();
+        output.push_str(input);
+        self.shift_in_place(output, decode);
+    }
+
+    fn shift_in_place(&self, text: &mut str, decode: bool) {
+        let shifts = self.shifts(decode);
+        if shifts.is_empty() {
+            return;
+        }
+
+        let mut shifts = shifts.iter().cycle();
+        ascii::map_in_place(text, |byte| match byte.is_ascii_alphabetic() {
+            true => shift_letter(byte, shifts.next().copied().unwrap_or_default()),
+            false => byte,
+        });
+    }
+}
+
+impl TextConverter for Vigenere {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.shifted(input.as_ref(), false)
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        self.shifted_into(input, false, output);
+    }
+
+    fn converter_in_place(&self, text: &mut str) -> bool {
+        self.shift_in_place(text, false);
+        true
+    }
+}
+
+impl ReversibleConverter for Vigenere {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        self.shifted(input.as_ref(), true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caesar_wraps_and_keeps_case() {
+        assert_eq!("abc XYZ", CaesarCipher::new(1).new_from_text("zab WXY"));
+        assert_eq!("Hello", CaesarCipher::new(26).new_from_text("Hello"));
+        assert_eq!("Ifmmp", CaesarCipher::new(27).new_from_text("Hello"));
+        assert_eq!("héllo 42", CaesarCipher::new(0).new_from_text("héllo 42"));
+    }
+
+    #[test]
+    fn caesar_round_trip() {
+        for shift in [0, 1, 13, 25, 26, 200, 255] {
+            let cipher = CaesarCipher::new(shift);
+            let text = "The Quick Brown Fox, 1234 é";
+            assert_eq!(text, cipher.decode_from_text(cipher.new_from_text(text)));
+        }
+    }
+
+    #[test]
+    fn rot13() {
+        assert_eq!("Uryyb, Jbeyq!", Rot13.new_from_text("Hello, World!"));
+        assert_eq!("Hello, World!", Rot13.new_from_text("Uryyb, Jbeyq!"));
+    }
+
+    #[test]
+    fn in_place() {
+        let mut text = String::from("Zebra é 42");
+        assert!(CaesarCipher::new(27).converter_in_place(&mut text));
+        assert_eq!("Afcsb é 42", text);
+        assert!(Rot13.converter_in_place(&mut text));
+        assert_eq!(Rot13.new_from_text("Afcsb é 42"), text);
+    }
+
+    #[test]
+    fn vigenere_follows_the_key() {
+        let cipher = Vigenere::new("LEMON");
+        assert_eq!("LXFOPVEFRNHR", cipher.new_from_text("ATTACKATDAWN"));
+        assert_eq!("lxf-OPV é 42 e", cipher.new_from_text("att-ACK é 42 a"));
+        assert_eq!(
+            cipher.new_from_text("attack"),
+            Vigenere::new("le mon!").new_from_text("attack")
+        );
+        assert_eq!("Hello", Vigenere::new("").new_from_text("Hello"));
+        assert_eq!("Hello", Vigenere::new("42 é").new_from_text("Hello"));
+    }
+
+    #[test]
+    fn vigenere_round_trip() {
+        for key in ["a", "z", "Key", "lemon", "Not a key"] {
+            let cipher = Vigenere::new(key);
+            let text = "The Quick Brown Fox, 1234 é";
+            assert_eq!(text, cipher.decode_from_text(cipher.new_from_text(text)));
+
+            let mut in_place = String::from(text);
+            assert!(cipher.converter_in_place(&mut in_place));
+            assert_eq!(cipher.new_from_text(text), in_place);
+        }
+    }
+}
@@ -0,0 +1,434 @@
+use crate::{ReversibleConverter, TextConverter};
+use alloc::string::String;
+use core::cmp::Reverse;
+
+/// Replaces `:shortcode:`s, as used by GitHub and Slack, with the emoji they stand for
+///
+/// Unknown shortcodes are kept as they are. Decoding replaces emoji with their shortcode, using
+/// the first one in alphabetical order for emoji that have several
+///
+/// ```
+/// use text_converter::{converters::Emojize, ReversibleConverter, TextConverter};
+///
+/// assert_eq!("Ship it 🚀 :not_an_emoji:", Emojize.new_from_text("Ship it :rocket: :not_an_emoji:"));
+/// assert_eq!("Nice :+1:", Emojize.decode_from_text("Nice 👍"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Emojize;
+
+impl Emojize {
+    /// Emoji for `shortcode`, written without the colons
+    pub fn emoji(shortcode: &str) -> Option<&'static str> {
+        SHORTCODES
+            .binary_search_by(|(name, _)| name.cmp(&shortcode))
+            .ok()
+            .map(|i| SHORTCODES[i].1)
+    }
+
+    /// Shortcode of the emoji `text` starts with, if any, and the length of that emoji in bytes
+    ///
+    /// Emoji are also recognized without their variation selector, as they are often written
+    fn shortcode_at(text: &str) -> Option<(&'static str, usize)> {
+        SHORTCODES
+            .iter()
+            .filter_map(|(name, emoji)| {
+                let len = match emoji.strip_suffix('\u{FE0F}') {
+                    _ if text.starts_with(emoji) => emoji.len(),
+                    Some(bare) if text.starts_with(bare) => bare.len(),
+                    _ => return None,
+                };
+                Some((*name, len))
+            })
+            // The longest emoji wins, and the first shortcode among equally long ones
+            .min_by_key(|(_, len)| Reverse(*len))
+    }
+}
+
+impl TextConverter for Emojize {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+        let mut rest = input.as_ref();
+
+        while let Some(start) = rest.find(':') {
+            output.push_str(&rest[..start]);
+            rest = &rest[start + 1..];
+
+            let emoji = rest
+                .find(':')
+                .and_then(|end| Some((end, Self::emoji(&rest[..end])?)));
+            match emoji {
+                Some((end, emoji)) => {
+                    output.push_str(emoji);
+                    rest = &rest[end + 1..];
+                }
+                None => output.push(':'),
+            }
+        }
+
+        output.push_str(rest);
+        output
+    }
+}
+
+impl ReversibleConverter for Emojize {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+        let mut rest = input.as_ref();
+
+        while let Some(c) = rest.chars().next() {
+            match Self::shortcode_at(rest).filter(|_| !c.is_ascii()) {
+                Some((name, len)) => {
+                    output.push(':');
+                    output.push_str(name);
+                    output.push(':');
+                    rest = &rest[len..];
+                }
+                None => {
+                    output.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+
+        output
+    }
+}
+
+/// Shortcodes and their emoji, sorted by shortcode
+const SHORTCODES: &[(&str, &str)] = &[
+    ("+1", "👍"),
+    ("-1", "👎"),
+    ("100", "💯"),
+    ("airplane", "✈\u{FE0F}"),
+    ("alarm_clock", "⏰"),
+    ("alien", "👽"),
+    ("angry", "😠"),
+    ("apple", "🍎"),
+    ("arrow_down", "⬇\u{FE0F}"),
+    ("arrow_left", "⬅\u{FE0F}"),
+    ("arrow_right", "➡\u{FE0F}"),
+    ("arrow_up", "⬆\u{FE0F}"),
+    ("art", "🎨"),
+    ("astonished", "😲"),
+    ("avocado", "🥑"),
+    ("baby", "👶"),
+    ("balloon", "🎈"),
+    ("banana", "🍌"),
+    ("bangbang", "‼\u{FE0F}"),
+    ("basketball", "🏀"),
+    ("beach_umbrella", "🏖\u{FE0F}"),
+    ("bear", "🐻"),
+    ("bee", "🐝"),
+    ("beer", "🍺"),
+    ("beers", "🍻"),
+    ("bell", "🔔"),
+    ("bike", "🚲"),
+    ("bird", "🐦"),
+    ("birthday", "🎂"),
+    ("black_heart", "🖤"),
+    ("blue_heart", "💙"),
+    ("blush", "😊"),
+    ("book", "📖"),
+    ("books", "📚"),
+    ("boom", "💥"),
+    ("brain", "🧠"),
+    ("bread", "🍞"),
+    ("broken_heart", "💔"),
+    ("bug", "🐛"),
+    ("bulb", "💡"),
+    ("bus", "🚌"),
+    ("butterfly", "🦋"),
+    ("cactus", "🌵"),
+    ("cake", "🍰"),
+    ("calendar", "📆"),
+    ("camping", "🏕\u{FE0F}"),
+    ("car", "🚗"),
+    ("carrot", "🥕"),
+    ("cat", "🐱"),
+    ("chart_with_downwards_trend", "📉"),
+    ("chart_with_upwards_trend", "📈"),
+    ("checkered_flag", "🏁"),
+    ("cheese", "🧀"),
+    ("cherries", "🍒"),
+    ("chicken", "🐔"),
+    ("clap", "👏"),
+    ("clipboard", "📋"),
+    ("cloud", "☁\u{FE0F}"),
+    ("clown_face", "🤡"),
+    ("coffee", "☕"),
+    ("cold_face", "🥶"),
+    ("computer", "💻"),
+    ("confetti_ball", "🎊"),
+    ("confused", "😕"),
+    ("cookie", "🍪"),
+    ("copyright", "©\u{FE0F}"),
+    ("cowboy_hat_face", "🤠"),
+    ("crab", "🦀"),
+    ("credit_card", "💳"),
+    ("crescent_moon", "🌙"),
+    ("crossed_fingers", "🤞"),
+    ("crown", "👑"),
+    ("cry", "😢"),
+    ("dart", "🎯"),
+    ("disappointed", "😞"),
+    ("dizzy", "💫"),
+    ("dizzy_face", "😵"),
+    ("dog", "🐶"),
+    ("dollar", "💵"),
+    ("doughnut", "🍩"),
+    ("droplet", "💧"),
+    ("earth_americas", "🌎"),
+    ("email", "📧"),
+    ("evergreen_tree", "🌲"),
+    ("exclamation", "❗"),
+    ("exploding_head", "🤯"),
+    ("expressionless", "😑"),
+    ("eyeglasses", "👓"),
+    ("eyes", "👀"),
+    ("face_with_monocle", "🧐"),
+    ("facepalm", "🤦"),
+    ("fallen_leaf", "🍂"),
+    ("fire", "🔥"),
+    ("fish", "🐟"),
+    ("fist", "✊"),
+    ("flag_br", "🇧🇷"),
+    ("flag_ca", "🇨🇦"),
+    ("flag_de", "🇩🇪"),
+    ("flag_es", "🇪🇸"),
+    ("flag_fr", "🇫🇷"),
+    ("flag_gb", "🇬🇧"),
+    ("flag_in", "🇮🇳"),
+    ("flag_it", "🇮🇹"),
+    ("flag_jp", "🇯🇵"),
+    ("flag_us", "🇺🇸"),
+    ("flushed", "😳"),
+    ("football", "🏈"),
+    ("four_leaf_clover", "🍀"),
+    ("fox_face", "🦊"),
+    ("free", "🆓"),
+    ("fries", "🍟"),
+    ("frog", "🐸"),
+    ("frowning_face", "☹\u{FE0F}"),
+    ("game_die", "🎲"),
+    ("gear", "⚙\u{FE0F}"),
+    ("gem", "💎"),
+    ("ghost", "👻"),
+    ("gift", "🎁"),
+    ("grapes", "🍇"),
+    ("green_circle", "🟢"),
+    ("green_heart", "💚"),
+    ("grimacing", "😬"),
+    ("grin", "😁"),
+    ("grinning", "😀"),
+    ("guitar", "🎸"),
+    ("hamburger", "🍔"),
+    ("hammer", "🔨"),
+    ("handshake", "🤝"),
+    ("headphones", "🎧"),
+    ("hear_no_evil", "🙉"),
+    ("heart", "❤\u{FE0F}"),
+    ("heart_eyes", "😍"),
+    ("heavy_check_mark", "✔\u{FE0F}"),
+    ("hospital", "🏥"),
+    ("hot_face", "🥵"),
+    ("hot_pepper", "🌶\u{FE0F}"),
+    ("hotdog", "🌭"),
+    ("hourglass", "⌛"),
+    ("house", "🏠"),
+    ("hugs", "🤗"),
+    ("infinity", "♾\u{FE0F}"),
+    ("innocent", "😇"),
+    ("iphone", "📱"),
+    ("jeans", "👖"),
+    ("joy", "😂"),
+    ("key", "🔑"),
+    ("keyboard", "⌨\u{FE0F}"),
+    ("kissing_heart", "😘"),
+    ("large_blue_circle", "🔵"),
+    ("laughing", "😆"),
+    ("lemon", "🍋"),
+    ("lightning", "🌩\u{FE0F}"),
+    ("link", "🔗"),
+    ("lipstick", "💄"),
+    ("lock", "🔒"),
+    ("lying_face", "🤥"),
+    ("mag", "🔍"),
+    ("man", "👨"),
+    ("mask", "😷"),
+    ("medal_sports", "🏅"),
+    ("memo", "📝"),
+    ("microphone", "🎤"),
+    ("money_mouth_face", "🤑"),
+    ("moneybag", "💰"),
+    ("monkey_face", "🐵"),
+    ("mountain", "⛰\u{FE0F}"),
+    ("mouse", "🐭"),
+    ("muscle", "💪"),
+    ("musical_note", "🎵"),
+    ("nauseated_face", "🤢"),
+    ("nerd_face", "🤓"),
+    ("neutral_face", "😐"),
+    ("new", "🆕"),
+    ("no_entry", "⛔"),
+    ("no_mouth", "😶"),
+    ("notes", "🎶"),
+    ("ocean", "🌊"),
+    ("octopus", "🐙"),
+    ("office", "🏢"),
+    ("ok", "🆗"),
+    ("ok_hand", "👌"),
+    ("older_man", "👴"),
+    ("older_woman", "👵"),
+    ("open_hands", "👐"),
+    ("open_mouth", "😮"),
+    ("orange_heart", "🧡"),
+    ("package", "📦"),
+    ("panda_face", "🐼"),
+    ("paperclip", "📎"),
+    ("partying_face", "🥳"),
+    ("peach", "🍑"),
+    ("penguin", "🐧"),
+    ("pensive", "😔"),
+    ("pill", "💊"),
+    ("pirate_flag", "🏴\u{200D}☠\u{FE0F}"),
+    ("pizza", "🍕"),
+    ("pleading_face", "🥺"),
+    ("point_down", "👇"),
+    ("point_left", "👈"),
+    ("point_right", "👉"),
+    ("point_up", "☝\u{FE0F}"),
+    ("poop", "💩"),
+    ("pray", "🙏"),
+    ("purple_heart", "💜"),
+    ("pushpin", "📌"),
+    ("question", "❓"),
+    ("rabbit", "🐰"),
+    ("rage", "😡"),
+    ("rainbow", "🌈"),
+    ("rainbow_flag", "🏳\u{FE0F}\u{200D}🌈"),
+    ("raised_hand", "✋"),
+    ("raised_hands", "🙌"),
+    ("ramen", "🍜"),
+    ("recycle", "♻\u{FE0F}"),
+    ("red_circle", "🔴"),
+    ("registered", "®\u{FE0F}"),
+    ("relieved", "😌"),
+    ("ring", "💍"),
+    ("robot", "🤖"),
+    ("rocket", "🚀"),
+    ("rofl", "🤣"),
+    ("roll_eyes", "🙄"),
+    ("rose", "🌹"),
+    ("scissors", "✂\u{FE0F}"),
+    ("scream", "😱"),
+    ("see_no_evil", "🙈"),
+    ("seedling", "🌱"),
+    ("ship", "🚢"),
+    ("shrug", "🤷"),
+    ("shushing_face", "🤫"),
+    ("skull", "💀"),
+    ("sleeping", "😴"),
+    ("sleepy", "😪"),
+    ("slightly_smiling_face", "🙂"),
+    ("smile", "😄"),
+    ("smiley", "😃"),
+    ("smiley_cat", "😺"),
+    ("smirk", "😏"),
+    ("snake", "🐍"),
+    ("sneezing_face", "🤧"),
+    ("snowflake", "❄\u{FE0F}"),
+    ("sob", "😭"),
+    ("soccer", "⚽"),
+    ("sos", "🆘"),
+    ("sparkles", "✨"),
+    ("sparkling_heart", "💖"),
+    ("speak_no_evil", "🙊"),
+    ("star", "⭐"),
+    ("star2", "🌟"),
+    ("star_struck", "🤩"),
+    ("strawberry", "🍓"),
+    ("stuck_out_tongue", "😛"),
+    ("stuck_out_tongue_winking_eye", "😜"),
+    ("sunflower", "🌻"),
+    ("sunglasses", "😎"),
+    ("sunny", "☀\u{FE0F}"),
+    ("sushi", "🍣"),
+    ("sweat", "😓"),
+    ("sweat_smile", "😅"),
+    ("t-shirt", "👕"),
+    ("taco", "🌮"),
+    ("tada", "🎉"),
+    ("tea", "🍵"),
+    ("tennis", "🎾"),
+    ("thinking", "🤔"),
+    ("thumbsdown", "👎"),
+    ("thumbsup", "👍"),
+    ("tired_face", "😫"),
+    ("tm", "™\u{FE0F}"),
+    ("tophat", "🎩"),
+    ("tornado", "🌪\u{FE0F}"),
+    ("triumph", "😤"),
+    ("trophy", "🏆"),
+    ("tulip", "🌷"),
+    ("turtle", "🐢"),
+    ("two_hearts", "💕"),
+    ("umbrella", "☔"),
+    ("unamused", "😒"),
+    ("unicorn", "🦄"),
+    ("unlock", "🔓"),
+    ("upside_down_face", "🙃"),
+    ("v", "✌\u{FE0F}"),
+    ("video_game", "🎮"),
+    ("volcano", "🌋"),
+    ("warning", "⚠\u{FE0F}"),
+    ("watch", "⌚"),
+    ("watermelon", "🍉"),
+    ("wave", "👋"),
+    ("weary", "😩"),
+    ("whale", "🐳"),
+    ("white_check_mark", "✅"),
+    ("white_flag", "🏳\u{FE0F}"),
+    ("wine_glass", "🍷"),
+    ("wink", "😉"),
+    ("woman", "👩"),
+    ("worried", "😟"),
+    ("wrench", "🔧"),
+    ("x", "❌"),
+    ("yawning_face", "🥱"),
+    ("yellow_heart", "💛"),
+    ("yum", "😋"),
+    ("zap", "⚡"),
+    ("zipper_mouth_face", "🤐"),
+    ("zzz", "💤"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortcodes_are_sorted() {
+        assert!(SHORTCODES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn expands_shortcodes() {
+        assert_eq!(
+            "🔥 12:30: 🎉🇫🇷 :: :",
+            Emojize.new_from_text(":fire: 12:30: :tada::flag_fr: :: :")
+        );
+        assert_eq!("Time is 10:45", Emojize.new_from_text("Time is 10:45"));
+    }
+
+    #[test]
+    fn demojizes() {
+        assert_eq!(
+            "I :heart: :pizza: and :rainbow_flag:!",
+            Emojize.decode_from_text("I ❤ 🍕 and 🏳️‍🌈!")
+        );
+
+        let text = "Done :white_check_mark: :sparkles:\n";
+        assert_eq!(text, Emojize.decode_from_text(Emojize.new_from_text(text)));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{split_line_ending, ReversibleConverter, TextConverter};
+use alloc::string::String;
+
+/// Characters outside of ASCII that have a fullwidth form
+const SYMBOLS: &[(char, char)] = &[
+    ('¢', '￠'),
+    ('£', '￡'),
+    ('¬', '￢'),
+    ('¯', '￣'),
+    ('¦', '￤'),
+    ('¥', '￥'),
+    ('₩', '￦'),
+];
+
+/// Converts ASCII text to fullwidth forms, "ｖａｐｏｒｗａｖｅ" style
+///
+/// Spaces become ideographic spaces, and characters without a fullwidth form, like letters with
+/// accents, are kept as they are. Decoding turns fullwidth forms back into ASCII, and removes the
+/// spaces put between characters when spacing is on
+///
+/// ```
+/// use text_converter::{converters::Fullwidth, ReversibleConverter, TextConverter};
+///
+/// assert_eq!("ｈｅｌｌｏ！", Fullwidth::default().new_from_text("hello!"));
+///
+/// let spaced = Fullwidth::new(true);
+/// assert_eq!("ａ ｅ ｓ ｔ ｈ ｅ ｔ ｉ ｃ", spaced.new_from_text("aesthetic"));
+/// assert_eq!("aesthetic", spaced.decode_from_text("ａ ｅ ｓ ｔ ｈ ｅ ｔ ｉ ｃ"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Fullwidth {
+    /// Whether to put a space between characters
+    pub spacing: bool,
+}
+
+impl Fullwidth {
+    /// Creates a converter that puts a space between characters if `spacing` is set
+    pub fn new(spacing: bool) -> Self {
+        Self { spacing }
+    }
+
+    fn widen(c: char) -> char {
+        match c {
+            ' ' => '\u{3000}',
+            '!'..='~' => char::from_u32(c as u32 + 0xFEE0).unwrap_or(c),
+            _ => SYMBOLS
+                .iter()
+                .find(|(narrow, _)| *narrow == c)
+                .map_or(c, |(_, wide)| *wide),
+        }
+    }
+
+    fn narrow(c: char) -> char {
+        match c {
+            '\u{3000}' => ' ',
+            '！'..='～' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            _ => SYMBOLS
+                .iter()
+                .find(|(_, wide)| *wide == c)
+                .map_or(c, |(narrow, _)| *narrow),
+        }
+    }
+}
+
+impl TextConverter for Fullwidth {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len() * 4);
+        self.converter_into(input.as_ref(), &mut output);
+        output
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+
+        for line in input.split_inclusive('\n') {
+            let (content, ending) = split_line_ending(line);
+            for (i, c) in content.chars().enumerate() {
+                if self.spacing && i > 0 {
+                    output.push(' ');
+                }
+                output.push(Self::widen(c));
+            }
+            output.push_str(ending);
+        }
+    }
+}
+
+impl ReversibleConverter for Fullwidth {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        input
+            .as_ref()
+            .chars()
+            .filter(|c| !self.spacing || *c != ' ')
+            .map(Self::narrow)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widens_ascii() {
+        let input = "Price: ¥100 (café)\nok";
+        let wide = Fullwidth::default().new_from_text(input);
+
+        assert_eq!("Ｐｒｉｃｅ：　￥１００　（ｃａｆé）\nｏｋ", wide);
+        assert_eq!(input, Fullwidth::default().decode_from_text(wide));
+    }
+
+    #[test]
+    fn spacing() {
+        let spaced = Fullwidth::new(true);
+        let wide = spaced.new_from_text("a b\r\ncd");
+
+        assert_eq!("ａ 　 ｂ\r\nｃ ｄ", wide);
+        assert_eq!("a b\r\ncd", spaced.decode_from_text(wide));
+    }
+}
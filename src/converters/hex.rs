@@ -0,0 +1,239 @@
+use crate::{BytesConverter, ConversionError, ReversibleConverter, TextConverter};
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+
+const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Number of bytes after which a dump line has an extra space
+const GROUP: usize = 8;
+
+/// Writes bytes as a classic hex dump, like `hexdump -C`: the offset of each line, its bytes as
+/// hexadecimal, and their printable ASCII characters, with `.` for the others
+///
+/// The last line is the offset of the end, unless there are no bytes at all. Converts the UTF-8
+/// bytes of text as a [`TextConverter`], and any bytes as a [`BytesConverter`]
+///
+/// ```
+/// use text_converter::{converters::HexDump, BytesConverter, TextConverter};
+///
+/// assert_eq!(
+///     "00000000  68 69 0a                                          |hi.|\n00000003\n",
+///     HexDump::default().new_from_text("hi\n")
+/// );
+/// assert_eq!(
+///     b"00000000  00 ff  |..|\n00000002\n".to_vec(),
+///     HexDump::new(2).new_from_bytes([0x00, 0xff])
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct HexDump {
+    /// Number of bytes on each line, at least 1
+    pub width: usize,
+}
+
+impl HexDump {
+    /// Creates a dump with `width` bytes on each line
+    pub fn new(width: usize) -> Self {
+        Self { width }
+    }
+
+    fn dump(&self, bytes: &[u8]) -> String {
+        let width = self.width.max(1);
+        let line_len = 12 + width * 4 + width.div_ceil(GROUP);
+        let mut output = String::with_capacity(bytes.len().div_ceil(width) * line_len + 9);
+
+        for (line, chunk) in bytes.chunks(width).enumerate() {
+            output.push_str(&format!("{:08x} ", line * width));
+            for i in 0..width {
+                if i % GROUP == 0 {
+                    output.push(' ');
+                }
+                match chunk.get(i) {
+                    Some(&byte) => push_hex(&mut output, byte),
+                    None => output.push_str("  "),
+                }
+                output.push(' ');
+            }
+
+            output.push_str(" |");
+            output.extend(chunk.iter().map(|&byte| {
+                match byte.is_ascii_graphic() || byte == b' ' {
+                    true => char::from(byte),
+                    false => '.',
+                }
+            }));
+            output.push_str("|\n");
+        }
+
+        if !bytes.is_empty() {
+            output.push_str(&format!("{:08x}\n", bytes.len()));
+        }
+        output
+    }
+}
+
+impl Default for HexDump {
+    fn default() -> Self {
+        Self::new(16)
+    }
+}
+
+impl TextConverter for HexDump {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.dump(input.as_ref().as_bytes())
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        Some("hex")
+    }
+}
+
+impl BytesConverter for HexDump {
+    fn converter(&self, input: &[u8]) -> Vec<u8> {
+        self.dump(input).into_bytes()
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        Some("hex")
+    }
+}
+
+/// Decodes a string of hexadecimal digits, two for each byte, in any case. Whitespace between
+/// the digits, like spaces between bytes, is ignored
+///
+/// The [converter](TextConverter::converter()) method panics on an odd number of digits, on
+/// characters that are not digits, or if the decoded bytes aren't UTF-8, use the `try_*` methods
+/// to get an error instead. As a [`BytesConverter`] it decodes to any bytes
+///
+/// ```
+/// use text_converter::{converters::HexDecode, ReversibleConverter, TextConverter};
+///
+/// assert_eq!("hi!", HexDecode.new_from_text("68 69 21"));
+/// assert_eq!("686921", HexDecode.decode_from_text("hi!"));
+/// assert!(HexDecode.try_new_from_text("6869 2").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HexDecode;
+
+impl HexDecode {
+    fn decode(input: &[u8]) -> Result<Vec<u8>, ConversionError> {
+        let digits: Vec<u8> = input
+            .iter()
+            .copied()
+            .filter(|byte| !byte.is_ascii_whitespace())
+            .collect();
+        if digits.len() % 2 == 1 {
+            return Err(ConversionError::InvalidInput(
+                "hex input has an odd number of digits".to_owned(),
+            ));
+        }
+
+        digits
+            .chunks(2)
+            .map(|pair| Ok(digit(pair[0])? << 4 | digit(pair[1])?))
+            .collect()
+    }
+}
+
+/// Value of the hexadecimal digit `byte`
+fn digit(byte: u8) -> Result<u8, ConversionError> {
+    char::from(byte)
+        .to_digit(16)
+        .map(|value| value as u8)
+        .ok_or_else(|| {
+            ConversionError::InvalidInput(format!(
+                "`{}` is not a hexadecimal digit",
+                char::from(byte)
+            ))
+        })
+}
+
+fn push_hex(output: &mut String, byte: u8) {
+    output.push(char::from(DIGITS[usize::from(byte >> 4)]));
+    output.push(char::from(DIGITS[usize::from(byte & 0xf)]));
+}
+
+impl TextConverter for HexDecode {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        TextConverter::try_converter(self, input)
+            .unwrap_or_else(|e| panic!("Failed to decode hex: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let bytes = Self::decode(input.as_ref().as_bytes())?;
+        String::from_utf8(bytes).map_err(|e| e.utf8_error().into())
+    }
+}
+
+impl ReversibleConverter for HexDecode {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        let bytes = input.as_ref().as_bytes();
+        let mut output = String::with_capacity(bytes.len() * 2);
+        for &byte in bytes {
+            push_hex(&mut output, byte);
+        }
+        output
+    }
+}
+
+impl BytesConverter for HexDecode {
+    fn converter(&self, input: &[u8]) -> Vec<u8> {
+        BytesConverter::try_converter(self, input)
+            .unwrap_or_else(|e| panic!("Failed to decode hex: {e}"))
+    }
+
+    fn try_converter(&self, input: &[u8]) -> Result<Vec<u8>, ConversionError> {
+        Self::decode(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dumps_lines() {
+        let dump = HexDump::default().new_from_text("Hello, World!\nSecond line é");
+        assert_eq!(
+            "00000000  48 65 6c 6c 6f 2c 20 57  6f 72 6c 64 21 0a 53 65  |Hello, World!.Se|\n\
+             00000010  63 6f 6e 64 20 6c 69 6e  65 20 c3 a9              |cond line ..|\n\
+             0000001c\n",
+            dump
+        );
+        assert_eq!("", HexDump::default().new_from_text(""));
+        assert_eq!(
+            "00000000  61  |a|\n00000001  62  |b|\n00000002\n",
+            HexDump::new(0).new_from_text("ab")
+        );
+        assert_eq!(
+            HexDump::default().new_from_text("\u{7f}"),
+            String::from_utf8(HexDump::default().new_from_bytes([0x7f])).unwrap()
+        );
+    }
+
+    #[test]
+    fn decodes_hex() {
+        assert_eq!("Hello é", HexDecode.new_from_text("48656C6C6f20\nc3 a9"));
+        assert_eq!("", HexDecode.new_from_text(" "));
+        assert_eq!(vec![0x00, 0xff], HexDecode.new_from_bytes(b"00 ff"));
+        assert_eq!(
+            "é",
+            HexDecode.new_from_text(HexDecode.decode_from_text("é"))
+        );
+
+        assert!(matches!(
+            HexDecode.try_new_from_text("abc"),
+            Err(ConversionError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            HexDecode.try_new_from_text("zz"),
+            Err(ConversionError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            HexDecode.try_new_from_text("ff"),
+            Err(ConversionError::Encoding(_))
+        ));
+    }
+}
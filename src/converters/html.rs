@@ -0,0 +1,477 @@
+use super::html_entities::ENTITIES;
+use crate::{ReversibleConverter, TextConverter};
+use alloc::{borrow::ToOwned, format, string::String, string::ToString, vec::Vec};
+
+/// Escapes `<`, `>`, `&`, `"` and `'`, so the text can go in HTML elements and attributes
+///
+/// ```
+/// use text_converter::{converters::HtmlEscape, ReversibleConverter, TextConverter};
+///
+/// let escaped = HtmlEscape.new_from_text(r#"<a href="x">Tom & Jerry's</a>"#);
+/// assert_eq!("&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#x27;s&lt;/a&gt;", escaped);
+/// assert_eq!(r#"<a href="x">Tom & Jerry's</a>"#, HtmlEscape.decode_from_text(escaped));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HtmlEscape;
+
+impl TextConverter for HtmlEscape {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+
+        for c in input.as_ref().chars() {
+            match c {
+                '<' => output.push_str("&lt;"),
+                '>' => output.push_str("&gt;"),
+                '&' => output.push_str("&amp;"),
+                '"' => output.push_str("&quot;"),
+                '\'' => output.push_str("&#x27;"),
+                _ => output.push(c),
+            }
+        }
+
+        output
+    }
+}
+
+impl ReversibleConverter for HtmlEscape {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        HtmlUnescape.converter(input)
+    }
+}
+
+/// Replaces named and numeric character references, like `&amp;`, `&eacute;` and `&#x1F600;`,
+/// with the characters they stand for
+///
+/// Every named reference of the HTML standard is recognized, including the legacy ones that may
+/// be written without the `;`. Like in browsers, numeric references outside of Unicode become
+/// U+FFFD, and anything that isn't a known reference is kept as it is
+///
+/// ```
+/// use text_converter::{converters::HtmlUnescape, TextConverter};
+///
+/// assert_eq!("café © 😀 &nope;", HtmlUnescape.new_from_text("caf&eacute; &copy &#x1F600; &nope;"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HtmlUnescape;
+
+impl HtmlUnescape {
+    /// Character the reference at the start of `text`, just after the `&`, stands for, and the
+    /// length of the reference
+    fn reference(text: &str) -> Option<(String, usize)> {
+        match text.strip_prefix('#') {
+            Some(number) => Self::numeric(number).map(|(c, len)| (c.to_string(), len + 1)),
+            None => Self::named(text).map(|(value, len)| (value.to_owned(), len)),
+        }
+    }
+
+    fn numeric(text: &str) -> Option<(char, usize)> {
+        let (digits, radix, prefix) = match text.strip_prefix(['x', 'X']) {
+            Some(hex) => (hex, 16, 1),
+            None => (text, 10, 0),
+        };
+
+        let len = digits
+            .find(|c: char| !c.is_digit(radix))
+            .unwrap_or(digits.len());
+        if len == 0 {
+            return None;
+        }
+
+        let code = u32::from_str_radix(&digits[..len], radix).unwrap_or(u32::MAX);
+        let c = match code {
+            0x80..=0x9f => WINDOWS_1252[(code - 0x80) as usize],
+            0 => '\u{FFFD}',
+            _ => char::from_u32(code).unwrap_or('\u{FFFD}'),
+        };
+        let semicolon = usize::from(digits[len..].starts_with(';'));
+
+        Some((c, prefix + len + semicolon))
+    }
+
+    fn named(text: &str) -> Option<(&'static str, usize)> {
+        let len = text
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(text.len());
+        let lookup = |name: &str| {
+            ENTITIES
+                .binary_search_by(|(entity, _)| (*entity).cmp(name))
+                .ok()
+                .map(|i| (ENTITIES[i].1, name.len()))
+        };
+
+        if text[len..].starts_with(';') {
+            if let Some(found) = lookup(&text[..=len]) {
+                return Some(found);
+            }
+        }
+
+        // Legacy references without a `;`, like `&copy`, match the longest name they start with
+        (2..=len.min(6))
+            .rev()
+            .find_map(|prefix| lookup(&text[..prefix]))
+    }
+}
+
+impl TextConverter for HtmlUnescape {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+        let mut rest = input.as_ref();
+
+        while let Some(start) = rest.find('&') {
+            output.push_str(&rest[..start]);
+            rest = &rest[start + 1..];
+
+            match Self::reference(rest) {
+                Some((value, len)) => {
+                    output.push_str(&value);
+                    rest = &rest[len..];
+                }
+                None => output.push('&'),
+            }
+        }
+
+        output.push_str(rest);
+        output
+    }
+}
+
+impl ReversibleConverter for HtmlUnescape {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        HtmlEscape.converter(input)
+    }
+}
+
+/// Turns HTML into plain text, keeping its basic structure
+///
+/// Tags are removed and character references decoded. Whitespace is collapsed like a browser
+/// would, except in `<pre>` blocks. Paragraphs, headings and other blocks are separated by blank
+/// lines, `<br>` starts a new line, list items get a `-` or their number, indented by nesting
+/// level, and table cells are separated by tabs. Scripts, styles and comments are dropped
+///
+/// ```
+/// use text_converter::{converters::HtmlToText, TextConverter};
+///
+/// let html = "<h1>Groceries</h1><p>Don&apos;t forget:</p><ul><li>milk</li><li>eggs</li></ul>";
+/// assert_eq!("Groceries\n\nDon't forget:\n\n- milk\n- eggs", HtmlToText.new_from_text(html));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HtmlToText;
+
+const BLOCKS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "dd",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "summary",
+    "table",
+    "ul",
+];
+
+/// Elements whose content is not text to display
+const HIDDEN: &[&str] = &["head", "noscript", "script", "style", "template", "title"];
+
+/// Separation to put before the next text, the strongest one requested winning
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Break {
+    None,
+    Space,
+    Tab,
+    Line,
+    Paragraph,
+}
+
+enum List {
+    Unordered,
+    Ordered(usize),
+}
+
+struct TextWriter {
+    output: String,
+    pending: Break,
+    lists: Vec<List>,
+    preformatted: usize,
+}
+
+impl TextWriter {
+    fn request(&mut self, separation: Break) {
+        self.pending = self.pending.max(separation);
+    }
+
+    /// Writes the pending separation, unless nothing has been written yet
+    fn flush(&mut self) {
+        if !self.output.is_empty() {
+            match self.pending {
+                Break::None => {}
+                Break::Space if self.output.ends_with(char::is_whitespace) => {}
+                Break::Space => self.output.push(' '),
+                Break::Tab => self.output.push('\t'),
+                Break::Line | Break::Paragraph => {
+                    self.output
+                        .truncate(self.output.trim_end_matches([' ', '\t']).len());
+                    let newlines = if self.pending == Break::Line { 1 } else { 2 };
+                    let existing = self.output.len() - self.output.trim_end_matches('\n').len();
+                    for _ in existing..newlines {
+                        self.output.push('\n');
+                    }
+                }
+            }
+        }
+        self.pending = Break::None;
+    }
+
+    fn text(&mut self, text: &str) {
+        let text = HtmlUnescape.converter(text);
+        if self.preformatted > 0 {
+            self.flush();
+            self.output.push_str(&text.replace('\u{A0}', " "));
+            return;
+        }
+
+        if text.starts_with(is_html_whitespace) {
+            self.request(Break::Space);
+        }
+        for (i, word) in text
+            .split(is_html_whitespace)
+            .filter(|w| !w.is_empty())
+            .enumerate()
+        {
+            if i > 0 {
+                self.request(Break::Space);
+            }
+            self.flush();
+            self.output.push_str(&word.replace('\u{A0}', " "));
+        }
+        if text.ends_with(is_html_whitespace) {
+            self.request(Break::Space);
+        }
+    }
+
+    fn start_tag(&mut self, name: &str) {
+        match name {
+            "br" => {
+                self.request(Break::Line);
+                self.flush();
+                self.request(Break::Line);
+            }
+            "tr" => self.request(Break::Line),
+            "ul" | "ol" => {
+                self.request(if self.lists.is_empty() {
+                    Break::Paragraph
+                } else {
+                    Break::Line
+                });
+                self.lists.push(match name {
+                    "ul" => List::Unordered,
+                    _ => List::Ordered(0),
+                });
+            }
+            "li" => {
+                self.request(Break::Line);
+                self.flush();
+                let depth = self.lists.len().saturating_sub(1);
+                self.output.push_str(&"  ".repeat(depth));
+                match self.lists.last_mut() {
+                    Some(List::Ordered(number)) => {
+                        *number += 1;
+                        self.output.push_str(&format!("{number}. "));
+                    }
+                    _ => self.output.push_str("- "),
+                }
+            }
+            "pre" => {
+                self.request(Break::Paragraph);
+                self.preformatted += 1;
+            }
+            _ if BLOCKS.contains(&name) => self.request(Break::Paragraph),
+            _ => {}
+        }
+    }
+
+    fn end_tag(&mut self, name: &str) {
+        match name {
+            "td" | "th" => self.request(Break::Tab),
+            "tr" | "li" => self.request(Break::Line),
+            "ul" | "ol" => {
+                self.lists.pop();
+                self.request(if self.lists.is_empty() {
+                    Break::Paragraph
+                } else {
+                    Break::Line
+                });
+            }
+            "pre" => {
+                self.preformatted = self.preformatted.saturating_sub(1);
+                self.request(Break::Paragraph);
+            }
+            _ if BLOCKS.contains(&name) => self.request(Break::Paragraph),
+            _ => {}
+        }
+    }
+}
+
+fn is_html_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r' | '\u{C}')
+}
+
+/// Length of the tag at the start of `html`, up to and including its `>`, skipping over quoted
+/// attribute values
+fn tag_len(html: &str) -> usize {
+    let mut quote = None;
+    for (i, c) in html.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), _) if open == c => quote = None,
+            (None, '>') => return i + 1,
+            _ => {}
+        }
+    }
+    html.len()
+}
+
+impl TextConverter for HtmlToText {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut writer = TextWriter {
+            output: String::with_capacity(input.as_ref().len()),
+            pending: Break::None,
+            lists: Vec::new(),
+            preformatted: 0,
+        };
+        let mut rest = input.as_ref();
+
+        while let Some(start) = rest.find('<') {
+            writer.text(&rest[..start]);
+            rest = &rest[start..];
+
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+                continue;
+            }
+
+            let after = &rest[1..];
+            let closing = after.starts_with('/');
+            let name_start = usize::from(closing);
+            let name_len = after[name_start..]
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(after.len() - name_start);
+            if name_len == 0 && !after.starts_with('!') && !after.starts_with('?') {
+                // Not a tag, like in `a < b`
+                writer.text("<");
+                rest = after;
+                continue;
+            }
+
+            let name = after[name_start..name_start + name_len].to_ascii_lowercase();
+            rest = &rest[tag_len(rest)..];
+
+            if closing {
+                writer.end_tag(&name);
+            } else if HIDDEN.contains(&name.as_str()) {
+                let lower = rest.to_ascii_lowercase();
+                rest = match lower.find(&format!("</{name}")) {
+                    Some(end) => &rest[end + tag_len(&rest[end..])..],
+                    None => "",
+                };
+            } else {
+                writer.start_tag(&name);
+            }
+        }
+        writer.text(rest);
+
+        let length = writer.output.trim_end().len();
+        writer.output.truncate(length);
+        writer.output
+    }
+}
+
+/// Characters browsers use for numeric references to the C1 control codes, from Windows-1252
+const WINDOWS_1252: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž', 'Ÿ',
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entity_table_is_sorted() {
+        assert_eq!(2231, ENTITIES.len());
+        assert!(ENTITIES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn named_references() {
+        assert_eq!(
+            "<p> ∀x ∈ ℝ ≠ ∅ ⫅̸ \u{A0}",
+            HtmlUnescape
+                .new_from_text("&lt;p&gt; &forall;x &isin; &Ropf; &ne; &empty; &nsubE; &nbsp;")
+        );
+        assert_eq!("¬it; ¬in", HtmlUnescape.new_from_text("&notit; &notin"));
+        assert_eq!("∉", HtmlUnescape.new_from_text("&notin;"));
+        assert_eq!(
+            "&unknown; & &;",
+            HtmlUnescape.new_from_text("&unknown; & &;")
+        );
+    }
+
+    #[test]
+    fn numeric_references() {
+        assert_eq!(
+            "AAé😀€\u{FFFD}\u{FFFD}\u{FFFD}",
+            HtmlUnescape.new_from_text("&#65;&#x41&#233;&#X1f600;&#128;&#0;&#xD800;&#99999999999;")
+        );
+        assert_eq!("&#; &#x;", HtmlUnescape.new_from_text("&#; &#x;"));
+    }
+
+    #[test]
+    fn html_to_text_structure() {
+        let html = r#"<!DOCTYPE html>
+            <html><head><title>Page</title><style>p { color: red; }</style></head>
+            <body>
+              <h2>Intro</h2>
+              <p>Some   <b>bold</b>
+                 text,<br>a new line &amp; a <a href="/x?a=1&b=2" title="a > b">link</a>.</p>
+              <!-- a comment -->
+              <ol><li>first<ul><li>nested</li></ul></li><li>second</li></ol>
+              <table><tr><th>Name</th><th>Age</th></tr><tr><td>Ann</td><td>42</td></tr></table>
+              <pre>  keep
+    this</pre>
+              <script>if (a < b) { alert("<p>"); }</script>
+              <p>1 < 2</p>
+            </body></html>"#;
+
+        assert_eq!(
+            "Intro\n\nSome bold text,\na new line & a link.\n\n1. first\n  - nested\n2. second\n\n\
+             Name\tAge\nAnn\t42\n\n  keep\n    this\n\n1 < 2",
+            HtmlToText.new_from_text(html)
+        );
+    }
+}
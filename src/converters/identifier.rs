@@ -0,0 +1,196 @@
+use crate::{split_line_ending, TextConverter};
+use alloc::{string::String, vec::Vec};
+
+/// Converts identifiers to `snake_case`
+///
+/// Words are split on whitespace, punctuation and camelCase humps, so any of the other styles
+/// can be converted. Every line is converted on its own
+///
+/// ```
+/// use text_converter::{converters::SnakeCase, TextConverter};
+///
+/// assert_eq!("parse_http_response", SnakeCase.new_from_text("parseHTTPResponse"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnakeCase;
+
+impl TextConverter for SnakeCase {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        convert_lines(input.as_ref(), "_", |_, word, output| {
+            output.push_str(&word.to_lowercase())
+        })
+    }
+}
+
+/// Converts identifiers to `SCREAMING_SNAKE_CASE`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScreamingSnakeCase;
+
+impl TextConverter for ScreamingSnakeCase {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        convert_lines(input.as_ref(), "_", |_, word, output| {
+            output.push_str(&word.to_uppercase())
+        })
+    }
+}
+
+/// Converts identifiers to `kebab-case`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KebabCase;
+
+impl TextConverter for KebabCase {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        convert_lines(input.as_ref(), "-", |_, word, output| {
+            output.push_str(&word.to_lowercase())
+        })
+    }
+}
+
+/// Converts identifiers to `camelCase`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CamelCase;
+
+impl TextConverter for CamelCase {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        convert_lines(input.as_ref(), "", |index, word, output| match index {
+            0 => output.push_str(&word.to_lowercase()),
+            _ => capitalize(word, output),
+        })
+    }
+}
+
+/// Converts identifiers to `PascalCase`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PascalCase;
+
+impl TextConverter for PascalCase {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        convert_lines(input.as_ref(), "", |_, word, output| {
+            capitalize(word, output)
+        })
+    }
+}
+
+/// Splits every line into words, and joins them back with `separator`, after `push_word` has
+/// changed their case
+fn convert_lines(
+    input: &str,
+    separator: &str,
+    push_word: impl Fn(usize, &str, &mut String),
+) -> String {
+    let mut output = String::with_capacity(input.len());
+
+    for line in input.split_inclusive('\n') {
+        let (content, ending) = split_line_ending(line);
+        for (index, word) in words(content).enumerate() {
+            if index > 0 {
+                output.push_str(separator);
+            }
+            push_word(index, word, &mut output);
+        }
+        output.push_str(ending);
+    }
+
+    output
+}
+
+fn capitalize(word: &str, output: &mut String) {
+    let mut chars = word.chars();
+    if let Some(first) = chars.next() {
+        output.extend(first.to_uppercase());
+        output.push_str(&chars.as_str().to_lowercase());
+    }
+}
+
+/// Words of an identifier or phrase
+///
+/// Anything that isn't alphanumeric separates words, and so do humps: a lowercase letter or
+/// digit followed by an uppercase letter (`camelCase`), or the last uppercase letter of an
+/// acronym followed by a lowercase one (`HTTPServer`). Digits stay with the word they follow
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .flat_map(split_humps)
+        .filter(|word| !word.is_empty())
+}
+
+fn split_humps(part: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = part.char_indices().collect();
+    let mut words = Vec::new();
+    let mut start = 0;
+
+    for i in 1..chars.len() {
+        let (index, current) = chars[i];
+        let previous = chars[i - 1].1;
+        let next_is_lower = chars.get(i + 1).is_some_and(|(_, c)| c.is_lowercase());
+
+        let hump = current.is_uppercase()
+            && (previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && next_is_lower));
+        if hump {
+            words.push(&part[start..index]);
+            start = index;
+        }
+    }
+
+    words.push(&part[start..]);
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_boundaries() {
+        let split = |text| words(text).collect::<Vec<_>>();
+
+        assert_eq!(
+            vec!["parse", "HTTP", "Response"],
+            split("parseHTTPResponse")
+        );
+        assert_eq!(vec!["my", "Var", "2", "name"], split("my Var_2-name"));
+        assert_eq!(vec!["utf8", "Decoder"], split("utf8Decoder"));
+        assert_eq!(vec!["IO", "Error"], split("IOError"));
+        assert_eq!(vec!["ID"], split("__ID__"));
+        assert!(split(" - ").is_empty());
+    }
+
+    #[test]
+    fn case_styles() {
+        let input = "XMLHttpRequest\nuser id\r\n";
+
+        assert_eq!(
+            "xml_http_request\nuser_id\r\n",
+            SnakeCase.new_from_text(input)
+        );
+        assert_eq!(
+            "XML_HTTP_REQUEST\nUSER_ID\r\n",
+            ScreamingSnakeCase.new_from_text(input)
+        );
+        assert_eq!(
+            "xml-http-request\nuser-id\r\n",
+            KebabCase.new_from_text(input)
+        );
+        assert_eq!("xmlHttpRequest\nuserId\r\n", CamelCase.new_from_text(input));
+        assert_eq!(
+            "XmlHttpRequest\nUserId\r\n",
+            PascalCase.new_from_text(input)
+        );
+    }
+
+    #[test]
+    fn round_trips_between_styles() {
+        let snake = "max_retry_count";
+
+        let camel = CamelCase.new_from_text(snake);
+        assert_eq!("maxRetryCount", camel);
+        assert_eq!("max-retry-count", KebabCase.new_from_text(&camel));
+        assert_eq!(snake, (PascalCase, SnakeCase).new_from_text(snake));
+    }
+}
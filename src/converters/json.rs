@@ -0,0 +1,143 @@
+use crate::{ConversionError, TextConverter};
+use serde::Serialize;
+use serde_json::{ser::PrettyFormatter, Serializer, Value};
+
+/// Reformats JSON with one value per line, indented by nesting level
+///
+/// Enabled by the `serde_json` feature. Keys keep their order and numbers are written exactly as
+/// they were. The [converter](TextConverter::converter()) method panics on invalid JSON, use the
+/// `try_*` methods to get a [`ConversionError::Syntax`] with the line and column instead
+///
+/// ```
+/// use text_converter::{converters::JsonPretty, TextConverter};
+///
+/// assert_eq!(
+///     "{\n  \"name\": \"tconv\",\n  \"tags\": [\n    \"cli\"\n  ]\n}",
+///     JsonPretty::default().new_from_text(r#"{"name":"tconv","tags":["cli"]}"#)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct JsonPretty {
+    /// Number of spaces per nesting level
+    pub indent: usize,
+}
+
+impl JsonPretty {
+    /// Creates a converter indenting by `indent` spaces per nesting level
+    pub fn new(indent: usize) -> Self {
+        Self { indent }
+    }
+}
+
+impl Default for JsonPretty {
+    /// Indents by 2 spaces
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl TextConverter for JsonPretty {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Failed to format JSON: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let value = parse(input.as_ref())?;
+        let indent = " ".repeat(self.indent);
+
+        let mut output = Vec::with_capacity(input.as_ref().len() * 2);
+        let mut serializer = Serializer::with_formatter(
+            &mut output,
+            PrettyFormatter::with_indent(indent.as_bytes()),
+        );
+        value
+            .serialize(&mut serializer)
+            .expect("Serializing a JSON value to memory cannot fail");
+
+        Ok(String::from_utf8(output).expect("serde_json writes UTF-8"))
+    }
+}
+
+/// Reformats JSON without any whitespace
+///
+/// Enabled by the `serde_json` feature. Keys keep their order and numbers are written exactly as
+/// they were. The [converter](TextConverter::converter()) method panics on invalid JSON, use the
+/// `try_*` methods to get a [`ConversionError::Syntax`] with the line and column instead
+///
+/// ```
+/// use text_converter::{converters::JsonMinify, ConversionError, TextConverter};
+///
+/// assert_eq!(r#"{"a":[1,2]}"#, JsonMinify.new_from_text("{\n  \"a\": [1, 2]\n}"));
+/// assert!(matches!(
+///     JsonMinify.try_new_from_text("{\n  \"a\": [1, 2,]\n}"),
+///     Err(ConversionError::Syntax { line: 2, column: 14, .. })
+/// ));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JsonMinify;
+
+impl TextConverter for JsonMinify {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Failed to minify JSON: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        Ok(parse(input.as_ref())?.to_string())
+    }
+}
+
+pub(super) fn parse(input: &str) -> Result<Value, ConversionError> {
+    serde_json::from_str(input).map_err(|e| {
+        let location = format!(" at line {} column {}", e.line(), e.column());
+        let message = e.to_string();
+
+        ConversionError::Syntax {
+            format: "JSON",
+            line: e.line(),
+            column: e.column(),
+            message: message
+                .strip_suffix(&location)
+                .unwrap_or(&message)
+                .to_owned(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_order_and_numbers() {
+        let json =
+            r#"{"zebra": 1, "apple": 12345678901234567890.50, "mid": {"b": null, "a": "é"}}"#;
+
+        assert_eq!(
+            r#"{"zebra":1,"apple":12345678901234567890.50,"mid":{"b":null,"a":"é"}}"#,
+            JsonMinify.new_from_text(json)
+        );
+        assert_eq!(
+            "{\n    \"zebra\": 1,\n    \"apple\": 12345678901234567890.50,\n    \"mid\": {\n        \
+             \"b\": null,\n        \"a\": \"é\"\n    }\n}",
+            JsonPretty::new(4).new_from_text(json)
+        );
+    }
+
+    #[test]
+    fn syntax_errors() {
+        let error = JsonPretty::default()
+            .try_new_from_text("[1, 2")
+            .unwrap_err();
+
+        assert_eq!(
+            "invalid JSON at line 1, column 5: EOF while parsing a list",
+            error.to_string()
+        );
+        assert!(JsonMinify.try_new_from_text("{} {}").is_err());
+    }
+}
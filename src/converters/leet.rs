@@ -0,0 +1,139 @@
+use crate::TextConverter;
+use alloc::string::String;
+
+/// How much of the text [`Leet`] replaces
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LeetIntensity {
+    /// Only vowels that look like digits: `a`, `e`, `i` and `o`
+    #[default]
+    Basic,
+    /// Every letter that looks like a digit
+    Advanced,
+    /// Every letter, using multi-character symbols where needed
+    Full,
+}
+
+const BASIC: &[(char, &str)] = &[('a', "4"), ('e', "3"), ('i', "1"), ('o', "0")];
+
+const ADVANCED: &[(char, &str)] = &[
+    ('a', "4"),
+    ('b', "8"),
+    ('e', "3"),
+    ('g', "9"),
+    ('i', "1"),
+    ('l', "1"),
+    ('o', "0"),
+    ('s', "5"),
+    ('t', "7"),
+    ('z', "2"),
+];
+
+const FULL: &[(char, &str)] = &[
+    ('a', "4"),
+    ('b', "8"),
+    ('c', "("),
+    ('d', "|)"),
+    ('e', "3"),
+    ('f', "|="),
+    ('g', "6"),
+    ('h', "#"),
+    ('i', "!"),
+    ('j', "_|"),
+    ('k', "|<"),
+    ('l', "1"),
+    ('m', "|\\/|"),
+    ('n', "|\\|"),
+    ('o', "0"),
+    ('p', "|*"),
+    ('q', "(,)"),
+    ('r', "|2"),
+    ('s', "5"),
+    ('t', "7"),
+    ('u', "|_|"),
+    ('v', "\\/"),
+    ('w', "\\/\\/"),
+    ('x', "><"),
+    ('y', "`/"),
+    ('z', "2"),
+];
+
+/// Replaces letters with look-alike digits and symbols, regardless of their case
+///
+/// ```
+/// use text_converter::{converters::{Leet, LeetIntensity}, TextConverter};
+///
+/// assert_eq!("1337 5p34k", Leet::new(LeetIntensity::Advanced).new_from_text("leet speak"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Leet {
+    /// Which substitution table to use
+    pub intensity: LeetIntensity,
+}
+
+impl Leet {
+    /// Creates a converter with the given intensity
+    pub fn new(intensity: LeetIntensity) -> Self {
+        Self { intensity }
+    }
+
+    fn table(&self) -> &'static [(char, &'static str)] {
+        match self.intensity {
+            LeetIntensity::Basic => BASIC,
+            LeetIntensity::Advanced => ADVANCED,
+            LeetIntensity::Full => FULL,
+        }
+    }
+}
+
+impl TextConverter for Leet {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+        self.converter_into(input.as_ref(), &mut output);
+        output
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+        let table = self.table();
+
+        for c in input.chars() {
+            match table
+                .iter()
+                .find(|(letter, _)| *letter == c.to_ascii_lowercase())
+            {
+                Some((_, symbol)) => output.push_str(symbol),
+                None => output.push(c),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intensities() {
+        let text = "Hello World";
+        assert_eq!(
+            "H3ll0 W0rld",
+            Leet::new(LeetIntensity::Basic).new_from_text(text)
+        );
+        assert_eq!(
+            "H3110 W0r1d",
+            Leet::new(LeetIntensity::Advanced).new_from_text(text)
+        );
+        assert_eq!(
+            "#3110 \\/\\/0|21|)",
+            Leet::new(LeetIntensity::Full).new_from_text(text)
+        );
+    }
+
+    #[test]
+    fn other_characters_are_kept() {
+        assert_eq!("ç4 v4 ? 42", Leet::default().new_from_text("ça va ? 42"));
+    }
+}
@@ -0,0 +1,206 @@
+use crate::TextConverter;
+use alloc::string::String;
+use core::fmt;
+use memchr::{memchr, memchr2_iter};
+
+/// A way of ending lines
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LineEnding {
+    /// `\n`, used by Unix-like systems
+    #[default]
+    Lf,
+    /// `\r\n`, used by Windows and many network protocols
+    CrLf,
+    /// `\r`, used by classic Mac OS
+    Cr,
+}
+
+impl LineEnding {
+    /// The characters ending a line
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+            Self::Cr => "\r",
+        }
+    }
+}
+
+/// How many lines of a text end each way, from [`LineEndings::detect`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct LineEndingCounts {
+    /// Number of `\n` not preceded by `\r`
+    pub lf: usize,
+    /// Number of `\r\n`
+    pub crlf: usize,
+    /// Number of `\r` not followed by `\n`
+    pub cr: usize,
+}
+
+impl LineEndingCounts {
+    /// Whether more than one kind of line ending is used
+    pub fn is_mixed(&self) -> bool {
+        [self.lf, self.crlf, self.cr]
+            .iter()
+            .filter(|&&count| count > 0)
+            .count()
+            > 1
+    }
+
+    /// The most used line ending, `None` for text on a single line. Ties go to LF, then CRLF
+    pub fn dominant(&self) -> Option<LineEnding> {
+        [
+            (LineEnding::Lf, self.lf),
+            (LineEnding::CrLf, self.crlf),
+            (LineEnding::Cr, self.cr),
+        ]
+        .into_iter()
+        .filter(|&(_, count)| count > 0)
+        .reduce(|best, next| match next.1 > best.1 {
+            true => next,
+            false => best,
+        })
+        .map(|(ending, _)| ending)
+    }
+}
+
+impl fmt::Display for LineEndingCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LF: {}, CRLF: {}, CR: {}", self.lf, self.crlf, self.cr)
+    }
+}
+
+/// Makes every line end the same way
+///
+/// `\n` and `\r\n` always count as line endings. A lone `\r` only counts as one in text that
+/// has no `\n` at all, the way classic Mac OS files are written, and is left alone otherwise so
+/// stray carriage returns in mixed text are kept as they are
+///
+/// ```
+/// use text_converter::{
+///     converters::{LineEnding, LineEndings},
+///     TextConverter,
+/// };
+///
+/// let windows = LineEndings::new(LineEnding::CrLf);
+/// assert_eq!("a\r\nb\r\nc\rd", windows.new_from_text("a\nb\r\nc\rd"));
+/// assert_eq!("a\r\nb\r\n", windows.new_from_text("a\rb\r"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct LineEndings {
+    /// The line ending every line gets
+    pub target: LineEnding,
+}
+
+impl LineEndings {
+    /// Creates a converter ending lines with `target`
+    pub fn new(target: LineEnding) -> Self {
+        Self { target }
+    }
+
+    /// Counts the line endings of each kind in `input`
+    ///
+    /// # Examples
+    /// ```
+    /// use text_converter::converters::{LineEnding, LineEndings};
+    ///
+    /// let counts = LineEndings::detect("a\r\nb\nc\r\n");
+    /// assert!(counts.is_mixed());
+    /// assert_eq!(Some(LineEnding::CrLf), counts.dominant());
+    /// ```
+    pub fn detect(input: impl AsRef<str>) -> LineEndingCounts {
+        let mut counts = LineEndingCounts::default();
+        let bytes = input.as_ref().as_bytes();
+
+        for i in memchr2_iter(b'\r', b'\n', bytes) {
+            match bytes[i] {
+                b'\r' if bytes.get(i + 1) == Some(&b'\n') => counts.crlf += 1,
+                b'\r' => counts.cr += 1,
+                _ if i > 0 && bytes[i - 1] == b'\r' => {}
+                _ => counts.lf += 1,
+            }
+        }
+
+        counts
+    }
+}
+
+impl TextConverter for LineEndings {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+        self.converter_into(input.as_ref(), &mut output);
+        output
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+        let bytes = input.as_bytes();
+        let lone_cr_ends_lines = memchr(b'\n', bytes).is_none();
+        let ending = self.target.as_str();
+
+        // Line breaks are found with SIMD where available, and the lines between them copied
+        // whole. They are ASCII, so they never cut a character in two
+        let mut line_start = 0;
+        for i in memchr2_iter(b'\r', b'\n', bytes) {
+            let line_end = match bytes[i] {
+                // The `\n` of a `\r\n` already replaced
+                _ if i < line_start => continue,
+                b'\r' if bytes.get(i + 1) == Some(&b'\n') => i + 2,
+                b'\r' if !lone_cr_ends_lines => continue,
+                _ => i + 1,
+            };
+            output.push_str(&input[line_start..i]);
+            output.push_str(ending);
+            line_start = line_end;
+        }
+        output.push_str(&input[line_start..]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_mixed_endings() {
+        let input = "a\r\nb\nc\r\n\rd";
+
+        assert_eq!("a\nb\nc\n\rd", LineEndings::default().new_from_text(input));
+        assert_eq!(
+            "a\rb\rc\r\rd",
+            LineEndings::new(LineEnding::Cr).new_from_text(input)
+        );
+        assert_eq!("a\nb", LineEndings::default().new_from_text("a\rb"));
+        assert_eq!(
+            "é\r\n😀\r\nü\r\n",
+            LineEndings::new(LineEnding::CrLf).new_from_text("é\n😀\r\nü\n")
+        );
+    }
+
+    #[test]
+    fn detection() {
+        let counts = LineEndings::detect("a\r\nb\nc\r\n\rd");
+        assert_eq!(
+            LineEndingCounts {
+                lf: 1,
+                crlf: 2,
+                cr: 1
+            },
+            counts
+        );
+        assert_eq!("LF: 1, CRLF: 2, CR: 1", counts.to_string());
+
+        let single_line = LineEndings::detect("abc");
+        assert!(!single_line.is_mixed());
+        assert_eq!(None, single_line.dominant());
+        assert_eq!(
+            Some(LineEnding::Lf),
+            LineEndings::detect("a\nb\r\n").dominant()
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{split_line_ending, ReversibleConverter, TextConverter};
+use alloc::{borrow::ToOwned, format, string::String, string::ToString};
+
+/// Puts the line number before every line, keeping the line breaks
+///
+/// Numbers are right-aligned. Decoding removes the numbers again, leaving lines that don't
+/// start with a number and the separator alone
+///
+/// ```
+/// use text_converter::{converters::NumberLines, ReversibleConverter, TextConverter};
+///
+/// let numbered = NumberLines::default().new_from_text("fn main() {\n}\n");
+/// assert_eq!("1: fn main() {\n2: }\n", numbered);
+/// assert_eq!("fn main() {\n}\n", NumberLines::default().decode_from_text(numbered));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct NumberLines {
+    /// Minimum width of the numbers. The default of 0 makes every number as wide as the
+    /// largest one
+    pub width: usize,
+    /// Text between the number and the line, `": "` by default
+    pub separator: String,
+    /// Number of the first line, 1 by default
+    pub start: usize,
+}
+
+impl NumberLines {
+    /// Creates a converter separating numbers from lines with `separator`, starting at 1
+    pub fn new(separator: impl Into<String>) -> Self {
+        Self {
+            width: 0,
+            separator: separator.into(),
+            start: 1,
+        }
+    }
+
+    /// The line without its number, if it has one
+    fn strip<'a>(&self, content: &'a str) -> Option<&'a str> {
+        let number = content.trim_start_matches(' ');
+        let rest = number.trim_start_matches(|c: char| c.is_ascii_digit());
+        if rest.len() == number.len() {
+            return None;
+        }
+
+        rest.strip_prefix(self.separator.as_str())
+            .or_else(|| (rest == self.separator.trim_end()).then_some(""))
+    }
+}
+
+impl Default for NumberLines {
+    fn default() -> Self {
+        Self::new(": ")
+    }
+}
+
+impl TextConverter for NumberLines {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let input = input.as_ref();
+        let lines = input.split_inclusive('\n').count();
+        // Counted in a wider type, so numbers past `usize::MAX` are written instead of overflowing
+        let start = self.start as u128;
+        let last = start + lines.saturating_sub(1) as u128;
+        let width = self.width.max(last.to_string().len());
+
+        let mut output = String::with_capacity(input.len() + lines * (width + 2));
+        for (number, line) in (start..).zip(input.split_inclusive('\n')) {
+            output.push_str(&format!("{number:>width$}{}{line}", self.separator));
+        }
+
+        output
+    }
+}
+
+impl ReversibleConverter for NumberLines {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+
+        for line in input.as_ref().split_inclusive('\n') {
+            let (content, ending) = split_line_ending(line);
+            output.push_str(self.strip(content).unwrap_or(content));
+            output.push_str(ending);
+        }
+
+        output
+    }
+}
+
+/// Removes line numbers from the start of lines, like the ones code viewers put before
+/// snippets
+///
+/// A number counts when it is followed by a tab, or by one of `:`, `|`, `.` or `)`, which may
+/// have whitespace around it. A number followed by a space alone does not, and numbers are only
+/// removed when every line that isn't blank has one, so text that merely starts with a number is
+/// left alone
+///
+/// ```
+/// use text_converter::{converters::StripLineNumbers, TextConverter};
+///
+/// assert_eq!("let a = 1;\n\nlet b = a;", StripLineNumbers.new_from_text(" 9 | let a = 1;\n10 |\n11 | let b = a;"));
+/// assert_eq!("1984 was\na year", StripLineNumbers.new_from_text("1984 was\na year"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StripLineNumbers;
+
+impl StripLineNumbers {
+    fn strip(content: &str) -> Option<&str> {
+        let number = content.trim_start();
+        let rest = number.trim_start_matches(|c: char| c.is_ascii_digit());
+        if rest.len() == number.len() {
+            return None;
+        }
+
+        let spaced = rest.trim_start_matches([' ', '\t']);
+        match spaced.strip_prefix([':', '|', '.', ')']) {
+            Some(content) => Some(content.strip_prefix([' ', '\t']).unwrap_or(content)),
+            None if rest.is_empty() => Some(rest),
+            None => rest.strip_prefix('\t'),
+        }
+    }
+}
+
+impl TextConverter for StripLineNumbers {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let input = input.as_ref();
+        let numbered = input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .all(|line| Self::strip(line).is_some());
+        if !numbered {
+            return input.to_owned();
+        }
+
+        let mut output = String::with_capacity(input.len());
+        for line in input.split_inclusive('\n') {
+            let (content, ending) = split_line_ending(line);
+            output.push_str(Self::strip(content).unwrap_or(""));
+            output.push_str(ending);
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbering_options() {
+        let numbers = NumberLines {
+            width: 3,
+            separator: "\t".to_owned(),
+            start: 9,
+        };
+        assert_eq!(
+            "  9\ta\r\n 10\t\r\n 11\tb",
+            numbers.new_from_text("a\r\n\r\nb")
+        );
+        assert_eq!(
+            " 9: a\n10: b",
+            NumberLines {
+                start: 9,
+                ..Default::default()
+            }
+            .new_from_text("a\nb")
+        );
+        assert_eq!("", NumberLines::default().new_from_text(""));
+
+        let numbers = NumberLines {
+            start: usize::MAX,
+            ..Default::default()
+        };
+        let numbered = numbers.new_from_text("a\nb\nc");
+        let max = usize::MAX as u128;
+        assert_eq!(
+            format!("{max}: a\n{}: b\n{}: c", max + 1, max + 2),
+            numbered
+        );
+        assert_eq!("a\nb\nc", numbers.decode_from_text(numbered));
+    }
+
+    #[test]
+    fn decoding_keeps_unnumbered_lines() {
+        let numbers = NumberLines::default();
+        assert_eq!(
+            "a\n\nplain\n42 b",
+            numbers.decode_from_text("1: a\n2:\nplain\n42 b")
+        );
+    }
+
+    #[test]
+    fn strip_line_numbers() {
+        assert_eq!("a\n  b", StripLineNumbers.new_from_text("1. a\n2)   b"));
+        assert_eq!("x\ny\n", StripLineNumbers.new_from_text("1\tx\n2\ty\n"));
+        assert_eq!("9 b\n10 c", StripLineNumbers.new_from_text("9 b\n10 c"));
+    }
+
+    #[test]
+    fn strip_line_numbers_round_trip() {
+        let text = "42 apples\n10 c\n\n7) seven\nplain";
+        for numbers in [
+            NumberLines::default(),
+            NumberLines::new("\t"),
+            NumberLines::new(" | "),
+        ] {
+            let numbered = numbers.new_from_text(text);
+            assert_eq!(
+                text,
+                StripLineNumbers.new_from_text(&numbered),
+                "{numbered:?}"
+            );
+            assert_eq!(text, numbers.decode_from_text(&numbered));
+        }
+    }
+}
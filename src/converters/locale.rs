@@ -0,0 +1,157 @@
+use crate::{ConversionError, TextConverter};
+use icu_casemap::{options::TitlecaseOptions, CaseMapper, TitlecaseMapper};
+use icu_locale_core::LanguageIdentifier;
+
+/// Converts every character to uppercase, following the rules of a language
+///
+/// Enabled by the `locale` feature. Unlike [`Uppercase`](super::Uppercase), Turkish and Azerbaijani `i` becomes a dotted `İ`,
+/// Greek drops its accents and so on
+///
+/// ```
+/// use text_converter::{converters::LocaleUppercase, TextConverter};
+///
+/// let turkish = LocaleUppercase::new("tr").unwrap();
+/// assert_eq!("İSTANBUL", turkish.new_from_text("istanbul"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct LocaleUppercase {
+    language: LanguageIdentifier,
+}
+
+/// Converts every character to lowercase, following the rules of a language
+///
+/// Greek sigma becomes a final `ς` at the end of words in every language, and Turkish and
+/// Azerbaijani `I` becomes a dotless `ı`
+///
+/// ```
+/// use text_converter::{converters::LocaleLowercase, TextConverter};
+///
+/// let turkish = LocaleLowercase::new("tr").unwrap();
+/// assert_eq!("ıi", turkish.new_from_text("Iİ"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct LocaleLowercase {
+    language: LanguageIdentifier,
+}
+
+/// Capitalizes the first letter of every word and lowercases the rest, following the rules of a
+/// language
+///
+/// Words are separated by whitespace, like with [`TitleCase`](super::TitleCase), and leading
+/// punctuation is skipped. Dutch capitalizes the `IJ` digraph as a whole
+///
+/// ```
+/// use text_converter::{converters::LocaleTitleCase, TextConverter};
+///
+/// let dutch = LocaleTitleCase::new("nl").unwrap();
+/// assert_eq!("IJsselmeer (IJmuiden)", dutch.new_from_text("ijsselmeer (ijmuiden)"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct LocaleTitleCase {
+    language: LanguageIdentifier,
+}
+
+macro_rules! impl_locale_constructors {
+    ($($converter:ident),+) => {$(
+        impl $converter {
+            /// Follows the rules of the language with the BCP 47 tag `language`, like `tr` or
+            /// `el-GR`. The default uses language-independent rules
+            ///
+            /// # Errors
+            /// [`ConversionError::InvalidInput`] if `language` is not a valid language tag
+            pub fn new(language: &str) -> Result<Self, ConversionError> {
+                let language = language.parse().map_err(|e| {
+                    ConversionError::InvalidInput(format!(
+                        "Invalid language tag {language:?}: {e}"
+                    ))
+                })?;
+                Ok(Self { language })
+            }
+
+            /// BCP 47 tag of the language whose rules are followed
+            pub fn language(&self) -> String {
+                self.language.to_string()
+            }
+        }
+
+        impl Default for $converter {
+            fn default() -> Self {
+                Self { language: LanguageIdentifier::UNKNOWN }
+            }
+        }
+    )+};
+}
+
+impl_locale_constructors!(LocaleUppercase, LocaleLowercase, LocaleTitleCase);
+
+impl TextConverter for LocaleUppercase {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        CaseMapper::new()
+            .uppercase_to_string(input.as_ref(), &self.language)
+            .into_owned()
+    }
+}
+
+impl TextConverter for LocaleLowercase {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        CaseMapper::new()
+            .lowercase_to_string(input.as_ref(), &self.language)
+            .into_owned()
+    }
+}
+
+impl TextConverter for LocaleTitleCase {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mapper = TitlecaseMapper::new();
+        let mut output = String::with_capacity(input.as_ref().len());
+
+        for word in input.as_ref().split_inclusive(char::is_whitespace) {
+            output.push_str(&mapper.titlecase_segment_to_string(
+                word,
+                &self.language,
+                TitlecaseOptions::default(),
+            ));
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn language_rules() {
+        let root = LocaleUppercase::default();
+        assert_eq!("ISTANBUL STRASSE", root.new_from_text("istanbul straße"));
+        assert_eq!("und", root.language());
+
+        let greek = LocaleLowercase::new("el").unwrap();
+        assert_eq!("οδυσσευς", greek.new_from_text("ΟΔΥΣΣΕΥΣ"));
+        assert_eq!(
+            "ΟΔΥΣΣΕΥΣ",
+            LocaleUppercase::new("el")
+                .unwrap()
+                .new_from_text("Οδυσσεύς")
+        );
+
+        let turkish = LocaleTitleCase::new("tr-TR").unwrap();
+        assert_eq!("İyi Işıklar\n", turkish.new_from_text("iyi IŞIKLAR\n"));
+        assert_eq!("tr-TR", turkish.language());
+    }
+
+    #[test]
+    fn invalid_language_tag() {
+        assert!(matches!(
+            LocaleUppercase::new("not a tag"),
+            Err(ConversionError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,72 @@
+use crate::TextConverter;
+use pulldown_cmark::{html, Options, Parser};
+
+/// Renders Markdown to HTML, with the GitHub extensions for tables, strikethrough, task lists and
+/// footnotes
+///
+/// Enabled by the `pulldown-cmark` feature. Output files get the `html` extension, unless
+/// [`OutputOptions`](crate::OutputOptions) say otherwise
+///
+/// ```
+/// use text_converter::{converters::MarkdownToHtml, TextConverter};
+///
+/// let html = MarkdownToHtml.new_from_text("# Notes\n\nSome *emphasis* and ~~mistakes~~");
+/// assert_eq!(
+///     "<h1>Notes</h1>\n<p>Some <em>emphasis</em> and <del>mistakes</del></p>\n",
+///     html
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarkdownToHtml;
+
+impl TextConverter for MarkdownToHtml {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let options = Options::ENABLE_TABLES
+            | Options::ENABLE_STRIKETHROUGH
+            | Options::ENABLE_TASKLISTS
+            | Options::ENABLE_FOOTNOTES;
+        let parser = Parser::new_ext(input.as_ref(), options);
+
+        let mut output = String::with_capacity(input.as_ref().len() * 3 / 2);
+        html::push_html(&mut output, parser);
+        output
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        Some("html")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{converters::Trim, OutputOptions};
+    use std::{env, fs};
+
+    #[test]
+    fn renders_extensions() {
+        let html = MarkdownToHtml.new_from_text("- [x] done\n\n| a |\n|---|\n| b |\n");
+
+        assert!(html.contains(r#"<input disabled="" type="checkbox" checked=""/>"#));
+        assert!(html.contains("<td>b</td>"));
+    }
+
+    #[test]
+    fn outputs_html_files() {
+        let dir = env::temp_dir().join("text_converter_markdown");
+        fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("notes.md");
+        fs::write(&input, "*hi*").unwrap();
+
+        (Trim, MarkdownToHtml, Trim).new_from_file(&input);
+        assert_eq!(
+            "<p><em>hi</em></p>",
+            fs::read_to_string(dir.join("notes_converted.html")).unwrap()
+        );
+
+        let options = OutputOptions::new().keep_extension();
+        MarkdownToHtml.new_from_file_with(&input, &options);
+        assert!(dir.join("notes_converted.md").exists());
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{ReversibleConverter, TextConverter};
+use alloc::string::String;
+
+/// Styles of the Unicode Mathematical Alphanumeric Symbols block, in the order of the block
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MathStyle {
+    /// 𝐁𝐨𝐥𝐝
+    #[default]
+    Bold,
+    /// 𝐼𝑡𝑎𝑙𝑖𝑐
+    Italic,
+    /// 𝑩𝒐𝒍𝒅 𝒊𝒕𝒂𝒍𝒊𝒄
+    BoldItalic,
+    /// 𝒮𝒸𝓇𝒾𝓅𝓉
+    Script,
+    /// 𝓑𝓸𝓵𝓭 𝓼𝓬𝓻𝓲𝓹𝓽
+    BoldScript,
+    /// 𝔉𝔯𝔞𝔨𝔱𝔲𝔯
+    Fraktur,
+    /// 𝔻𝕠𝕦𝕓𝕝𝕖-𝕤𝕥𝕣𝕦𝕔𝕜
+    DoubleStruck,
+    /// 𝕭𝖔𝖑𝖉 𝖋𝖗𝖆𝖐𝖙𝖚𝖗
+    BoldFraktur,
+    /// 𝖲𝖺𝗇𝗌-𝗌𝖾𝗋𝗂𝖿
+    SansSerif,
+    /// 𝗦𝗮𝗻𝘀-𝘀𝗲𝗿𝗶𝗳 𝗯𝗼𝗹𝗱
+    SansSerifBold,
+    /// 𝘚𝘢𝘯𝘴-𝘴𝘦𝘳𝘪𝘧 𝘪𝘵𝘢𝘭𝘪𝘤
+    SansSerifItalic,
+    /// 𝙎𝙖𝙣𝙨-𝙨𝙚𝙧𝙞𝙛 𝙗𝙤𝙡𝙙 𝙞𝙩𝙖𝙡𝙞𝙘
+    SansSerifBoldItalic,
+    /// 𝙼𝚘𝚗𝚘𝚜𝚙𝚊𝚌𝚎
+    Monospace,
+}
+
+const LETTERS_START: u32 = 0x1D400;
+const LETTERS_END: u32 = 0x1D6A3;
+const DIGITS_START: u32 = 0x1D7CE;
+const DIGITS_END: u32 = 0x1D7FF;
+
+/// Letters that were encoded before the block, and are left as holes in it
+const EXCEPTIONS: &[(MathStyle, char, char)] = &[
+    (MathStyle::Italic, 'h', 'ℎ'),
+    (MathStyle::Script, 'B', 'ℬ'),
+    (MathStyle::Script, 'E', 'ℰ'),
+    (MathStyle::Script, 'F', 'ℱ'),
+    (MathStyle::Script, 'H', 'ℋ'),
+    (MathStyle::Script, 'I', 'ℐ'),
+    (MathStyle::Script, 'L', 'ℒ'),
+    (MathStyle::Script, 'M', 'ℳ'),
+    (MathStyle::Script, 'R', 'ℛ'),
+    (MathStyle::Script, 'e', 'ℯ'),
+    (MathStyle::Script, 'g', 'ℊ'),
+    (MathStyle::Script, 'o', 'ℴ'),
+    (MathStyle::Fraktur, 'C', 'ℭ'),
+    (MathStyle::Fraktur, 'H', 'ℌ'),
+    (MathStyle::Fraktur, 'I', 'ℑ'),
+    (MathStyle::Fraktur, 'R', 'ℜ'),
+    (MathStyle::Fraktur, 'Z', 'ℨ'),
+    (MathStyle::DoubleStruck, 'C', 'ℂ'),
+    (MathStyle::DoubleStruck, 'H', 'ℍ'),
+    (MathStyle::DoubleStruck, 'N', 'ℕ'),
+    (MathStyle::DoubleStruck, 'P', 'ℙ'),
+    (MathStyle::DoubleStruck, 'Q', 'ℚ'),
+    (MathStyle::DoubleStruck, 'R', 'ℝ'),
+    (MathStyle::DoubleStruck, 'Z', 'ℤ'),
+];
+
+impl MathStyle {
+    /// First of the 10 styled digits, for the styles that have them
+    fn digits_start(self) -> Option<u32> {
+        match self {
+            Self::Bold => Some(0x1D7CE),
+            Self::DoubleStruck => Some(0x1D7D8),
+            Self::SansSerif => Some(0x1D7E2),
+            Self::SansSerifBold => Some(0x1D7EC),
+            Self::Monospace => Some(0x1D7F6),
+            _ => None,
+        }
+    }
+
+    fn apply(self, c: char) -> char {
+        if let Some((_, _, styled)) = EXCEPTIONS
+            .iter()
+            .find(|(style, plain, _)| *style == self && *plain == c)
+        {
+            return *styled;
+        }
+
+        let letters = LETTERS_START + 52 * self as u32;
+        let code = match c {
+            'A'..='Z' => letters + (c as u32 - 'A' as u32),
+            'a'..='z' => letters + 26 + (c as u32 - 'a' as u32),
+            '0'..='9' => match self.digits_start() {
+                Some(digits) => digits + (c as u32 - '0' as u32),
+                None => return c,
+            },
+            _ => return c,
+        };
+
+        char::from_u32(code).unwrap_or(c)
+    }
+}
+
+/// Styles ASCII letters and digits with the Unicode Mathematical Alphanumeric Symbols, like 𝐛𝐨𝐥𝐝
+/// or 𝚖𝚘𝚗𝚘𝚜𝚙𝚊𝚌𝚎 text
+///
+/// Styles without digits keep them plain, and other characters are kept as they are. Decoding
+/// uses [`UnicodeUnstyle`], turning text in any style back into ASCII
+///
+/// ```
+/// use text_converter::{converters::{MathStyle, UnicodeStyle}, ReversibleConverter, TextConverter};
+///
+/// let bold = UnicodeStyle::new(MathStyle::Bold);
+/// assert_eq!("𝐛𝐨𝐥𝐝 𝟏𝟐𝟑!", bold.new_from_text("bold 123!"));
+/// assert_eq!("bold 123!", bold.decode_from_text("𝐛𝐨𝐥𝐝 𝟏𝟐𝟑!"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct UnicodeStyle {
+    /// Style of the letters and digits
+    pub style: MathStyle,
+}
+
+impl UnicodeStyle {
+    /// Creates a converter using the given style
+    pub fn new(style: MathStyle) -> Self {
+        Self { style }
+    }
+}
+
+impl TextConverter for UnicodeStyle {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        input
+            .as_ref()
+            .chars()
+            .map(|c| self.style.apply(c))
+            .collect()
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+        output.extend(input.chars().map(|c| self.style.apply(c)));
+    }
+}
+
+impl ReversibleConverter for UnicodeStyle {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        UnicodeUnstyle.converter(input)
+    }
+}
+
+/// Turns letters and digits styled with the Unicode Mathematical Alphanumeric Symbols back into
+/// plain ASCII, whatever their style
+///
+/// Styled Greek letters and other symbols of the block are kept as they are
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnicodeUnstyle;
+
+impl TextConverter for UnicodeUnstyle {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        input.as_ref().chars().map(unstyle).collect()
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+        output.extend(input.chars().map(unstyle));
+    }
+}
+
+fn unstyle(c: char) -> char {
+    let code = c as u32;
+    let plain = match code {
+        LETTERS_START..=LETTERS_END => match (code - LETTERS_START) % 52 {
+            letter @ 0..=25 => b'A' + letter as u8,
+            letter => b'a' + (letter - 26) as u8,
+        },
+        DIGITS_START..=DIGITS_END => b'0' + ((code - DIGITS_START) % 10) as u8,
+        _ => {
+            return EXCEPTIONS
+                .iter()
+                .find(|(_, _, styled)| *styled == c)
+                .map_or(c, |(_, plain, _)| *plain)
+        }
+    };
+
+    plain as char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn styles() {
+        let style = |style| UnicodeStyle::new(style).new_from_text("Hello 42");
+
+        assert_eq!("𝐻𝑒𝑙𝑙𝑜 42", style(MathStyle::Italic));
+        assert_eq!("ℋℯ𝓁𝓁ℴ 42", style(MathStyle::Script));
+        assert_eq!("ℌ𝔢𝔩𝔩𝔬 42", style(MathStyle::Fraktur));
+        assert_eq!("ℍ𝕖𝕝𝕝𝕠 𝟜𝟚", style(MathStyle::DoubleStruck));
+        assert_eq!("𝙷𝚎𝚕𝚕𝚘 𝟺𝟸", style(MathStyle::Monospace));
+        assert_eq!("𝙃𝙚𝙡𝙡𝙤 42", style(MathStyle::SansSerifBoldItalic));
+    }
+
+    #[test]
+    fn unstyles_every_style() {
+        let styles = [
+            MathStyle::Bold,
+            MathStyle::Italic,
+            MathStyle::BoldItalic,
+            MathStyle::Script,
+            MathStyle::BoldScript,
+            MathStyle::Fraktur,
+            MathStyle::DoubleStruck,
+            MathStyle::BoldFraktur,
+            MathStyle::SansSerif,
+            MathStyle::SansSerifBold,
+            MathStyle::SansSerifItalic,
+            MathStyle::SansSerifBoldItalic,
+            MathStyle::Monospace,
+        ];
+        let text = "The quick brown fox jumps over the lazy dog, 0123456789 times!\n";
+
+        for style in styles {
+            let styled = UnicodeStyle::new(style).new_from_text(text);
+            assert_ne!(text, styled, "{style:?}");
+            assert_eq!(text, UnicodeUnstyle.new_from_text(styled), "{style:?}");
+        }
+        assert_eq!("A𝛂", UnicodeUnstyle.new_from_text("𝐀𝛂"));
+    }
+}
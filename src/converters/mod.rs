@@ -0,0 +1,195 @@
+//! Ready-made converters for common transformations
+//!
+//! Enabled by the `converters` feature, which is on by default. With the `serde` feature, every
+//! converter here implements `Serialize` and `Deserialize`, and so do tuple and vector
+//! [pipelines](crate::pipeline) of them, so a conversion setup can be stored and loaded back
+//!
+//! # Examples
+//!
+//! ```
+//! use text_converter::{converters::{TitleCase, Trim}, TextConverter};
+//!
+//! let title = (Trim, TitleCase).new_from_text("  the quick brown fox ");
+//! assert_eq!("The Quick Brown Fox", title);
+//! ```
+
+mod ascii;
+#[cfg(feature = "figlet-rs")]
+mod banner;
+mod base64;
+mod binary;
+mod braille;
+mod case;
+mod cipher;
+mod emoji;
+mod fullwidth;
+mod hex;
+mod html;
+mod html_entities;
+mod identifier;
+#[cfg(feature = "serde_json")]
+mod json;
+mod leet;
+mod line_endings;
+mod line_numbers;
+#[cfg(feature = "locale")]
+mod locale;
+#[cfg(feature = "pulldown-cmark")]
+mod markdown;
+mod math_style;
+mod morse;
+mod nato;
+#[cfg(feature = "unicode-normalization")]
+mod normalize;
+mod order;
+mod pig_latin;
+#[cfg(feature = "regex")]
+mod replace;
+#[cfg(feature = "rhai")]
+mod script;
+mod table;
+mod template;
+#[cfg(feature = "deunicode")]
+mod transliterate;
+mod url;
+mod whitespace;
+mod wrap;
+#[cfg(feature = "serde_yaml")]
+mod yaml;
+mod zalgo;
+
+#[cfg(feature = "figlet-rs")]
+pub use banner::{Banner, FigletFont};
+pub use base64::{Base64Alphabet, Base64Decode, Base64Encode};
+pub use binary::Binary;
+pub use braille::Braille;
+pub use case::{Lowercase, MockingCase, SwapCase, TitleCase, Uppercase};
+pub use cipher::{CaesarCipher, Rot13, Vigenere};
+pub use emoji::Emojize;
+pub use fullwidth::Fullwidth;
+pub use hex::{HexDecode, HexDump};
+pub use html::{HtmlEscape, HtmlToText, HtmlUnescape};
+pub use identifier::{CamelCase, KebabCase, PascalCase, ScreamingSnakeCase, SnakeCase};
+#[cfg(feature = "serde_json")]
+pub use json::{JsonMinify, JsonPretty};
+pub use leet::{Leet, LeetIntensity};
+pub use line_endings::{LineEnding, LineEndingCounts, LineEndings};
+pub use line_numbers::{NumberLines, StripLineNumbers};
+#[cfg(feature = "locale")]
+pub use locale::{LocaleLowercase, LocaleTitleCase, LocaleUppercase};
+#[cfg(feature = "pulldown-cmark")]
+pub use markdown::MarkdownToHtml;
+pub use math_style::{MathStyle, UnicodeStyle, UnicodeUnstyle};
+pub use morse::Morse;
+pub use nato::Nato;
+#[cfg(feature = "unicode-normalization")]
+pub use normalize::{NormalizationForm, Normalize};
+pub use order::{DedupLines, DedupScope, Reverse, ReverseLines, ReverseWords, SortKey, SortLines};
+pub use pig_latin::PigLatin;
+#[cfg(feature = "regex")]
+pub use replace::{RegexOptions, RegexReplace};
+#[cfg(feature = "rhai")]
+pub use script::ScriptConverter;
+pub use table::{CsvHeader, CsvToMarkdown, MarkdownToCsv};
+pub use template::TemplateConverter;
+#[cfg(feature = "deunicode")]
+pub use transliterate::Transliterate;
+pub use url::{UrlDecode, UrlEncode, UrlEncodeSet};
+pub use whitespace::{
+    CollapseWhitespace, NormalizeWhitespace, RemoveEmptyLines, RemoveWhitespace, Trim, TrimLines,
+};
+pub use wrap::WordWrap;
+#[cfg(feature = "config")]
+pub(crate) use yaml::syntax_error as yaml_error;
+#[cfg(feature = "serde_yaml")]
+pub use yaml::{JsonToYaml, YamlToJson};
+pub use zalgo::{Zalgo, ZalgoIntensity, ZalgoStrip};
+
+use crate::{ConversionError, ConversionMode};
+use alloc::{format, string::String, string::ToString};
+#[cfg(feature = "std")]
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+};
+
+/// What a converter does with characters it has no mapping for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Unmappable {
+    /// Leave them out of the output
+    #[default]
+    Skip,
+    /// Fail with [`ConversionError::InvalidInput`]
+    Error,
+    /// Copy them to the output unchanged
+    Passthrough,
+    /// Output this character in their place
+    Replace(char),
+}
+
+impl From<ConversionMode> for Unmappable {
+    fn from(mode: ConversionMode) -> Self {
+        match mode {
+            ConversionMode::Strict => Self::Error,
+            ConversionMode::Lossy => Self::Replace('?'),
+            ConversionMode::Skip => Self::Skip,
+        }
+    }
+}
+
+impl Unmappable {
+    /// Picks what to output for `original`, given the result of looking up its mapping
+    pub(crate) fn resolve(
+        self,
+        mapped: Option<String>,
+        original: String,
+        converter: &str,
+    ) -> Result<Option<String>, ConversionError> {
+        match (mapped, self) {
+            (Some(mapped), _) => Ok(Some(mapped)),
+            (None, Self::Skip) => Ok(None),
+            (None, Self::Passthrough) => Ok(Some(original)),
+            (None, Self::Replace(replacement)) => Ok(Some(replacement.to_string())),
+            (None, Self::Error) => Err(ConversionError::InvalidInput(format!(
+                "`{original}` cannot be converted to {converter}"
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn entropy() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+/// Without `std` there is no source of randomness, so generators without a seed all start alike
+#[cfg(not(feature = "std"))]
+fn entropy() -> u64 {
+    0x853c_49e6_748f_ea9b
+}
+
+/// Small and fast pseudo-random number generator (SplitMix64), plenty for converters that add
+/// randomness for fun
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    /// Generates the same numbers every time for a given seed, or different ones on every run
+    /// without one
+    pub(crate) fn new(seed: Option<u64>) -> Self {
+        Self(seed.unwrap_or_else(entropy))
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Number in `0..n`
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
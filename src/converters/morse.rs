@@ -0,0 +1,201 @@
+use super::Unmappable;
+use crate::{ConversionError, ReversibleConverter, TextConverter};
+use alloc::{borrow::ToOwned, string::String, string::ToString, vec::Vec};
+
+const TABLE: &[(char, &str)] = &[
+    ('A', ".-"),
+    ('B', "-..."),
+    ('C', "-.-."),
+    ('D', "-.."),
+    ('E', "."),
+    ('F', "..-."),
+    ('G', "--."),
+    ('H', "...."),
+    ('I', ".."),
+    ('J', ".---"),
+    ('K', "-.-"),
+    ('L', ".-.."),
+    ('M', "--"),
+    ('N', "-."),
+    ('O', "---"),
+    ('P', ".--."),
+    ('Q', "--.-"),
+    ('R', ".-."),
+    ('S', "..."),
+    ('T', "-"),
+    ('U', "..-"),
+    ('V', "...-"),
+    ('W', ".--"),
+    ('X', "-..-"),
+    ('Y', "-.--"),
+    ('Z', "--.."),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+    ('.', ".-.-.-"),
+    (',', "--..--"),
+    ('?', "..--.."),
+    ('\'', ".----."),
+    ('!', "-.-.--"),
+    ('/', "-..-."),
+    ('(', "-.--."),
+    (')', "-.--.-"),
+    ('&', ".-..."),
+    (':', "---..."),
+    (';', "-.-.-."),
+    ('=', "-...-"),
+    ('+', ".-.-."),
+    ('-', "-....-"),
+    ('_', "..--.-"),
+    ('"', ".-..-."),
+    ('$', "...-..-"),
+    ('@', ".--.-."),
+];
+
+/// Separator written between words
+const WORD_SEPARATOR: &str = " / ";
+
+/// Encodes text as International Morse code, with letters separated by spaces and words by
+/// ` / `. Letters are case insensitive and decode to uppercase
+///
+/// ```
+/// use text_converter::{converters::Morse, ReversibleConverter, TextConverter};
+///
+/// assert_eq!("... --- ... / .... . .-.. .--.", Morse::default().new_from_text("SOS help"));
+/// assert_eq!("SOS HELP", Morse::default().decode_from_text("... --- ... / .... . .-.. .--."));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Morse {
+    /// What to do with characters that have no Morse code, and with unknown codes when decoding
+    pub unmappable: Unmappable,
+}
+
+impl Morse {
+    /// Creates a converter handling unmappable characters as given, or as a
+    /// [`ConversionMode`](crate::ConversionMode) says
+    pub fn new(unmappable: impl Into<Unmappable>) -> Self {
+        Self {
+            unmappable: unmappable.into(),
+        }
+    }
+
+    fn encode(&self, input: &str) -> Result<String, ConversionError> {
+        let mut words = Vec::new();
+
+        for word in input.split_whitespace() {
+            let mut codes = Vec::new();
+            for c in word.chars() {
+                let code = TABLE
+                    .iter()
+                    .find(|(letter, _)| *letter == c.to_ascii_uppercase())
+                    .map(|(_, code)| code.to_string());
+
+                if let Some(code) = self.unmappable.resolve(code, c.to_string(), "Morse")? {
+                    codes.push(code);
+                }
+            }
+
+            if !codes.is_empty() {
+                words.push(codes.join(" "));
+            }
+        }
+
+        Ok(words.join(WORD_SEPARATOR))
+    }
+
+    fn decode(&self, input: &str) -> Result<String, ConversionError> {
+        let mut words = Vec::new();
+
+        for word in input.split('/') {
+            let mut letters = String::new();
+            for code in word.split_whitespace() {
+                let letter = TABLE
+                    .iter()
+                    .find(|(_, known)| *known == code)
+                    .map(|(letter, _)| letter.to_string());
+
+                if let Some(letter) = self.unmappable.resolve(letter, code.to_owned(), "Morse")? {
+                    letters.push_str(&letter);
+                }
+            }
+
+            if !letters.is_empty() {
+                words.push(letters);
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+}
+
+impl TextConverter for Morse {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Failed to encode Morse code: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.encode(input.as_ref())
+    }
+}
+
+impl ReversibleConverter for Morse {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        self.try_deconverter(input)
+            .unwrap_or_else(|e| panic!("Failed to decode Morse code: {e}"))
+    }
+
+    fn try_deconverter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.decode(input.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let morse = Morse::default();
+        let text = "THE QUICK BROWN FOX JUMPS OVER 13 LAZY DOGS, OK?";
+        assert_eq!(text, morse.decode_from_text(morse.new_from_text(text)));
+        assert_eq!("-..-. / -..-.", morse.new_from_text("/ \n /"));
+        assert_eq!("/ /", morse.decode_from_text("-..-. / -..-."));
+    }
+
+    #[test]
+    fn unmappable_policies() {
+        let text = "hé!";
+        assert_eq!(
+            ".... -.-.--",
+            Morse::new(Unmappable::Skip).new_from_text(text)
+        );
+        assert_eq!(
+            ".... é -.-.--",
+            Morse::new(Unmappable::Passthrough).new_from_text(text)
+        );
+        assert!(matches!(
+            Morse::new(Unmappable::Error).try_new_from_text(text),
+            Err(ConversionError::InvalidInput(_))
+        ));
+
+        let code = ".... ..--..-- ..";
+        assert_eq!("HI", Morse::new(Unmappable::Skip).decode_from_text(code));
+        assert_eq!(
+            "H..--..--I",
+            Morse::new(Unmappable::Passthrough).decode_from_text(code)
+        );
+        assert!(Morse::new(Unmappable::Error)
+            .try_decode_from_text(code)
+            .is_err());
+    }
+}
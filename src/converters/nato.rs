@@ -0,0 +1,195 @@
+use super::Unmappable;
+use crate::{ConversionError, ReversibleConverter, TextConverter};
+use alloc::{borrow::ToOwned, string::String, string::ToString, vec::Vec};
+
+const TABLE: &[(char, &str)] = &[
+    ('A', "Alfa"),
+    ('B', "Bravo"),
+    ('C', "Charlie"),
+    ('D', "Delta"),
+    ('E', "Echo"),
+    ('F', "Foxtrot"),
+    ('G', "Golf"),
+    ('H', "Hotel"),
+    ('I', "India"),
+    ('J', "Juliett"),
+    ('K', "Kilo"),
+    ('L', "Lima"),
+    ('M', "Mike"),
+    ('N', "November"),
+    ('O', "Oscar"),
+    ('P', "Papa"),
+    ('Q', "Quebec"),
+    ('R', "Romeo"),
+    ('S', "Sierra"),
+    ('T', "Tango"),
+    ('U', "Uniform"),
+    ('V', "Victor"),
+    ('W', "Whiskey"),
+    ('X', "X-ray"),
+    ('Y', "Yankee"),
+    ('Z', "Zulu"),
+    ('0', "Zero"),
+    ('1', "One"),
+    ('2', "Two"),
+    ('3', "Three"),
+    ('4', "Four"),
+    ('5', "Five"),
+    ('6', "Six"),
+    ('7', "Seven"),
+    ('8', "Eight"),
+    ('9', "Nine"),
+];
+
+/// Other spellings understood when decoding, from English usage and radiotelephony
+const ALIASES: &[(char, &str)] = &[
+    ('A', "Alpha"),
+    ('J', "Juliet"),
+    ('X', "Xray"),
+    ('3', "Tree"),
+    ('5', "Fife"),
+    ('9', "Niner"),
+];
+
+/// Separator written between words
+const WORD_SEPARATOR: &str = " / ";
+
+/// Spells text out in the NATO phonetic alphabet, to read codes over the phone without letters
+/// being misheard
+///
+/// Letters and digits are spelled with one word each, separated by spaces, and words of the
+/// input by ` / `. Letters are case insensitive and decode to uppercase. Decoding also accepts
+/// spellings like `Alpha`, `Juliet` and `Niner`, in any case
+///
+/// ```
+/// use text_converter::{converters::Nato, ReversibleConverter, TextConverter};
+///
+/// assert_eq!("Alfa Bravo Charlie / One Two", Nato::default().new_from_text("abc 12"));
+/// assert_eq!("ABC 12", Nato::default().decode_from_text("alpha bravo charlie / one two"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Nato {
+    /// What to do with characters that have no code word, and with unknown words when decoding
+    pub unmappable: Unmappable,
+}
+
+impl Nato {
+    /// Creates a converter handling unmappable characters as given, or as a
+    /// [`ConversionMode`](crate::ConversionMode) says
+    pub fn new(unmappable: impl Into<Unmappable>) -> Self {
+        Self {
+            unmappable: unmappable.into(),
+        }
+    }
+
+    fn encode(&self, input: &str) -> Result<String, ConversionError> {
+        let mut words = Vec::new();
+
+        for word in input.split_whitespace() {
+            let mut spelled = Vec::new();
+            for c in word.chars() {
+                let code = TABLE
+                    .iter()
+                    .find(|(letter, _)| *letter == c.to_ascii_uppercase())
+                    .map(|(_, code)| (*code).to_owned());
+
+                if let Some(code) = self.unmappable.resolve(code, c.to_string(), "NATO")? {
+                    spelled.push(code);
+                }
+            }
+
+            if !spelled.is_empty() {
+                words.push(spelled.join(" "));
+            }
+        }
+
+        Ok(words.join(WORD_SEPARATOR))
+    }
+
+    fn decode(&self, input: &str) -> Result<String, ConversionError> {
+        let mut words = Vec::new();
+
+        for word in input.split('/') {
+            let mut letters = String::new();
+            for code in word.split_whitespace() {
+                let letter = TABLE
+                    .iter()
+                    .chain(ALIASES)
+                    .find(|(_, known)| known.eq_ignore_ascii_case(code))
+                    .map(|(letter, _)| letter.to_string());
+
+                if let Some(letter) = self.unmappable.resolve(letter, code.to_owned(), "NATO")? {
+                    letters.push_str(&letter);
+                }
+            }
+
+            if !letters.is_empty() {
+                words.push(letters);
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+}
+
+impl TextConverter for Nato {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Failed to spell out in NATO: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.encode(input.as_ref())
+    }
+}
+
+impl ReversibleConverter for Nato {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        self.try_deconverter(input)
+            .unwrap_or_else(|e| panic!("Failed to decode NATO: {e}"))
+    }
+
+    fn try_deconverter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.decode(input.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let nato = Nato::default();
+        let text = "THE QUICK BROWN FOX JUMPS OVER 13 LAZY DOGS";
+        assert_eq!(text, nato.decode_from_text(nato.new_from_text(text)));
+        assert_eq!("X-ray Nine", nato.new_from_text("x9"));
+        assert_eq!("X9 3", nato.decode_from_text("XRAY niner / Tree"));
+    }
+
+    #[test]
+    fn unmappable_policies() {
+        let text = "a-1";
+        assert_eq!("Alfa One", Nato::new(Unmappable::Skip).new_from_text(text));
+        assert_eq!(
+            "Alfa - One",
+            Nato::new(Unmappable::Passthrough).new_from_text(text)
+        );
+        assert!(matches!(
+            Nato::new(Unmappable::Error).try_new_from_text(text),
+            Err(ConversionError::InvalidInput(_))
+        ));
+
+        let code = "Alfa Bogus One";
+        assert_eq!("A1", Nato::new(Unmappable::Skip).decode_from_text(code));
+        assert_eq!(
+            "ABogus1",
+            Nato::new(Unmappable::Passthrough).decode_from_text(code)
+        );
+        assert!(Nato::new(Unmappable::Error)
+            .try_decode_from_text(code)
+            .is_err());
+    }
+}
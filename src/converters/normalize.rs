@@ -0,0 +1,107 @@
+use crate::{ConversionError, TextConverter};
+use std::borrow::Cow;
+use unicode_normalization::{
+    is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick, IsNormalized, UnicodeNormalization,
+};
+
+/// One of the Unicode normalization forms
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NormalizationForm {
+    /// Canonical composition, `e` and a combining accent become `é`
+    #[default]
+    Nfc,
+    /// Canonical decomposition, `é` becomes `e` and a combining accent
+    Nfd,
+    /// Compatibility composition, also turns look-alikes like `ﬁ` or `²` into plain characters
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+/// Brings text to a Unicode normalization form, so text that looks the same is encoded the same
+///
+/// Enabled by the `unicode-normalization` feature. Useful before comparing or hashing text that
+/// comes from different sources
+///
+/// ```
+/// use text_converter::{
+///     converters::{NormalizationForm, Normalize},
+///     TextConverter,
+/// };
+///
+/// assert_eq!("caf\u{e9}", Normalize::default().new_from_text("cafe\u{301}"));
+/// assert_eq!("fi2", Normalize::new(NormalizationForm::Nfkc).new_from_text("\u{fb01}\u{b2}"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Normalize {
+    /// The form the text is brought to
+    pub form: NormalizationForm,
+}
+
+impl Normalize {
+    /// Creates a converter normalizing to `form`
+    pub fn new(form: NormalizationForm) -> Self {
+        Self { form }
+    }
+}
+
+impl TextConverter for Normalize {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let input = input.as_ref();
+
+        match self.form {
+            NormalizationForm::Nfc => input.nfc().collect(),
+            NormalizationForm::Nfd => input.nfd().collect(),
+            NormalizationForm::Nfkc => input.nfkc().collect(),
+            NormalizationForm::Nfkd => input.nfkd().collect(),
+        }
+    }
+
+    fn converter_cow<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        let normalized = match self.form {
+            NormalizationForm::Nfc => is_nfc_quick(input.chars()),
+            NormalizationForm::Nfd => is_nfd_quick(input.chars()),
+            NormalizationForm::Nfkc => is_nfkc_quick(input.chars()),
+            NormalizationForm::Nfkd => is_nfkd_quick(input.chars()),
+        };
+
+        match normalized {
+            IsNormalized::Yes => Cow::Borrowed(input),
+            IsNormalized::No | IsNormalized::Maybe => Cow::Owned(self.converter(input)),
+        }
+    }
+
+    fn try_converter_cow<'a>(&self, input: &'a str) -> Result<Cow<'a, str>, ConversionError> {
+        Ok(self.converter_cow(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_forms() {
+        let input = "\u{e9}\u{fb01}";
+
+        assert_eq!(
+            "\u{e9}\u{fb01}",
+            Normalize::new(NormalizationForm::Nfc).new_from_text(input)
+        );
+        assert_eq!(
+            "e\u{301}\u{fb01}",
+            Normalize::new(NormalizationForm::Nfd).new_from_text(input)
+        );
+        assert_eq!(
+            "\u{e9}fi",
+            Normalize::new(NormalizationForm::Nfkc).new_from_text(input)
+        );
+        assert_eq!(
+            "e\u{301}fi",
+            Normalize::new(NormalizationForm::Nfkd).new_from_text(input)
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use crate::TextConverter;
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeSet as HashSet, string::String, vec::Vec};
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+/// Reverses the order of the characters
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Reverse;
+
+impl TextConverter for Reverse {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        input.as_ref().chars().rev().collect()
+    }
+}
+
+/// Reverses the order of the words, joining them with single spaces
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReverseWords;
+
+impl TextConverter for ReverseWords {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        input
+            .as_ref()
+            .split_whitespace()
+            .rev()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Reverses the order of the lines, keeping a trailing line break in place
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReverseLines;
+
+impl TextConverter for ReverseLines {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let input = input.as_ref();
+        let mut output = input.lines().rev().collect::<Vec<_>>().join("\n");
+
+        if input.ends_with('\n') {
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+/// How [`SortLines`] compares lines
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortKey {
+    /// By Unicode code point, so uppercase letters come before lowercase ones
+    #[default]
+    Lexicographic,
+    /// Like [`Lexicographic`](Self::Lexicographic), but ignoring case
+    CaseInsensitive,
+    /// By the number at the start of the line, ignoring leading whitespace. Lines without one
+    /// come first
+    Numeric,
+}
+
+/// Sorts the lines, keeping a trailing line break in place
+///
+/// The sort is stable, so lines that compare equal keep their order
+///
+/// ```
+/// use text_converter::{
+///     converters::{SortKey, SortLines},
+///     TextConverter,
+/// };
+///
+/// assert_eq!("2 pears\n10 apples", SortLines::new(SortKey::Numeric).new_from_text("10 apples\n2 pears"));
+/// assert_eq!("10 apples\n2 pears", SortLines::default().new_from_text("2 pears\n10 apples"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SortLines {
+    /// How lines are compared
+    pub key: SortKey,
+    /// Sort from largest to smallest instead
+    pub reverse: bool,
+}
+
+impl SortLines {
+    /// Creates a converter sorting lines in ascending order by `key`
+    pub fn new(key: SortKey) -> Self {
+        Self {
+            key,
+            reverse: false,
+        }
+    }
+
+    fn compare(&self, a: &str, b: &str) -> Ordering {
+        match self.key {
+            SortKey::Lexicographic => a.cmp(b),
+            SortKey::CaseInsensitive => a.to_lowercase().cmp(&b.to_lowercase()),
+            SortKey::Numeric => leading_number(a)
+                .partial_cmp(&leading_number(b))
+                .unwrap_or(Ordering::Equal),
+        }
+    }
+}
+
+impl TextConverter for SortLines {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let input = input.as_ref();
+        let mut lines: Vec<_> = input.lines().collect();
+
+        lines.sort_by(|a, b| match self.reverse {
+            true => self.compare(b, a),
+            false => self.compare(a, b),
+        });
+
+        let mut output = lines.join("\n");
+        if input.ends_with('\n') {
+            output.push('\n');
+        }
+        output
+    }
+}
+
+/// The number a line starts with, with an optional sign and fraction
+fn leading_number(line: &str) -> Option<f64> {
+    let line = line.trim_start();
+    let sign = usize::from(line.starts_with(['-', '+']));
+    let digits = line[sign..]
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .map_or(line.len(), |end| end + sign);
+
+    line[..digits].parse().ok()
+}
+
+/// Which repeated lines [`DedupLines`] removes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DedupScope {
+    /// Lines that were already seen anywhere before
+    #[default]
+    Global,
+    /// Lines equal to the line right before them, like `uniq`
+    Adjacent,
+}
+
+/// Removes repeated lines, keeping the first occurrence and a trailing line break in place
+///
+/// ```
+/// use text_converter::{
+///     converters::{DedupLines, DedupScope},
+///     TextConverter,
+/// };
+///
+/// assert_eq!("a\nb", DedupLines::default().new_from_text("a\nb\na\nb"));
+/// assert_eq!("a\nb\na", DedupLines::new(DedupScope::Adjacent).new_from_text("a\na\nb\na"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DedupLines {
+    /// Which repeated lines are removed
+    pub scope: DedupScope,
+}
+
+impl DedupLines {
+    /// Creates a converter removing repeated lines in `scope`
+    pub fn new(scope: DedupScope) -> Self {
+        Self { scope }
+    }
+}
+
+impl TextConverter for DedupLines {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let input = input.as_ref();
+        let mut seen = HashSet::new();
+        let mut previous = None;
+
+        let lines: Vec<_> = input
+            .lines()
+            .filter(|&line| match self.scope {
+                DedupScope::Global => seen.insert(line),
+                DedupScope::Adjacent => previous.replace(line) != Some(line),
+            })
+            .collect();
+
+        let mut output = lines.join("\n");
+        if input.ends_with('\n') {
+            output.push('\n');
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reverse() {
+        assert_eq!("!dlroW olleH", Reverse.new_from_text("Hello World!"));
+        assert_eq!(
+            "world hello",
+            ReverseWords.new_from_text("  hello \t world ")
+        );
+    }
+
+    #[test]
+    fn reverse_lines() {
+        assert_eq!("c\nb\na\n", ReverseLines.new_from_text("a\nb\nc\n"));
+        assert_eq!("c\nb\na", ReverseLines.new_from_text("a\r\nb\nc"));
+    }
+
+    #[test]
+    fn sort_lines() {
+        let text = "b\n-1.5 x\nB\n10\na\n2\n";
+
+        assert_eq!(
+            "-1.5 x\n10\n2\nB\na\nb\n",
+            SortLines::default().new_from_text(text)
+        );
+        assert_eq!(
+            "b\nB\na\n2\n10\n-1.5 x\n",
+            SortLines {
+                key: SortKey::CaseInsensitive,
+                reverse: true
+            }
+            .new_from_text(text)
+        );
+        assert_eq!(
+            "b\nB\na\n-1.5 x\n2\n10\n",
+            SortLines::new(SortKey::Numeric).new_from_text(text)
+        );
+    }
+
+    #[test]
+    fn dedup_lines() {
+        assert_eq!(
+            "x\ny\n",
+            DedupLines::default().new_from_text("x\ny\nx\r\ny\n")
+        );
+        assert_eq!("", DedupLines::new(DedupScope::Adjacent).new_from_text(""));
+    }
+}
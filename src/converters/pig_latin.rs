@@ -0,0 +1,140 @@
+use crate::TextConverter;
+use alloc::string::String;
+
+/// Translates words to Pig Latin
+///
+/// Words starting with a vowel get `way` appended, other words have their leading consonants
+/// moved to the end followed by `ay`. `qu` moves as a whole, and `y` counts as a vowel unless it
+/// starts the word. Capitalized and all caps words stay that way, and punctuation around words is
+/// left where it is
+///
+/// ```
+/// use text_converter::{converters::PigLatin, TextConverter};
+///
+/// assert_eq!(
+///     "Ellohay, orldway! ON'TDAY itquay.",
+///     PigLatin.new_from_text("Hello, world! DON'T quit.")
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PigLatin;
+
+impl TextConverter for PigLatin {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let input = input.as_ref();
+        let mut output = String::with_capacity(input.len() + input.len() / 2);
+        let mut rest = input;
+
+        while let Some(start) = rest.find(char::is_alphabetic) {
+            output.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let end = word_len(rest);
+            translate_word(&rest[..end], &mut output);
+            rest = &rest[end..];
+        }
+
+        output.push_str(rest);
+        output
+    }
+}
+
+/// Length of the word at the start of `text`, which may contain apostrophes between letters
+fn word_len(text: &str) -> usize {
+    let mut end = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        let is_inner_apostrophe = matches!(c, '\'' | '’')
+            && end > 0
+            && chars.peek().is_some_and(|(_, next)| next.is_alphabetic());
+        if !c.is_alphabetic() && !is_inner_apostrophe {
+            break;
+        }
+        end = index + c.len_utf8();
+    }
+
+    end
+}
+
+fn translate_word(word: &str, output: &mut String) {
+    let lower = word.to_lowercase();
+    let split = consonant_cluster_len(&lower);
+
+    let mut translated = String::with_capacity(lower.len() + 3);
+    match split {
+        0 => {
+            translated.push_str(&lower);
+            translated.push_str("way");
+        }
+        _ => {
+            translated.push_str(&lower[split..]);
+            translated.push_str(&lower[..split]);
+            translated.push_str("ay");
+        }
+    }
+
+    let letters = word.chars().filter(|c| c.is_alphabetic()).count();
+    if letters > 1 && !word.chars().any(char::is_lowercase) {
+        output.push_str(&translated.to_uppercase());
+    } else if word.starts_with(char::is_uppercase) {
+        let mut chars = translated.chars();
+        if let Some(first) = chars.next() {
+            output.extend(first.to_uppercase());
+            output.push_str(chars.as_str());
+        }
+    } else {
+        output.push_str(&translated);
+    }
+}
+
+/// Length in bytes of the consonants that start a lowercase word
+fn consonant_cluster_len(word: &str) -> usize {
+    let mut chars = word.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        let is_vowel = matches!(c, 'a' | 'e' | 'i' | 'o' | 'u') || (c == 'y' && index > 0);
+        if is_vowel {
+            return match (c, word[..index].ends_with('q')) {
+                ('u', true) => index + 1,
+                _ => index,
+            };
+        }
+        if !c.is_alphabetic() {
+            return index;
+        }
+        // Words without vowels, like "nth", are moved as a whole
+        if chars.peek().is_none() {
+            return 0;
+        }
+    }
+
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consonant_clusters() {
+        assert_eq!("igpay", PigLatin.new_from_text("pig"));
+        assert_eq!("ingstray", PigLatin.new_from_text("string"));
+        assert_eq!("appleway", PigLatin.new_from_text("apple"));
+        assert_eq!("eenquay", PigLatin.new_from_text("queen"));
+        assert_eq!("aressquay", PigLatin.new_from_text("squares"));
+        assert_eq!("ellowyay", PigLatin.new_from_text("yellow"));
+        assert_eq!("ythmrhay", PigLatin.new_from_text("rhythm"));
+        assert_eq!("nthway", PigLatin.new_from_text("nth"));
+    }
+
+    #[test]
+    fn case_and_punctuation() {
+        assert_eq!(
+            "(Ellohay) ORLDWAY -- It'sway erehay.\n",
+            PigLatin.new_from_text("(Hello) WORLD -- It's here.\n")
+        );
+        assert_eq!("Away 42", PigLatin.new_from_text("A 42"));
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{ConversionError, TextConverter};
+use regex::{Regex, RegexBuilder};
+
+/// How a [`RegexReplace`] pattern matches
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct RegexOptions {
+    /// Letters match regardless of case, like the `i` flag
+    pub case_insensitive: bool,
+    /// `^` and `$` match at the start and end of every line, like the `m` flag
+    pub multi_line: bool,
+    /// Only the first match is replaced instead of all of them
+    pub first_only: bool,
+}
+
+/// Replaces text matching a regular expression
+///
+/// Enabled by the `regex` feature. The replacement can refer to capture groups with `$1` or
+/// `${name}`, and `$$` stands for a literal `$`. The pattern syntax is the one of the
+/// [`regex`] crate, which also accepts inline flags like `(?i)`
+///
+/// ```
+/// use text_converter::{converters::RegexReplace, TextConverter};
+///
+/// let dates = RegexReplace::new(r"(?<y>\d{4})-(?<m>\d{2})-(?<d>\d{2})", "$d/$m/${y}").unwrap();
+/// assert_eq!("Due 14/10/2026", dates.new_from_text("Due 2026-10-14"));
+/// ```
+///
+/// With the `serde` feature, it is stored as its pattern, replacement and options, and the
+/// pattern is compiled again when reading it back
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Config", into = "Config")
+)]
+pub struct RegexReplace {
+    regex: Regex,
+    replacement: String,
+    options: RegexOptions,
+}
+
+impl RegexReplace {
+    /// Replaces every match of `pattern` with `replacement`
+    ///
+    /// # Errors
+    /// [`ConversionError::InvalidInput`] if `pattern` is not a valid regular expression
+    pub fn new(pattern: &str, replacement: impl Into<String>) -> Result<Self, ConversionError> {
+        Self::with_options(pattern, replacement, RegexOptions::default())
+    }
+
+    /// Replaces matches of `pattern` with `replacement`, matching as `options` says
+    ///
+    /// # Errors
+    /// [`ConversionError::InvalidInput`] if `pattern` is not a valid regular expression
+    ///
+    /// # Examples
+    /// ```
+    /// use text_converter::{converters::{RegexOptions, RegexReplace}, TextConverter};
+    ///
+    /// let options = RegexOptions {
+    ///     case_insensitive: true,
+    ///     first_only: true,
+    ///     ..Default::default()
+    /// };
+    /// let replace = RegexReplace::with_options("cat", "dog", options).unwrap();
+    /// assert_eq!("dog, Cat, CAT", replace.new_from_text("Cat, Cat, CAT"));
+    /// ```
+    pub fn with_options(
+        pattern: &str,
+        replacement: impl Into<String>,
+        options: RegexOptions,
+    ) -> Result<Self, ConversionError> {
+        let regex = RegexBuilder::new(pattern)
+            .case_insensitive(options.case_insensitive)
+            .multi_line(options.multi_line)
+            .build()
+            .map_err(|e| {
+                ConversionError::InvalidInput(format!("Invalid regex {pattern:?}: {e}"))
+            })?;
+
+        Ok(Self {
+            regex,
+            replacement: replacement.into(),
+            options,
+        })
+    }
+
+    /// The pattern matches are searched with
+    pub fn pattern(&self) -> &str {
+        self.regex.as_str()
+    }
+
+    /// The text matches are replaced with
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+
+    /// How the pattern matches
+    pub fn options(&self) -> RegexOptions {
+        self.options
+    }
+}
+
+/// What a [`RegexReplace`] is stored as, since its compiled pattern cannot be
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Config {
+    pattern: String,
+    replacement: String,
+    #[serde(flatten)]
+    options: RegexOptions,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Config> for RegexReplace {
+    type Error = ConversionError;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        Self::with_options(&config.pattern, config.replacement, config.options)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<RegexReplace> for Config {
+    fn from(replace: RegexReplace) -> Self {
+        Self {
+            pattern: replace.regex.as_str().to_owned(),
+            replacement: replace.replacement,
+            options: replace.options,
+        }
+    }
+}
+
+impl TextConverter for RegexReplace {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let limit = match self.options.first_only {
+            true => 1,
+            false => 0,
+        };
+
+        self.regex
+            .replacen(input.as_ref(), limit, self.replacement.as_str())
+            .into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_and_groups() {
+        let multi_line = RegexOptions {
+            multi_line: true,
+            ..Default::default()
+        };
+        let quote = RegexReplace::with_options("^(.+)$", "> $1", multi_line).unwrap();
+        assert_eq!("> a\n> b", quote.new_from_text("a\nb"));
+
+        let price = RegexReplace::new(r"\$(\d+)", "$$$1.00").unwrap();
+        assert_eq!("$5.00 or $12.00", price.new_from_text("$5 or $12"));
+        assert_eq!(r"\$(\d+)", price.pattern());
+        assert_eq!("$$$1.00", price.replacement());
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    fn stored_as_pattern() {
+        let options = RegexOptions {
+            case_insensitive: true,
+            ..Default::default()
+        };
+        let replace = RegexReplace::with_options("cat", "dog", options).unwrap();
+        let json = serde_json::to_string(&replace).unwrap();
+        assert_eq!(
+            r#"{"pattern":"cat","replacement":"dog","case_insensitive":true,"multi_line":false,"first_only":false}"#,
+            json
+        );
+
+        let replace: RegexReplace = serde_json::from_str(&json).unwrap();
+        assert_eq!("dog and dog", replace.new_from_text("Cat and cat"));
+        assert!(
+            serde_json::from_str::<RegexReplace>(r#"{"pattern":"(","replacement":""}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        assert!(matches!(
+            RegexReplace::new("(unclosed", ""),
+            Err(ConversionError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{ConversionError, TextConverter};
+use rhai::{Engine, EvalAltResult, ParseError, Scope, AST};
+use std::{fs, path::Path};
+
+/// Converts text with a [Rhai](https://rhai.rs) script defining a `convert(input)` function
+///
+/// Enabled by the `rhai` feature. The function gets the input as a string and must return the
+/// output as a string. Scripts cannot access files or the network, only what the Rhai
+/// standard library offers. The default script returns the input unchanged
+///
+/// With the `serde` feature, it is stored as its source, and the script is compiled again when
+/// reading it back
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{converters::ScriptConverter, TextConverter};
+///
+/// let initials = ScriptConverter::new(
+///     r#"
+///     fn convert(input) {
+///         let initials = "";
+///         for word in input.split(" ") {
+///             initials += word[0].to_upper();
+///         }
+///         initials
+///     }
+///     "#,
+/// )
+/// .unwrap();
+/// assert_eq!("NASA", initials.new_from_text("national aeronautics space administration"));
+/// ```
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Config", into = "Config")
+)]
+pub struct ScriptConverter {
+    engine: Engine,
+    ast: AST,
+    source: String,
+}
+
+impl ScriptConverter {
+    /// Compiles `source`
+    ///
+    /// # Errors
+    /// - [`ConversionError::Syntax`] if it is not a valid script
+    /// - [`ConversionError::InvalidInput`] if it does not define a `convert` function taking
+    ///   one parameter
+    pub fn new(source: impl Into<String>) -> Result<Self, ConversionError> {
+        let source = source.into();
+        let engine = Engine::new();
+        let ast = engine.compile(&source).map_err(syntax_error)?;
+
+        if !ast
+            .iter_functions()
+            .any(|f| f.name == "convert" && f.params.len() == 1)
+        {
+            return Err(ConversionError::InvalidInput(
+                "the script does not define a `convert(input)` function".to_owned(),
+            ));
+        }
+
+        Ok(Self {
+            engine,
+            ast,
+            source,
+        })
+    }
+
+    /// Compiles the script in the file at `path`
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if the file is inaccessible
+    /// - Same as [new](Self::new()) otherwise
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConversionError> {
+        Self::new(fs::read_to_string(path)?)
+    }
+
+    /// Source of the script
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+impl Default for ScriptConverter {
+    fn default() -> Self {
+        Self::new("fn convert(input) { input }").expect("the default script is valid")
+    }
+}
+
+impl Clone for ScriptConverter {
+    fn clone(&self) -> Self {
+        Self {
+            engine: Engine::new(),
+            ast: self.ast.clone(),
+            source: self.source.clone(),
+        }
+    }
+}
+
+impl TextConverter for ScriptConverter {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Script failed to convert: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.engine
+            .call_fn::<String>(
+                &mut Scope::new(),
+                &self.ast,
+                "convert",
+                (input.as_ref().to_owned(),),
+            )
+            .map_err(|e: Box<EvalAltResult>| {
+                ConversionError::InvalidInput(format!("the script failed: {e}"))
+            })
+    }
+}
+
+/// What a [`ScriptConverter`] is stored as, since its compiled script cannot be
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Config {
+    source: String,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Config> for ScriptConverter {
+    type Error = ConversionError;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        Self::new(config.source)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ScriptConverter> for Config {
+    fn from(script: ScriptConverter) -> Self {
+        Self {
+            source: script.source,
+        }
+    }
+}
+
+fn syntax_error(e: ParseError) -> ConversionError {
+    let position = e.position();
+    ConversionError::Syntax {
+        format: "Rhai",
+        line: position.line().unwrap_or(1),
+        column: position.position().unwrap_or(1),
+        message: e.err_type().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_convert() {
+        let shout = ScriptConverter::new("fn convert(input) { input.to_upper() + \"!\" }").unwrap();
+        assert_eq!("HEY!", shout.new_from_text("hey"));
+        assert_eq!("same", ScriptConverter::default().new_from_text("same"));
+        assert_eq!("HEY!", shout.clone().new_from_text("hey"));
+
+        let not_text = ScriptConverter::new("fn convert(input) { 42 }").unwrap();
+        assert!(matches!(
+            not_text.try_new_from_text("hey"),
+            Err(ConversionError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn invalid_scripts() {
+        assert!(matches!(
+            ScriptConverter::new("fn convert(input) {\n  input +\n"),
+            Err(ConversionError::Syntax { format: "Rhai", .. })
+        ));
+        assert!(matches!(
+            ScriptConverter::new("fn transform(input) { input }"),
+            Err(ConversionError::InvalidInput(_))
+        ));
+    }
+}
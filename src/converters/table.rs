@@ -0,0 +1,389 @@
+use crate::{ConversionError, ReversibleConverter, TextConverter};
+use alloc::{borrow::ToOwned, format, string::String, string::ToString, vec::Vec};
+
+/// Whether the first CSV row holds the column names
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CsvHeader {
+    /// Treats the first row as a header when its cells are all filled in, different from each
+    /// other and not numbers
+    #[default]
+    Detect,
+    /// The first row is always the header
+    FirstRow,
+    /// There is no header, the columns are named `Column 1`, `Column 2` and so on
+    None,
+}
+
+/// Turns CSV, or other delimiter-separated values like TSV, into a Markdown table with aligned
+/// columns
+///
+/// Quoted fields may contain delimiters, line breaks and `""` for a quote. Columns that only
+/// hold numbers are right-aligned, `|` in cells is escaped and line breaks become `<br>`. The
+/// [converter](TextConverter::converter()) method panics on an unterminated quoted field, use
+/// the `try_*` methods to get an error instead. Decoding uses [`MarkdownToCsv`]
+///
+/// ```
+/// use text_converter::{converters::CsvToMarkdown, TextConverter};
+///
+/// let csv = "fruit,price\n\"apple, red\",1.5\nkiwi,12\n";
+/// assert_eq!(
+///     "| fruit      | price |\n| ---------- | ----: |\n| apple, red |   1.5 |\n| kiwi       |    12 |",
+///     CsvToMarkdown::default().new_from_text(csv)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct CsvToMarkdown {
+    /// Character separating fields, `,` by default
+    pub delimiter: char,
+    /// Whether the first row is the header
+    pub header: CsvHeader,
+}
+
+impl CsvToMarkdown {
+    /// Creates a converter for fields separated by `delimiter`, detecting the header
+    pub fn new(delimiter: char) -> Self {
+        Self {
+            delimiter,
+            header: CsvHeader::Detect,
+        }
+    }
+
+    /// Creates a converter for tab-separated values
+    pub fn tsv() -> Self {
+        Self::new('\t')
+    }
+
+    fn has_header(&self, rows: &[Vec<String>]) -> bool {
+        match self.header {
+            CsvHeader::FirstRow => true,
+            CsvHeader::None => false,
+            CsvHeader::Detect => rows.first().is_some_and(|first| {
+                first.iter().enumerate().all(|(i, cell)| {
+                    !cell.trim().is_empty() && !is_number(cell) && !first[..i].contains(cell)
+                })
+            }),
+        }
+    }
+}
+
+impl Default for CsvToMarkdown {
+    fn default() -> Self {
+        Self::new(',')
+    }
+}
+
+impl TextConverter for CsvToMarkdown {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Failed to convert CSV: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let mut rows = parse_csv(input.as_ref(), self.delimiter)?;
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
+            return Ok(String::new());
+        }
+        for row in &mut rows {
+            row.resize(columns, String::new());
+        }
+
+        let header = match self.has_header(&rows) {
+            true => rows.remove(0),
+            false => (1..=columns).map(|i| format!("Column {i}")).collect(),
+        };
+        let header: Vec<String> = header.iter().map(|cell| escape_cell(cell)).collect();
+        let rows: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| row.iter().map(|cell| escape_cell(cell)).collect())
+            .collect();
+
+        let numeric: Vec<bool> = (0..columns)
+            .map(|i| {
+                let mut cells = rows.iter().map(|row| &row[i]).filter(|c| !c.is_empty());
+                cells.clone().next().is_some() && cells.all(|cell| is_number(cell))
+            })
+            .collect();
+        let widths: Vec<usize> = (0..columns)
+            .map(|i| {
+                rows.iter()
+                    .chain([&header])
+                    .map(|row| row[i].chars().count())
+                    .max()
+                    .unwrap_or(0)
+                    .max(3)
+            })
+            .collect();
+
+        let mut lines = Vec::with_capacity(rows.len() + 2);
+        lines.push(table_row(&header, &widths, &[]));
+        lines.push(
+            (0..columns)
+                .map(|i| match numeric[i] {
+                    true => format!("{}:", "-".repeat(widths[i] - 1)),
+                    false => "-".repeat(widths[i]),
+                })
+                .fold(String::from("|"), |line, cell| {
+                    line + " " + cell.as_str() + " |"
+                }),
+        );
+        for row in &rows {
+            lines.push(table_row(row, &widths, &numeric));
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+impl ReversibleConverter for CsvToMarkdown {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        MarkdownToCsv::new(self.delimiter).converter(input)
+    }
+}
+
+/// Turns Markdown tables into CSV, or other delimiter-separated values like TSV
+///
+/// Lines that are not part of a table are left out, and so is the line separating the header
+/// from the body. Fields are quoted when needed
+///
+/// ```
+/// use text_converter::{converters::MarkdownToCsv, TextConverter};
+///
+/// let table = "| name | note |\n|---|---|\n| Ann | says \"hi\", \\| waves |";
+/// assert_eq!(
+///     "name,note\nAnn,\"says \"\"hi\"\", | waves\"",
+///     MarkdownToCsv::default().new_from_text(table)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct MarkdownToCsv {
+    /// Character separating fields, `,` by default
+    pub delimiter: char,
+}
+
+impl MarkdownToCsv {
+    /// Creates a converter separating fields with `delimiter`
+    pub fn new(delimiter: char) -> Self {
+        Self { delimiter }
+    }
+
+    /// Creates a converter for tab-separated values
+    pub fn tsv() -> Self {
+        Self::new('\t')
+    }
+
+    fn quote(&self, field: &str) -> String {
+        let needs_quotes = field.contains([self.delimiter, '"', '\n', '\r'])
+            || field.starts_with(' ')
+            || field.ends_with(' ');
+        match needs_quotes {
+            true => format!("\"{}\"", field.replace('"', "\"\"")),
+            false => field.to_owned(),
+        }
+    }
+}
+
+impl Default for MarkdownToCsv {
+    fn default() -> Self {
+        Self::new(',')
+    }
+}
+
+impl TextConverter for MarkdownToCsv {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let delimiter = self.delimiter.to_string();
+
+        input
+            .as_ref()
+            .lines()
+            .map(str::trim)
+            .filter(|line| line.starts_with('|') || line.ends_with('|'))
+            .map(split_table_row)
+            .filter(|cells| !is_separator_row(cells))
+            .map(|cells| {
+                cells
+                    .iter()
+                    .map(|cell| self.quote(&unescape_cell(cell)))
+                    .collect::<Vec<_>>()
+                    .join(&delimiter)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl ReversibleConverter for MarkdownToCsv {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        CsvToMarkdown::new(self.delimiter).converter(input)
+    }
+}
+
+/// Parses delimiter-separated values the way RFC 4180 describes them
+fn parse_csv(input: &str, delimiter: char) -> Result<Vec<Vec<String>>, ConversionError> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut chars = input.chars().peekable();
+    let (mut line, mut column) = (1, 0);
+    let mut quoted_at = None;
+
+    while let Some(c) = chars.next() {
+        column += 1;
+        match (quoted_at.is_some(), c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                column += 1;
+                field.push('"');
+            }
+            (true, '"') => quoted_at = None,
+            (true, '\n') => {
+                field.push(c);
+                line += 1;
+                column = 0;
+            }
+            (true, _) => field.push(c),
+            (false, '"') if field.is_empty() => quoted_at = Some((line, column)),
+            (false, _) if c == delimiter => row.push(core::mem::take(&mut field)),
+            (false, '\r') if chars.peek() == Some(&'\n') => {}
+            (false, '\n') => {
+                row.push(core::mem::take(&mut field));
+                rows.push(core::mem::take(&mut row));
+                line += 1;
+                column = 0;
+            }
+            (false, _) => field.push(c),
+        }
+    }
+
+    if let Some((line, column)) = quoted_at {
+        return Err(ConversionError::Syntax {
+            format: "CSV",
+            line,
+            column,
+            message: "quoted field is never closed".to_owned(),
+        });
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+fn is_number(cell: &str) -> bool {
+    let cell = cell.trim();
+    !cell.is_empty() && cell.parse::<f64>().is_ok()
+}
+
+fn escape_cell(cell: &str) -> String {
+    cell.trim()
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+fn unescape_cell(cell: &str) -> String {
+    cell.replace("\\|", "|").replace("<br>", "\n")
+}
+
+/// Formats a row of cells padded to the column widths, right-aligning numeric columns
+fn table_row(cells: &[String], widths: &[usize], numeric: &[bool]) -> String {
+    let mut line = String::from("|");
+    for (i, cell) in cells.iter().enumerate() {
+        let padding = " ".repeat(widths[i] - cell.chars().count());
+        match numeric.get(i) {
+            Some(true) => line.push_str(&format!(" {padding}{cell} |")),
+            _ => line.push_str(&format!(" {cell}{padding} |")),
+        }
+    }
+    line
+}
+
+/// Cells of a Markdown table row, split on the `|` that are not escaped
+fn split_table_row(line: &str) -> Vec<&str> {
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = match line.strip_suffix('|') {
+        Some(stripped) if !stripped.ends_with('\\') => stripped,
+        _ => line,
+    };
+
+    let mut cells = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '|' if !escaped => {
+                cells.push(line[start..i].trim());
+                start = i + 1;
+            }
+            _ => escaped = c == '\\' && !escaped,
+        }
+    }
+    cells.push(line[start..].trim());
+    cells
+}
+
+fn is_separator_row(cells: &[&str]) -> bool {
+    cells.iter().all(|cell| {
+        let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+        !dashes.is_empty() && dashes.bytes().all(|b| b == b'-')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quoted_fields() {
+        let rows = parse_csv("a,\"b,\"\"c\"\"\"\r\n\"multi\nline\",\n", ',').unwrap();
+        assert_eq!(vec![vec!["a", "b,\"c\""], vec!["multi\nline", ""]], rows);
+
+        assert!(matches!(
+            parse_csv("a,b\nc,\"oops\n", ','),
+            Err(ConversionError::Syntax {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn header_detection() {
+        let table = CsvToMarkdown::tsv().new_from_text("1\t2\n3\t40");
+        assert_eq!(
+            "| Column 1 | Column 2 |\n| -------: | -------: |\n|        1 |        2 |\n|        3 |       40 |",
+            table
+        );
+
+        let first_row = CsvToMarkdown {
+            delimiter: ';',
+            header: CsvHeader::FirstRow,
+        };
+        assert_eq!(
+            "| 1   | a\\|b   |\n| --- | ------ |\n| x   | y<br>z |",
+            first_row.new_from_text("1;a|b\nx;\"y\nz\"")
+        );
+        assert_eq!("", CsvToMarkdown::default().new_from_text(""));
+    }
+
+    #[test]
+    fn round_trip() {
+        let csv = "name,quote\nAnn,\"she said \"\"hi, there\"\"\"\nBob,\"a | b\nc\"";
+        let converter = CsvToMarkdown::default();
+
+        let table = converter.new_from_text(csv);
+        assert_eq!(csv, converter.decode_from_text(&table));
+        assert_eq!(
+            csv,
+            MarkdownToCsv::default().new_from_text(format!("Intro\n\n{table}\n\nOutro"))
+        );
+    }
+}
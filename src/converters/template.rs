@@ -0,0 +1,164 @@
+use crate::{ConversionError, TextConverter};
+use alloc::{borrow::ToOwned, collections::BTreeMap, format, string::String};
+
+/// Treats the input as a template and replaces `{name}` placeholders with values
+///
+/// `{{` and `}}` stand for literal braces. Placeholders without a value, and braces that are
+/// never closed, are left as they are unless the converter is strict. The
+/// [converter](TextConverter::converter()) method panics on those in strict mode, use the
+/// `try_*` methods to get a [`ConversionError::Syntax`] with the line and column instead
+///
+/// ```
+/// use text_converter::{converters::TemplateConverter, TextConverter};
+///
+/// let template = TemplateConverter::new([("name", "Ada"), ("lang", "Rust")]);
+/// assert_eq!(
+///     "Hi Ada, {{lang}} is Rust, {unknown}",
+///     template.new_from_text("Hi {name}, {{{{lang}}}} is {lang}, {unknown}")
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TemplateConverter {
+    /// Values of the placeholders, by name
+    pub values: BTreeMap<String, String>,
+    /// Whether placeholders without a value and unclosed braces are errors
+    pub strict: bool,
+}
+
+impl TemplateConverter {
+    /// Creates a lenient converter filling in `values`
+    pub fn new<K, V>(values: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        Self {
+            values: values
+                .into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+            strict: false,
+        }
+    }
+
+    /// Creates a strict converter filling in `values`
+    pub fn strict<K, V>(values: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        Self {
+            strict: true,
+            ..Self::new(values)
+        }
+    }
+
+    fn error(line: usize, column: usize, message: String) -> ConversionError {
+        ConversionError::Syntax {
+            format: "template",
+            line,
+            column,
+            message,
+        }
+    }
+}
+
+impl TextConverter for TemplateConverter {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Failed to expand template: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let mut output = String::with_capacity(input.as_ref().len());
+
+        for (index, line) in input.as_ref().split_inclusive('\n').enumerate() {
+            let mut rest = line;
+            while let Some(brace) = rest.find(['{', '}']) {
+                let column = line[..line.len() - rest.len() + brace].chars().count() + 1;
+                output.push_str(&rest[..brace]);
+                rest = &rest[brace..];
+
+                if let Some(after) = rest.strip_prefix("{{").or(rest.strip_prefix("}}")) {
+                    output.push_str(&rest[..1]);
+                    rest = after;
+                    continue;
+                }
+                if rest.starts_with('}') {
+                    output.push('}');
+                    rest = &rest[1..];
+                    continue;
+                }
+
+                let Some(end) = rest[1..].find(['{', '}', '\n']).map(|end| end + 1) else {
+                    if self.strict {
+                        return Err(Self::error(index + 1, column, "unclosed `{`".to_owned()));
+                    }
+                    break;
+                };
+                if !rest[end..].starts_with('}') {
+                    if self.strict {
+                        return Err(Self::error(index + 1, column, "unclosed `{`".to_owned()));
+                    }
+                    output.push_str(&rest[..end]);
+                    rest = &rest[end..];
+                    continue;
+                }
+
+                let name = &rest[1..end];
+                match self.values.get(name.trim()) {
+                    Some(value) => output.push_str(value),
+                    None if self.strict => {
+                        return Err(Self::error(
+                            index + 1,
+                            column,
+                            format!("unknown placeholder `{name}`"),
+                        ))
+                    }
+                    None => output.push_str(&rest[..=end]),
+                }
+                rest = &rest[end + 1..];
+            }
+            output.push_str(rest);
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lenient_keeps_unknown() {
+        let template = TemplateConverter::new([("a", "1")]);
+        assert_eq!(
+            "1 {b} { 1 } {oops\n{x {y}",
+            template.new_from_text("{a} {b} { {a} } {oops\n{x {y}")
+        );
+        assert_eq!("1 }", template.new_from_text("{ a } }"));
+    }
+
+    #[test]
+    fn strict_errors() {
+        let template = TemplateConverter::strict([("a", "1")]);
+        assert_eq!("{1}", template.new_from_text("{{{a}}}"));
+
+        let error = template.try_new_from_text("{a}\né {b}").unwrap_err();
+        assert_eq!(
+            "invalid template at line 2, column 3: unknown placeholder `b`",
+            error.to_string()
+        );
+        assert!(matches!(
+            template.try_new_from_text("{a} {a"),
+            Err(ConversionError::Syntax {
+                line: 1,
+                column: 5,
+                ..
+            })
+        ));
+    }
+}
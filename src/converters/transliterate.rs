@@ -0,0 +1,82 @@
+use super::Unmappable;
+use crate::{ConversionError, TextConverter};
+use deunicode::AsciiChars;
+
+/// Replaces accented and non-Latin characters with ASCII approximations
+///
+/// Enabled by the `deunicode` feature. Handy for making identifiers and file names. Chinese
+/// characters become their pinyin without tones, separated by spaces
+///
+/// ```
+/// use text_converter::{converters::Transliterate, TextConverter};
+///
+/// assert_eq!("Creme brulee, Strasse", Transliterate::default().new_from_text("Crème brûlée, Straße"));
+/// assert_eq!("Bei Jing", Transliterate::default().new_from_text("北京"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Transliterate {
+    /// What to do with characters that have no ASCII approximation, replaced with `?` by default
+    pub unmappable: Unmappable,
+}
+
+impl Transliterate {
+    /// Creates a converter handling characters without an approximation as given, or as a
+    /// [`ConversionMode`](crate::ConversionMode) says
+    pub fn new(unmappable: impl Into<Unmappable>) -> Self {
+        Self {
+            unmappable: unmappable.into(),
+        }
+    }
+}
+
+impl Default for Transliterate {
+    fn default() -> Self {
+        Self::new(Unmappable::Replace('?'))
+    }
+}
+
+impl TextConverter for Transliterate {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Failed to transliterate: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let input = input.as_ref();
+        let mut output = String::with_capacity(input.len());
+
+        for (c, ascii) in input.chars().zip(input.ascii_chars()) {
+            let ascii = ascii.map(str::to_owned);
+            if let Some(ascii) = self.unmappable.resolve(ascii, c.to_string(), "ASCII")? {
+                output.push_str(&ascii);
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unmappable_characters() {
+        let text = "a\u{e000}b";
+
+        assert_eq!("a?b", Transliterate::default().new_from_text(text));
+        assert_eq!(
+            "ab",
+            Transliterate::new(Unmappable::Skip).new_from_text(text)
+        );
+        assert_eq!(
+            text,
+            Transliterate::new(Unmappable::Passthrough).new_from_text(text)
+        );
+        assert!(Transliterate::new(Unmappable::Error)
+            .try_new_from_text(text)
+            .is_err());
+    }
+}
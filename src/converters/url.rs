@@ -0,0 +1,207 @@
+use crate::{ConversionError, ReversibleConverter, TextConverter};
+use alloc::{format, string::String, vec::Vec};
+
+/// Which characters [`UrlEncode`] leaves unencoded, depending on the part of the URL the text goes
+/// in. Letters, digits, `-`, `.`, `_` and `~` are never encoded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UrlEncodeSet {
+    /// A path, keeping `/` and the other characters allowed in path segments, like `:`, `@` and
+    /// `=`. Only `?`, `#`, spaces and characters that are always unsafe are encoded
+    Path,
+    /// A single key or value of a query string, encoding `&`, `=`, `+` and `#` so they don't end
+    /// it early, and spaces as `%20`
+    #[default]
+    Query,
+    /// A key or value of an HTML form (`application/x-www-form-urlencoded`), encoding everything
+    /// but letters, digits and `*-._`, and spaces as `+`
+    Form,
+}
+
+impl UrlEncodeSet {
+    fn keeps(self, byte: u8) -> bool {
+        let always = byte.is_ascii_alphanumeric() || b"-._~".contains(&byte);
+        match self {
+            Self::Path => always || b"/:@!$&'()*+,;=".contains(&byte),
+            Self::Query => always || b"/?:@!$'()*,;".contains(&byte),
+            Self::Form => (always && byte != b'~') || byte == b'*',
+        }
+    }
+}
+
+/// Percent-encodes the UTF-8 bytes of the text that aren't allowed in a part of a URL
+///
+/// ```
+/// use text_converter::{converters::{UrlEncode, UrlEncodeSet}, ReversibleConverter, TextConverter};
+///
+/// let query = UrlEncode::new(UrlEncodeSet::Query);
+/// assert_eq!("fish%20%26%20chips?", query.new_from_text("fish & chips?"));
+/// assert_eq!("fish & chips?", query.decode_from_text("fish%20%26%20chips?"));
+///
+/// let form = UrlEncode::new(UrlEncodeSet::Form);
+/// assert_eq!("fish+%26+chips%3F", form.new_from_text("fish & chips?"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct UrlEncode {
+    /// Which characters to leave unencoded
+    pub set: UrlEncodeSet,
+}
+
+impl UrlEncode {
+    /// Creates an encoder leaving the characters of `set` unencoded
+    pub fn new(set: UrlEncodeSet) -> Self {
+        Self { set }
+    }
+}
+
+impl TextConverter for UrlEncode {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        const HEX: &[u8; 16] = b"0123456789ABCDEF";
+        let mut output = String::with_capacity(input.as_ref().len() * 3);
+
+        for &byte in input.as_ref().as_bytes() {
+            if self.set.keeps(byte) {
+                output.push(char::from(byte));
+            } else if byte == b' ' && self.set == UrlEncodeSet::Form {
+                output.push('+');
+            } else {
+                output.push('%');
+                output.push(char::from(HEX[usize::from(byte >> 4)]));
+                output.push(char::from(HEX[usize::from(byte & 0xf)]));
+            }
+        }
+
+        output
+    }
+}
+
+impl ReversibleConverter for UrlEncode {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        UrlDecode::new(self.set).converter(input)
+    }
+
+    fn try_deconverter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        UrlDecode::new(self.set).try_converter(input)
+    }
+}
+
+/// Decodes percent-encoded text. `+` is decoded as a space for [`UrlEncodeSet::Form`], and kept
+/// as it is otherwise
+///
+/// The [converter](TextConverter::converter()) method panics on a `%` not followed by two
+/// hexadecimal digits, or if the decoded bytes aren't UTF-8, use the `try_*` methods to get an
+/// error instead
+///
+/// ```
+/// use text_converter::{converters::UrlDecode, TextConverter};
+///
+/// assert_eq!("café au lait", UrlDecode::default().new_from_text("caf%C3%A9%20au%20lait"));
+/// assert!(UrlDecode::default().try_new_from_text("100%").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct UrlDecode {
+    /// Which part of a URL the input comes from
+    pub set: UrlEncodeSet,
+}
+
+impl UrlDecode {
+    /// Creates a decoder for text encoded with `set`
+    pub fn new(set: UrlEncodeSet) -> Self {
+        Self { set }
+    }
+
+    fn decode(&self, input: &str) -> Result<Vec<u8>, ConversionError> {
+        let mut output = Vec::with_capacity(input.len());
+        let mut bytes = input.bytes().enumerate();
+
+        while let Some((i, byte)) = bytes.next() {
+            match byte {
+                b'%' => {
+                    let value = input
+                        .get(i + 1..i + 3)
+                        .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                        .ok_or_else(|| {
+                            ConversionError::InvalidInput(format!(
+                                "Malformed percent-encoded sequence at byte {i}"
+                            ))
+                        })?;
+                    output.push(value);
+                    bytes.nth(1);
+                }
+                b'+' if self.set == UrlEncodeSet::Form => output.push(b' '),
+                _ => output.push(byte),
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+impl TextConverter for UrlDecode {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Failed to decode URL: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let bytes = self.decode(input.as_ref())?;
+        String::from_utf8(bytes).map_err(|e| e.utf8_error().into())
+    }
+}
+
+impl ReversibleConverter for UrlDecode {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        UrlEncode::new(self.set).converter(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_sets() {
+        let text = "/a b/c?d=e&f+g#h~ü";
+
+        assert_eq!(
+            "/a%20b/c%3Fd=e&f+g%23h~%C3%BC",
+            UrlEncode::new(UrlEncodeSet::Path).new_from_text(text)
+        );
+        assert_eq!(
+            "/a%20b/c?d%3De%26f%2Bg%23h~%C3%BC",
+            UrlEncode::new(UrlEncodeSet::Query).new_from_text(text)
+        );
+        assert_eq!(
+            "%2Fa+b%2Fc%3Fd%3De%26f%2Bg%23h%7E%C3%BC",
+            UrlEncode::new(UrlEncodeSet::Form).new_from_text(text)
+        );
+
+        for set in [UrlEncodeSet::Path, UrlEncodeSet::Query, UrlEncodeSet::Form] {
+            let encoder = UrlEncode::new(set);
+            assert_eq!(text, encoder.decode_from_text(encoder.new_from_text(text)));
+        }
+    }
+
+    #[test]
+    fn malformed_input() {
+        let decoder = UrlDecode::default();
+
+        assert_eq!("a+b c", decoder.new_from_text("a+b%20c"));
+        assert_eq!(
+            "a b",
+            UrlDecode::new(UrlEncodeSet::Form).new_from_text("a+b")
+        );
+        assert!(decoder.try_new_from_text("%2").is_err());
+        assert!(decoder.try_new_from_text("%zz").is_err());
+        assert!(decoder.try_new_from_text("%+1").is_err());
+        assert!(matches!(
+            decoder.try_new_from_text("%FF"),
+            Err(ConversionError::Encoding(_))
+        ));
+    }
+}
@@ -0,0 +1,283 @@
+use super::ascii::{self, Run};
+use crate::{split_line_ending, ConversionError, TextConverter};
+use alloc::{borrow::Cow, borrow::ToOwned, string::String};
+
+/// Removes leading and trailing whitespace
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trim;
+
+impl TextConverter for Trim {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        input.as_ref().trim().to_owned()
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+        output.push_str(input.trim());
+    }
+
+    fn converter_cow<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        Cow::Borrowed(input.trim())
+    }
+
+    fn try_converter_cow<'a>(&self, input: &'a str) -> Result<Cow<'a, str>, ConversionError> {
+        Ok(self.converter_cow(input))
+    }
+}
+
+/// Removes leading and trailing whitespace from every line, keeping the line breaks
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrimLines;
+
+impl TextConverter for TrimLines {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+        self.converter_into(input.as_ref(), &mut output);
+        output
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+
+        for line in input.split_inclusive('\n') {
+            let (content, ending) = split_line_ending(line);
+            output.push_str(content.trim());
+            output.push_str(ending);
+        }
+    }
+}
+
+/// Replaces every run of whitespace, line breaks included, with a single space and trims the ends
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CollapseWhitespace;
+
+impl TextConverter for CollapseWhitespace {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+        self.converter_into(input.as_ref(), &mut output);
+        output
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+
+        // Whether there was whitespace since the last word written
+        let mut space = false;
+
+        for run in ascii::runs(input) {
+            let mut start = 0;
+            match run {
+                Run::Ascii(text) => {
+                    for (i, byte) in text.bytes().enumerate() {
+                        if matches!(byte, b'\t'..=b'\r' | b' ') {
+                            push_word(output, &text[start..i], &mut space);
+                            space = true;
+                            start = i + 1;
+                        }
+                    }
+                    push_word(output, &text[start..], &mut space);
+                }
+                Run::Other(text) => {
+                    for (i, c) in text.char_indices() {
+                        if c.is_whitespace() {
+                            push_word(output, &text[start..i], &mut space);
+                            space = true;
+                            start = i + c.len_utf8();
+                        }
+                    }
+                    push_word(output, &text[start..], &mut space);
+                }
+            }
+        }
+    }
+}
+
+/// Appends `word` to `output`, after a space if there was whitespace before it and it is not
+/// the first word
+fn push_word(output: &mut String, word: &str, space: &mut bool) {
+    if word.is_empty() {
+        return;
+    }
+    if *space && !output.is_empty() {
+        output.push(' ');
+    }
+    output.push_str(word);
+    *space = false;
+}
+
+/// Removes all whitespace, line breaks included
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveWhitespace;
+
+impl TextConverter for RemoveWhitespace {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        input
+            .as_ref()
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect()
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+        output.extend(input.chars().filter(|c| !c.is_whitespace()));
+    }
+}
+
+/// Removes lines that are empty or only contain whitespace
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveEmptyLines;
+
+impl TextConverter for RemoveEmptyLines {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        input
+            .as_ref()
+            .split_inclusive('\n')
+            .filter(|line| !split_line_ending(line).0.trim().is_empty())
+            .collect()
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+        output.extend(
+            input
+                .split_inclusive('\n')
+                .filter(|line| !split_line_ending(line).0.trim().is_empty()),
+        );
+    }
+}
+
+/// Tidies up the spacing within lines, keeping the line breaks
+///
+/// Every step can be turned off on its own. Only spaces and tabs count as whitespace, unless
+/// Unicode spaces like no-break spaces are normalized first
+///
+/// ```
+/// use text_converter::{converters::NormalizeWhitespace, TextConverter};
+///
+/// let normalize = NormalizeWhitespace {
+///     unicode_spaces: true,
+///     ..Default::default()
+/// };
+/// assert_eq!("a b c\nd", normalize.new_from_text(" a \t b\u{a0}\u{2009}c \n d\t"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct NormalizeWhitespace {
+    /// Replace runs of spaces and tabs with a single space, on by default
+    pub collapse: bool,
+    /// Remove spaces and tabs at the start and end of every line, on by default
+    pub trim_lines: bool,
+    /// Replace Unicode spaces, like no-break and thin spaces, with ASCII spaces, off by default
+    pub unicode_spaces: bool,
+}
+
+impl Default for NormalizeWhitespace {
+    fn default() -> Self {
+        Self {
+            collapse: true,
+            trim_lines: true,
+            unicode_spaces: false,
+        }
+    }
+}
+
+impl NormalizeWhitespace {
+    fn is_space(&self, c: char) -> bool {
+        c == ' ' || c == '\t' || (self.unicode_spaces && is_unicode_space(c))
+    }
+}
+
+impl TextConverter for NormalizeWhitespace {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let mut output = String::with_capacity(input.as_ref().len());
+        self.converter_into(input.as_ref(), &mut output);
+        output
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+
+        for line in input.split_inclusive('\n') {
+            let (mut content, ending) = split_line_ending(line);
+            if self.trim_lines {
+                content = content.trim_matches(|c| self.is_space(c));
+            }
+
+            let mut previous_space = false;
+            for c in content.chars() {
+                let space = self.is_space(c);
+                match (space, self.collapse) {
+                    (true, true) if previous_space => {}
+                    (true, true) => output.push(' '),
+                    (true, false) if self.unicode_spaces && is_unicode_space(c) => output.push(' '),
+                    _ => output.push(c),
+                }
+                previous_space = space;
+            }
+            output.push_str(ending);
+        }
+    }
+}
+
+/// Spaces other than the ASCII space and tab, the line breaking ones left out
+fn is_unicode_space(c: char) -> bool {
+    matches!(
+        c,
+        '\u{a0}' | '\u{1680}' | '\u{2000}'..='\u{200a}' | '\u{202f}' | '\u{205f}' | '\u{3000}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trim() {
+        assert_eq!("hello", Trim.new_from_text(" \thello\n"));
+        assert_eq!("a\nb\r\nc", TrimLines.new_from_text("  a \n\tb\r\n c "));
+    }
+
+    #[test]
+    fn collapse_and_remove() {
+        assert_eq!("a b c", CollapseWhitespace.new_from_text(" a  b\n\n\tc "));
+        let mixed = "\u{a0} café \u{2003}\x0b au\u{3000}lait\u{85}";
+        assert_eq!(
+            mixed.split_whitespace().collect::<Vec<_>>().join(" "),
+            CollapseWhitespace.new_from_text(mixed)
+        );
+        assert_eq!("abc", RemoveWhitespace.new_from_text(" a  b\n\n\tc "));
+    }
+
+    #[test]
+    fn normalize_toggles() {
+        let input = "\u{a0} a  b\t\r\n";
+        assert_eq!(
+            "\u{a0} a b\r\n",
+            NormalizeWhitespace::default().new_from_text(input)
+        );
+
+        let keep_runs = NormalizeWhitespace {
+            collapse: false,
+            unicode_spaces: true,
+            ..Default::default()
+        };
+        assert_eq!("a  b\r\n", keep_runs.new_from_text(input));
+
+        let only_unicode = NormalizeWhitespace {
+            collapse: false,
+            trim_lines: false,
+            unicode_spaces: true,
+        };
+        assert_eq!("  a  b\t\r\n", only_unicode.new_from_text(input));
+    }
+
+    #[test]
+    fn remove_empty_lines() {
+        assert_eq!(
+            "a\nb\r\nc",
+            RemoveEmptyLines.new_from_text("a\n\n  \nb\r\n\r\nc")
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{split_line_ending, TextConverter};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+/// Reflows paragraphs so no line is longer than a given width
+///
+/// Paragraphs are separated by blank lines, which are kept. The lines of a paragraph are joined
+/// and filled again, keeping the indentation of its first line. Widths count characters. Words
+/// longer than a line go on a line of their own unless `break_words` is set
+///
+/// ```
+/// use text_converter::{converters::WordWrap, TextConverter};
+///
+/// let wrap = WordWrap {
+///     hanging_indent: 2,
+///     ..WordWrap::new(12)
+/// };
+/// assert_eq!(
+///     "- one two\n  three four\n\nfive",
+///     wrap.new_from_text("- one\ntwo three four\n\nfive")
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct WordWrap {
+    /// Maximum number of characters on a line, 80 by default
+    pub width: usize,
+    /// Number of spaces before every line of a paragraph but the first
+    pub hanging_indent: usize,
+    /// Split words longer than a line instead of letting them stick out
+    pub break_words: bool,
+}
+
+impl WordWrap {
+    /// Creates a converter wrapping at `width` characters, without a hanging indent
+    pub fn new(width: usize) -> Self {
+        Self {
+            width,
+            hanging_indent: 0,
+            break_words: false,
+        }
+    }
+
+    fn fill(&self, paragraph: &[&str], lines: &mut Vec<String>) {
+        let first = paragraph[0];
+        let indent = &first[..first.len() - first.trim_start().len()];
+        let hanging = " ".repeat(self.hanging_indent);
+
+        let mut line = indent.to_owned();
+        let mut prefix_len = indent.chars().count();
+        let mut empty = true;
+        for word in paragraph.iter().flat_map(|line| line.split_whitespace()) {
+            let mut word = word;
+            loop {
+                let used = line.chars().count();
+                let needed = word.chars().count() + usize::from(!empty);
+                if used + needed <= self.width || (empty && !self.break_words) {
+                    if !empty {
+                        line.push(' ');
+                    }
+                    line.push_str(word);
+                    empty = false;
+                    break;
+                }
+
+                if self.break_words && empty {
+                    let room = self.width.saturating_sub(prefix_len).max(1);
+                    let split = word.char_indices().nth(room).map_or(word.len(), |(i, _)| i);
+                    line.push_str(&word[..split]);
+                    word = &word[split..];
+                    if word.is_empty() {
+                        empty = false;
+                        break;
+                    }
+                }
+                lines.push(core::mem::replace(&mut line, hanging.clone()));
+                prefix_len = self.hanging_indent;
+                empty = true;
+            }
+        }
+
+        lines.push(line);
+    }
+}
+
+impl Default for WordWrap {
+    fn default() -> Self {
+        Self::new(80)
+    }
+}
+
+impl TextConverter for WordWrap {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let input = input.as_ref();
+        let ending = match input.contains("\r\n") {
+            true => "\r\n",
+            false => "\n",
+        };
+
+        let mut lines = Vec::new();
+        let mut paragraph = Vec::new();
+        for line in input.split_inclusive('\n') {
+            let (content, _) = split_line_ending(line);
+            if content.trim().is_empty() {
+                if !paragraph.is_empty() {
+                    self.fill(&paragraph, &mut lines);
+                    paragraph.clear();
+                }
+                lines.push(String::new());
+            } else {
+                paragraph.push(content);
+            }
+        }
+        if !paragraph.is_empty() {
+            self.fill(&paragraph, &mut lines);
+        }
+
+        let mut output = lines.join(ending);
+        if input.ends_with('\n') {
+            output.push_str(ending);
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_words_whole() {
+        let text = "  The quick brown fox jumps over the lazy dog\r\n\r\n\r\nEnd\r\n";
+
+        assert_eq!(
+            "  The quick\r\nbrown fox\r\njumps over\r\nthe lazy dog\r\n\r\n\r\nEnd\r\n",
+            WordWrap::new(12).new_from_text(text)
+        );
+        assert_eq!(
+            "a\nextraordinary\nb",
+            WordWrap::new(5).new_from_text("a extraordinary b")
+        );
+    }
+
+    #[test]
+    fn breaks_long_words() {
+        let wrap = WordWrap {
+            width: 5,
+            hanging_indent: 1,
+            break_words: true,
+        };
+
+        assert_eq!(
+            "a\n extr\n aord\n inar\n y b",
+            wrap.new_from_text("a extraordinary b")
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    fn pipeline_round_trip() {
+        use crate::converters::{CaesarCipher, Trim};
+
+        let pipeline = (Trim, WordWrap::new(10), CaesarCipher { shift: 1 });
+        let json = serde_json::to_string(&pipeline).unwrap();
+        assert_eq!(
+            r#"[null,{"width":10,"hanging_indent":0,"break_words":false},{"shift":1}]"#,
+            json
+        );
+
+        let loaded: (Trim, WordWrap, CaesarCipher) = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            pipeline.new_from_text(" ab cd "),
+            loaded.new_from_text(" ab cd ")
+        );
+        let partial: WordWrap = serde_json::from_str(r#"{"width":20}"#).unwrap();
+        assert_eq!(WordWrap::new(20), partial);
+    }
+}
@@ -0,0 +1,188 @@
+use super::json;
+use crate::{ConversionError, ReversibleConverter, TextConverter};
+use serde_json::Value as Json;
+use serde_yaml::{Mapping, Number, Value as Yaml};
+
+/// Turns a YAML document into pretty-printed JSON
+///
+/// Enabled by the `serde_yaml` feature. Keys keep their order, keys that are numbers or booleans
+/// become strings. The [converter](TextConverter::converter()) method panics on invalid YAML, use
+/// the `try_*` methods to get a [`ConversionError::Syntax`] with the line and column instead.
+/// Decoding uses [`JsonToYaml`]
+///
+/// ```
+/// use text_converter::{converters::YamlToJson, TextConverter};
+///
+/// assert_eq!(
+///     "{\n  \"name\": \"tconv\",\n  \"tags\": [\n    \"cli\"\n  ]\n}",
+///     YamlToJson.new_from_text("name: tconv\ntags:\n  - cli\n")
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YamlToJson;
+
+impl TextConverter for YamlToJson {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Failed to convert YAML: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let yaml: Yaml = serde_yaml::from_str(input.as_ref()).map_err(syntax_error)?;
+        let json = serde_json::to_value(yaml)
+            .map_err(|e| ConversionError::InvalidInput(format!("cannot represent as JSON: {e}")))?;
+
+        Ok(serde_json::to_string_pretty(&json).expect("Serializing a JSON value cannot fail"))
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        Some("json")
+    }
+}
+
+impl ReversibleConverter for YamlToJson {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        JsonToYaml.converter(input)
+    }
+
+    fn try_deconverter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        JsonToYaml.try_converter(input)
+    }
+}
+
+/// Turns JSON into a YAML document
+///
+/// Enabled by the `serde_yaml` feature. Keys keep their order. Numbers too large for 64 bits
+/// are rounded to the nearest float. The [converter](TextConverter::converter()) method panics
+/// on invalid JSON, use the `try_*` methods to get a [`ConversionError::Syntax`] with the line
+/// and column instead. Decoding uses [`YamlToJson`]
+///
+/// ```
+/// use text_converter::{converters::JsonToYaml, TextConverter};
+///
+/// assert_eq!(
+///     "zebra: 1\napple:\n- true\n- null",
+///     JsonToYaml.new_from_text(r#"{"zebra": 1, "apple": [true, null]}"#)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JsonToYaml;
+
+impl TextConverter for JsonToYaml {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Failed to convert JSON: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let yaml = to_yaml(json::parse(input.as_ref())?);
+        let output = serde_yaml::to_string(&yaml).expect("Serializing a YAML value cannot fail");
+
+        Ok(output.trim_end_matches('\n').to_owned())
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        Some("yaml")
+    }
+}
+
+impl ReversibleConverter for JsonToYaml {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        YamlToJson.converter(input)
+    }
+
+    fn try_deconverter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        YamlToJson.try_converter(input)
+    }
+}
+
+/// Converts by hand, `serde_json` numbers only serialize to other formats without the
+/// `arbitrary_precision` feature
+fn to_yaml(json: Json) -> Yaml {
+    match json {
+        Json::Null => Yaml::Null,
+        Json::Bool(b) => Yaml::Bool(b),
+        Json::Number(n) => Yaml::Number(match (n.as_u64(), n.as_i64()) {
+            (Some(n), _) => Number::from(n),
+            (_, Some(n)) => Number::from(n),
+            _ => Number::from(n.as_f64().unwrap_or(f64::NAN)),
+        }),
+        Json::String(s) => Yaml::String(s),
+        Json::Array(items) => Yaml::Sequence(items.into_iter().map(to_yaml).collect()),
+        Json::Object(map) => Yaml::Mapping(
+            map.into_iter()
+                .map(|(key, value)| (Yaml::String(key), to_yaml(value)))
+                .collect::<Mapping>(),
+        ),
+    }
+}
+
+pub(crate) fn syntax_error(e: serde_yaml::Error) -> ConversionError {
+    let message = e.to_string();
+    let message = message
+        .split(" at line ")
+        .next()
+        .unwrap_or(&message)
+        .to_owned();
+
+    match e.location() {
+        Some(location) => ConversionError::Syntax {
+            format: "YAML",
+            line: location.line(),
+            column: location.column(),
+            message,
+        },
+        None => ConversionError::InvalidInput(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::converters::JsonMinify;
+
+    #[test]
+    fn round_trip_keeps_order() {
+        let yaml = "zebra: 1\napple:\n  nested: -2.5\n  list:\n  - a\n  - 3\nmid: ~\n";
+
+        let json = YamlToJson.new_from_text(yaml);
+        assert_eq!(
+            r#"{"zebra":1,"apple":{"nested":-2.5,"list":["a",3]},"mid":null}"#,
+            JsonMinify.new_from_text(&json)
+        );
+        assert_eq!(
+            "zebra: 1\napple:\n  nested: -2.5\n  list:\n  - a\n  - 3\nmid: null",
+            YamlToJson.decode_from_text(&json)
+        );
+        assert_eq!(
+            r#"{"1":"a","true":"b"}"#,
+            JsonMinify.new_from_text(YamlToJson.new_from_text("1: a\ntrue: b"))
+        );
+        assert_eq!(
+            "'true': yes",
+            JsonToYaml.new_from_text(r#"{"true": "yes"}"#)
+        );
+    }
+
+    #[test]
+    fn syntax_errors() {
+        let error = YamlToJson
+            .try_new_from_text("a: 1\nb: [1, 2\n")
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            ConversionError::Syntax {
+                format: "YAML",
+                line: 3,
+                ..
+            }
+        ));
+        assert!(matches!(
+            JsonToYaml.try_new_from_text("{\"a\": }"),
+            Err(ConversionError::Syntax { format: "JSON", .. })
+        ));
+        assert!(YamlToJson.try_new_from_text("[1, 2]: a").is_err());
+    }
+}
@@ -0,0 +1,171 @@
+use super::Rng;
+use crate::{ReversibleConverter, TextConverter};
+use alloc::string::String;
+
+/// How many combining marks [`Zalgo`] stacks on every character
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZalgoIntensity {
+    /// Up to 2 marks above and below
+    #[default]
+    Mild,
+    /// Up to 5 marks above and below, and one through the character
+    Moderate,
+    /// Up to 12 marks above and below, and 2 through the character
+    Max,
+}
+
+impl ZalgoIntensity {
+    /// Most marks added above, through and below a character
+    fn limits(self) -> (usize, usize, usize) {
+        match self {
+            Self::Mild => (2, 0, 2),
+            Self::Moderate => (5, 1, 5),
+            Self::Max => (12, 2, 12),
+        }
+    }
+}
+
+const ABOVE: &[char] = &[
+    '\u{0300}', '\u{0301}', '\u{0302}', '\u{0303}', '\u{0304}', '\u{0305}', '\u{0306}', '\u{0307}',
+    '\u{0308}', '\u{0309}', '\u{030A}', '\u{030B}', '\u{030C}', '\u{030D}', '\u{030E}', '\u{030F}',
+    '\u{0310}', '\u{0311}', '\u{0312}', '\u{0313}', '\u{0314}', '\u{033D}', '\u{033E}', '\u{033F}',
+    '\u{0340}', '\u{0341}', '\u{0342}', '\u{0343}', '\u{0344}', '\u{0346}', '\u{034A}', '\u{034B}',
+    '\u{034C}', '\u{0350}', '\u{0351}', '\u{0352}', '\u{0357}', '\u{035B}', '\u{0363}', '\u{0364}',
+    '\u{0365}', '\u{0366}', '\u{0367}', '\u{0368}', '\u{0369}', '\u{036A}', '\u{036B}', '\u{036C}',
+    '\u{036D}', '\u{036E}', '\u{036F}',
+];
+
+const THROUGH: &[char] = &['\u{0334}', '\u{0335}', '\u{0336}', '\u{0337}', '\u{0338}'];
+
+const BELOW: &[char] = &[
+    '\u{0316}', '\u{0317}', '\u{0318}', '\u{0319}', '\u{031C}', '\u{031D}', '\u{031E}', '\u{031F}',
+    '\u{0320}', '\u{0321}', '\u{0322}', '\u{0323}', '\u{0324}', '\u{0325}', '\u{0326}', '\u{0327}',
+    '\u{0328}', '\u{0329}', '\u{032A}', '\u{032B}', '\u{032C}', '\u{032D}', '\u{032E}', '\u{032F}',
+    '\u{0330}', '\u{0331}', '\u{0332}', '\u{0333}', '\u{0339}', '\u{033A}', '\u{033B}', '\u{033C}',
+    '\u{0345}', '\u{0347}', '\u{0348}', '\u{0349}', '\u{034D}', '\u{034E}', '\u{0353}', '\u{0354}',
+    '\u{0355}', '\u{0356}', '\u{0359}', '\u{035A}',
+];
+
+/// Stacks random combining diacritics on every character, for that "corrupted" look
+///
+/// Whitespace gets no marks, so lines and words stay where they were. [`ZalgoStrip`], also
+/// used to decode, removes the marks again
+///
+/// ```
+/// use text_converter::{converters::{Zalgo, ZalgoIntensity}, ReversibleConverter, TextConverter};
+///
+/// let zalgo = Zalgo::new(ZalgoIntensity::Max);
+/// let corrupted = zalgo.new_from_text("he comes");
+/// assert!(corrupted.chars().count() > "he comes".len());
+/// assert_eq!("he comes", zalgo.decode_from_text(corrupted));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Zalgo {
+    /// How many marks to stack
+    pub intensity: ZalgoIntensity,
+    /// Seed for picking the marks, to get the same output every time. Without one, every
+    /// conversion is different
+    pub seed: Option<u64>,
+}
+
+impl Zalgo {
+    /// Creates a converter with the given intensity, picking different marks on every conversion
+    pub fn new(intensity: ZalgoIntensity) -> Self {
+        Self {
+            intensity,
+            seed: None,
+        }
+    }
+}
+
+impl TextConverter for Zalgo {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let (above, through, below) = self.intensity.limits();
+        let mut rng = Rng::new(self.seed);
+        let mut output = String::with_capacity(input.as_ref().len() * 8);
+
+        for c in input.as_ref().chars() {
+            output.push(c);
+            if c.is_whitespace() || c.is_control() {
+                continue;
+            }
+
+            for (marks, limit) in [(ABOVE, above), (THROUGH, through), (BELOW, below)] {
+                let count = rng.below(limit + 1);
+                output.extend((0..count).map(|_| marks[rng.below(marks.len())]));
+            }
+        }
+
+        output
+    }
+}
+
+impl ReversibleConverter for Zalgo {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        ZalgoStrip.converter(input)
+    }
+}
+
+/// Removes combining diacritics, turning [`Zalgo`] text readable again
+///
+/// Accents written as separate combining characters, like `e` followed by U+0301, are removed
+/// too. Precomposed characters like `é` are kept
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZalgoStrip;
+
+impl TextConverter for ZalgoStrip {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        input
+            .as_ref()
+            .chars()
+            .filter(|c| !is_combining_mark(*c))
+            .collect()
+    }
+}
+
+fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stacks_marks() {
+        let text = "Zalgo\nis here";
+        let zalgo = Zalgo {
+            intensity: ZalgoIntensity::Moderate,
+            seed: Some(7),
+        };
+
+        let corrupted = zalgo.new_from_text(text);
+        assert_eq!(corrupted, zalgo.new_from_text(text));
+        assert!(corrupted.chars().any(is_combining_mark));
+        assert_eq!(
+            text.chars().filter(|c| c.is_whitespace()).count(),
+            corrupted.chars().filter(|c| c.is_whitespace()).count()
+        );
+        assert_eq!(text, ZalgoStrip.new_from_text(&corrupted));
+
+        let mild = Zalgo::new(ZalgoIntensity::Mild).new_from_text("a");
+        assert!(mild.chars().count() <= 5);
+    }
+
+    #[test]
+    fn strips_only_combining_marks() {
+        assert_eq!("cafe", ZalgoStrip.new_from_text("cafe\u{0301}\u{20D7}"));
+        assert_eq!("café ✓", ZalgoStrip.new_from_text("café ✓"));
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{ConversionError, TextConverter};
+use alloc::{format, string::String, vec, vec::Vec};
+
+/// Number of unchanged lines shown around every change
+const CONTEXT: usize = 3;
+
+/// The input and output of a conversion, with a unified diff between them to preview what the
+/// converter changed
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{converters::TrimLines, Conversion};
+///
+/// let conversion = Conversion::with_diff(&TrimLines, "keep\n  trim me  \nkeep\n").unwrap();
+/// assert_eq!("keep\ntrim me\nkeep\n", conversion.output);
+/// assert_eq!(
+///     "--- original\n+++ converted\n@@ -1,3 +1,3 @@\n keep\n-  trim me  \n+trim me\n keep\n",
+///     conversion.diff
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Conversion {
+    /// Text given to the converter
+    pub input: String,
+    /// Text the converter returned
+    pub output: String,
+    /// Unified diff from the input to the output, empty when nothing changed
+    pub diff: String,
+}
+
+impl Conversion {
+    /// Converts `input` with the [try_converter](TextConverter::try_converter()) method and
+    /// compares the output to it
+    ///
+    /// # Errors
+    /// Returns whatever error the converter reports
+    pub fn with_diff<C: TextConverter + ?Sized>(
+        converter: &C,
+        input: impl Into<String>,
+    ) -> Result<Self, ConversionError> {
+        let input = input.into();
+        let output = converter.try_converter(&input)?;
+
+        Ok(Self::new(input, output))
+    }
+
+    /// Compares an output to the input it was converted from
+    pub fn new(input: impl Into<String>, output: impl Into<String>) -> Self {
+        let (input, output) = (input.into(), output.into());
+        let diff = unified_diff(&input, &output);
+
+        Self {
+            input,
+            output,
+            diff,
+        }
+    }
+
+    /// Whether the output is the same as the input
+    pub fn is_unchanged(&self) -> bool {
+        self.input == self.output
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit<'a> {
+    Equal(&'a str),
+    Delete(&'a str),
+    Insert(&'a str),
+}
+
+/// Renders the changes between the lines of `old` and `new` in unified format, with `original`
+/// and `converted` as the file names
+fn unified_diff(old: &str, new: &str) -> String {
+    labelled_diff(old, new, "original", "converted")
+}
+
+/// Same as [unified_diff], with `old_name` and `new_name` as the file names
+pub(crate) fn labelled_diff(old: &str, new: &str, old_name: &str, new_name: &str) -> String {
+    if old == new {
+        return String::new();
+    }
+
+    let old_lines: Vec<_> = old.split_inclusive('\n').collect();
+    let new_lines: Vec<_> = new.split_inclusive('\n').collect();
+    let edits = edits(&old_lines, &new_lines);
+
+    // Line numbers in both texts before every edit, and one past the end
+    let mut positions = Vec::with_capacity(edits.len() + 1);
+    let (mut old_line, mut new_line) = (0, 0);
+    for edit in &edits {
+        positions.push((old_line, new_line));
+        match edit {
+            Edit::Equal(_) => (old_line, new_line) = (old_line + 1, new_line + 1),
+            Edit::Delete(_) => old_line += 1,
+            Edit::Insert(_) => new_line += 1,
+        }
+    }
+    positions.push((old_line, new_line));
+
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for (i, _) in edits
+        .iter()
+        .enumerate()
+        .filter(|(_, edit)| !matches!(edit, Edit::Equal(_)))
+    {
+        let (start, end) = (
+            i.saturating_sub(CONTEXT),
+            (i + 1 + CONTEXT).min(edits.len()),
+        );
+        match hunks.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut diff = format!("--- {old_name}\n+++ {new_name}\n");
+    for (start, end) in hunks {
+        let ((old_start, new_start), (old_end, new_end)) = (positions[start], positions[end]);
+        diff.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(old_start, old_end - old_start),
+            hunk_range(new_start, new_end - new_start)
+        ));
+
+        for edit in &edits[start..end] {
+            let (prefix, line) = match edit {
+                Edit::Equal(line) => (' ', line),
+                Edit::Delete(line) => ('-', line),
+                Edit::Insert(line) => ('+', line),
+            };
+            diff.push(prefix);
+            diff.push_str(line);
+            if !line.ends_with('\n') {
+                diff.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+    }
+
+    diff
+}
+
+/// Formats the start and length of a hunk the way `diff -u` does
+fn hunk_range(start: usize, len: usize) -> String {
+    match len {
+        0 => format!("{start},0"),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{len}", start + 1),
+    }
+}
+
+/// Shortest list of edits turning `old` into `new`, found with Myers' algorithm
+fn edits<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Edit<'a>> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (old_middle, new_middle) = (
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+    );
+
+    let mut edits: Vec<_> = old[..prefix].iter().map(|line| Edit::Equal(line)).collect();
+    edits.extend(middle_edits(old_middle, new_middle));
+    edits.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|line| Edit::Equal(line)),
+    );
+    edits
+}
+
+fn middle_edits<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Edit<'a>> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let offset = n + m;
+    let mut furthest = vec![0; 2 * offset as usize + 2];
+    let mut trace = Vec::new();
+
+    'search: for d in 0..=offset {
+        trace.push(furthest.clone());
+        for k in (-d..=d).step_by(2) {
+            let index = (k + offset) as usize;
+            let mut x = match k == -d || (k != d && furthest[index - 1] < furthest[index + 1]) {
+                true => furthest[index + 1],
+                false => furthest[index - 1] + 1,
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                (x, y) = (x + 1, y + 1);
+            }
+            furthest[index] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut edits = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, furthest) in trace.iter().enumerate().rev() {
+        let (d, k) = (d as isize, x - y);
+        let previous_k = match k == -d
+            || (k != d && furthest[(k - 1 + offset) as usize] < furthest[(k + 1 + offset) as usize])
+        {
+            true => k + 1,
+            false => k - 1,
+        };
+        let previous_x = furthest[(previous_k + offset) as usize];
+        let previous_y = previous_x - previous_k;
+
+        while x > previous_x && y > previous_y {
+            (x, y) = (x - 1, y - 1);
+            edits.push(Edit::Equal(old[x as usize]));
+        }
+        if d > 0 {
+            match x == previous_x {
+                true => edits.push(Edit::Insert(new[previous_y as usize])),
+                false => edits.push(Edit::Delete(old[previous_x as usize])),
+            }
+        }
+        (x, y) = (previous_x, previous_y);
+    }
+
+    edits.reverse();
+    edits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortest_edits() {
+        let old = ["a", "b", "c", "a", "b", "b", "a"];
+        let new = ["c", "b", "a", "b", "a", "c"];
+        let edits = edits(&old, &new);
+
+        let changes = edits
+            .iter()
+            .filter(|edit| !matches!(edit, Edit::Equal(_)))
+            .count();
+        assert_eq!(5, changes);
+        let rebuilt: Vec<_> = edits
+            .iter()
+            .filter_map(|edit| match edit {
+                Edit::Equal(line) | Edit::Insert(line) => Some(*line),
+                Edit::Delete(_) => None,
+            })
+            .collect();
+        assert_eq!(new.as_slice(), rebuilt);
+    }
+
+    #[test]
+    fn separate_hunks_and_missing_newline() {
+        let old: String = (1..=12).map(|i| format!("{i}\n")).collect();
+        let new = old.replacen("2\n", "two\n", 1).replace("12\n", "12");
+
+        assert_eq!(
+            "--- original\n+++ converted\n\
+             @@ -1,5 +1,5 @@\n 1\n-2\n+two\n 3\n 4\n 5\n\
+             @@ -9,4 +9,4 @@\n 9\n 10\n 11\n-12\n+12\n\\ No newline at end of file\n",
+            unified_diff(&old, &new)
+        );
+        assert_eq!("", Conversion::new("same", "same").diff);
+        assert_eq!(
+            "@@ -0,0 +1 @@",
+            unified_diff("", "new").lines().nth(2).unwrap()
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{ConversionError, TextConverter};
+use alloc::{borrow::Cow, boxed::Box, string::String};
+
+/// Object safe version of [`TextConverter`], for storing converters as trait objects
+///
+/// Implemented automatically for every [`TextConverter`], and trait objects of it implement
+/// [`TextConverter`] in turn, so `Box<dyn DynTextConverter>` has all the input sources
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{converters::*, DynTextConverter, TextConverter};
+///
+/// let stages: Vec<Box<dyn DynTextConverter>> = vec![Box::new(Trim), Box::new(Uppercase)];
+/// assert_eq!("HELLO", stages.new_from_text("  hello  "));
+/// ```
+pub trait DynTextConverter {
+    /// Transforms the input with [`TextConverter::converter`]
+    fn convert(&self, input: &str) -> String;
+
+    /// Transforms the input with [`TextConverter::try_converter`]
+    fn try_convert(&self, input: &str) -> Result<String, ConversionError>;
+
+    /// Extension of the output files, from [`TextConverter::output_extension`]
+    fn extension(&self) -> Option<&str>;
+
+    /// Transforms the input with [`TextConverter::converter_cow`]
+    fn convert_cow<'a>(&self, input: &'a str) -> Cow<'a, str>;
+
+    /// Transforms the input with [`TextConverter::try_converter_cow`]
+    fn try_convert_cow<'a>(&self, input: &'a str) -> Result<Cow<'a, str>, ConversionError>;
+
+    /// Transforms the input into `output` with [`TextConverter::converter_into`]
+    fn convert_into(&self, input: &str, output: &mut String);
+
+    /// Transforms `text` in place with [`TextConverter::converter_in_place`]
+    fn convert_in_place(&self, text: &mut str) -> bool;
+}
+
+impl<T: TextConverter> DynTextConverter for T {
+    fn convert(&self, input: &str) -> String {
+        self.converter(input)
+    }
+
+    fn try_convert(&self, input: &str) -> Result<String, ConversionError> {
+        self.try_converter(input)
+    }
+
+    fn extension(&self) -> Option<&str> {
+        self.output_extension()
+    }
+
+    fn convert_cow<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        self.converter_cow(input)
+    }
+
+    fn try_convert_cow<'a>(&self, input: &'a str) -> Result<Cow<'a, str>, ConversionError> {
+        self.try_converter_cow(input)
+    }
+
+    fn convert_into(&self, input: &str, output: &mut String) {
+        self.converter_into(input, output);
+    }
+
+    fn convert_in_place(&self, text: &mut str) -> bool {
+        self.converter_in_place(text)
+    }
+}
+
+macro_rules! impl_text_converter_for_dyn {
+    ($($ty:ty),+) => {
+        $(
+            impl<'a> TextConverter for $ty {
+                fn converter(&self, input: impl AsRef<str>) -> String {
+                    self.convert(input.as_ref())
+                }
+
+                fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+                    self.try_convert(input.as_ref())
+                }
+
+                fn output_extension(&self) -> Option<&str> {
+                    self.extension()
+                }
+
+                fn converter_cow<'b>(&self, input: &'b str) -> Cow<'b, str> {
+                    self.convert_cow(input)
+                }
+
+                fn try_converter_cow<'b>(&self, input: &'b str) -> Result<Cow<'b, str>, ConversionError> {
+                    self.try_convert_cow(input)
+                }
+
+                fn converter_into(&self, input: &str, output: &mut String) {
+                    self.convert_into(input, output);
+                }
+
+                fn converter_in_place(&self, text: &mut str) -> bool {
+                    self.convert_in_place(text)
+                }
+            }
+        )+
+    };
+}
+
+impl_text_converter_for_dyn!(
+    dyn DynTextConverter + 'a,
+    dyn DynTextConverter + Send + 'a,
+    dyn DynTextConverter + Send + Sync + 'a
+);
+
+impl<T: TextConverter + ?Sized> TextConverter for Box<T> {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        (**self).converter(input)
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        (**self).try_converter(input)
+    }
+
+    fn converter_cow<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        (**self).converter_cow(input)
+    }
+
+    fn try_converter_cow<'a>(&self, input: &'a str) -> Result<Cow<'a, str>, ConversionError> {
+        (**self).try_converter_cow(input)
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        (**self).output_extension()
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        (**self).converter_into(input, output);
+    }
+
+    fn converter_in_place(&self, text: &mut str) -> bool {
+        (**self).converter_in_place(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Exclaim;
+    struct Reverse;
+
+    impl TextConverter for Exclaim {
+        fn converter(&self, input: impl AsRef<str>) -> String {
+            input.as_ref().to_owned() + "!"
+        }
+    }
+
+    impl TextConverter for Reverse {
+        fn converter(&self, input: impl AsRef<str>) -> String {
+            input.as_ref().chars().rev().collect()
+        }
+    }
+
+    #[test]
+    fn trait_objects_convert() {
+        let converters: Vec<Box<dyn DynTextConverter>> = vec![Box::new(Exclaim), Box::new(Reverse)];
+        let outputs: Vec<_> = converters.iter().map(|c| c.new_from_text("abc")).collect();
+        assert_eq!(vec!["abc!", "cba"], outputs);
+
+        let shared: &(dyn DynTextConverter + Send + Sync) = &Reverse;
+        assert_eq!("cba", shared.try_new_from_text("abc").unwrap());
+    }
+}
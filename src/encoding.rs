@@ -0,0 +1,270 @@
+use crate::{bom, Bom, ConversionError, ConversionMode};
+use chardetng::{EncodingDetector, Iso2022JpDetection, Utf8Detection};
+use encoding_rs::{DecoderResult, EncoderResult, Encoding, UTF_16BE, UTF_16LE};
+
+/// How [try_new_from_file_with_encoding](crate::TextConverter::try_new_from_file_with_encoding())
+/// reads files that may not be UTF-8, and how it writes the output
+///
+/// Enabled by the `encoding` feature. By default the encoding is detected, the output is
+/// written as UTF-8, and input or output that is not valid in its encoding is an error
+///
+/// # Examples
+///
+/// ```no_run
+/// use text_converter::{converters::Uppercase, encoding_rs, EncodingOptions, OutputOptions};
+/// use text_converter::TextConverter;
+///
+/// // Writes notes_converted.txt in Shift_JIS, like notes.txt
+/// let encoding = EncodingOptions::new().encoding(encoding_rs::SHIFT_JIS).keep_encoding(true);
+/// Uppercase.new_from_file_with_encoding("notes.txt", &encoding, &OutputOptions::new());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EncodingOptions {
+    encoding: Option<&'static Encoding>,
+    keep_encoding: bool,
+    mode: ConversionMode,
+}
+
+impl EncodingOptions {
+    /// Detects the encoding of the input and writes the output as UTF-8
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the input as `encoding` instead of detecting it
+    pub fn encoding(mut self, encoding: &'static Encoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Whether to write the output in the encoding of the input, byte order mark included,
+    /// instead of UTF-8
+    pub fn keep_encoding(mut self, keep_encoding: bool) -> Self {
+        self.keep_encoding = keep_encoding;
+        self
+    }
+
+    /// How to handle bytes of the input that are not valid in its encoding, and characters of
+    /// the output that the encoding of the input cannot represent when keeping it. They are an
+    /// error in [`ConversionMode::Strict`], the default
+    pub fn mode(mut self, mode: ConversionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Decodes `bytes` into UTF-8, in the configured encoding or the detected one
+    pub(crate) fn decode(&self, bytes: &[u8]) -> Result<DecodedText, ConversionError> {
+        let encoding = self.encoding.unwrap_or_else(|| detect_encoding(bytes));
+        let bom = Bom::detect(bytes)
+            .filter(|_| Encoding::for_bom(bytes).is_some_and(|(bom, _)| bom == encoding));
+        let content = &bytes[bom.map_or(0, |bom| bom.as_bytes().len())..];
+
+        let text = match self.mode {
+            ConversionMode::Strict => encoding
+                .decode_without_bom_handling_and_without_replacement(content)
+                .ok_or_else(|| {
+                    ConversionError::InvalidInput(format!("input is not valid {}", encoding.name()))
+                })?
+                .into_owned(),
+            ConversionMode::Lossy => encoding.decode_without_bom_handling(content).0.into_owned(),
+            ConversionMode::Skip => decode_skipping(encoding, content),
+        };
+
+        Ok(DecodedText {
+            text,
+            encoding,
+            bom,
+        })
+    }
+
+    /// Encodes the output of a conversion of `input`
+    pub(crate) fn encode(
+        &self,
+        input: &DecodedText,
+        output: &str,
+    ) -> Result<Vec<u8>, ConversionError> {
+        if !self.keep_encoding {
+            return Ok(output.as_bytes().to_vec());
+        }
+
+        let encoding = input.encoding;
+        if let Some(bom) = input.bom {
+            return Ok(bom.encode(output));
+        }
+
+        // encoding_rs only decodes UTF-16, so it is encoded by hand
+        if encoding == UTF_16LE || encoding == UTF_16BE {
+            let mut bytes = Vec::new();
+            bom::encode_utf16(output, encoding == UTF_16LE, &mut bytes);
+            return Ok(bytes);
+        }
+
+        match self.mode {
+            ConversionMode::Strict => {
+                let (encoded, _, unmappable) = encoding.encode(output);
+                if unmappable {
+                    return Err(ConversionError::InvalidInput(format!(
+                        "output has characters that {} cannot represent",
+                        encoding.name()
+                    )));
+                }
+                Ok(encoded.into_owned())
+            }
+            ConversionMode::Lossy => Ok(encode_replacing(encoding, output, "?")),
+            ConversionMode::Skip => Ok(encode_replacing(encoding, output, "")),
+        }
+    }
+}
+
+/// Decodes `bytes`, leaving out the sequences that are not valid in `encoding`
+fn decode_skipping(encoding: &'static Encoding, mut bytes: &[u8]) -> String {
+    let mut decoder = encoding.new_decoder_without_bom_handling();
+    let mut text = String::new();
+
+    loop {
+        if let Some(needed) = decoder.max_utf8_buffer_length_without_replacement(bytes.len()) {
+            text.reserve(needed);
+        }
+        let (result, read) = decoder.decode_to_string_without_replacement(bytes, &mut text, true);
+        bytes = &bytes[read..];
+        match result {
+            DecoderResult::InputEmpty => return text,
+            DecoderResult::OutputFull | DecoderResult::Malformed(..) => {}
+        }
+    }
+}
+
+/// Encodes `text`, writing `replacement` in place of the characters `encoding` cannot represent
+fn encode_replacing(encoding: &'static Encoding, mut text: &str, replacement: &str) -> Vec<u8> {
+    let mut encoder = encoding.new_encoder();
+    let mut bytes = Vec::new();
+
+    loop {
+        reserve(&encoder, &mut bytes, text.len().max(replacement.len()));
+        let (result, read) =
+            encoder.encode_from_utf8_to_vec_without_replacement(text, &mut bytes, true);
+        text = &text[read..];
+        match result {
+            EncoderResult::InputEmpty => return bytes,
+            EncoderResult::OutputFull => {}
+            // Goes through the encoder too, which may have to switch back to ASCII first
+            EncoderResult::Unmappable(_) => {
+                reserve(&encoder, &mut bytes, replacement.len());
+                let _ = encoder.encode_from_utf8_to_vec_without_replacement(
+                    replacement,
+                    &mut bytes,
+                    false,
+                );
+            }
+        }
+    }
+}
+
+fn reserve(encoder: &encoding_rs::Encoder, bytes: &mut Vec<u8>, len: usize) {
+    if let Some(needed) = encoder.max_buffer_length_from_utf8_without_replacement(len) {
+        bytes.reserve(needed);
+    }
+}
+
+/// Text decoded from a file, with how it was encoded
+#[derive(Debug)]
+pub(crate) struct DecodedText {
+    pub(crate) text: String,
+    pub(crate) encoding: &'static Encoding,
+    pub(crate) bom: Option<Bom>,
+}
+
+/// Guesses the encoding of `bytes`, from its byte order mark if it has one
+///
+/// Enabled by the `encoding` feature. UTF-16 is only recognized by its byte order mark, and
+/// text that is valid UTF-8 is assumed to be UTF-8
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{detect_encoding, encoding_rs};
+///
+/// assert_eq!(encoding_rs::UTF_8, detect_encoding("déjà vu".as_bytes()));
+/// assert_eq!(encoding_rs::UTF_16LE, detect_encoding(b"\xff\xfeh\0i\0"));
+/// ```
+pub fn detect_encoding(bytes: &[u8]) -> &'static Encoding {
+    if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+        return encoding;
+    }
+
+    let mut detector = EncodingDetector::new(Iso2022JpDetection::Deny);
+    detector.feed(bytes, true);
+    detector.guess(None, Utf8Detection::Allow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use encoding_rs::{SHIFT_JIS, WINDOWS_1252};
+
+    #[test]
+    fn decodes_and_keeps_encodings() {
+        let latin1 = b"Caf\xe9 cr\xe8me br\xfbl\xe9e, s'il vous pla\xeet";
+        let keep = EncodingOptions::new().keep_encoding(true);
+        let decoded = keep.decode(latin1).unwrap();
+        assert_eq!(WINDOWS_1252, decoded.encoding);
+        assert_eq!("Café crème brûlée, s'il vous plaît", decoded.text);
+        assert_eq!(
+            latin1.to_vec(),
+            keep.encode(&decoded, &decoded.text).unwrap()
+        );
+
+        let utf16 = b"\xfe\xff\0h\0\xe9";
+        let decoded = keep.decode(utf16).unwrap();
+        assert_eq!(
+            (UTF_16BE, Some(Bom::Utf16Be)),
+            (decoded.encoding, decoded.bom)
+        );
+        assert_eq!("hé", decoded.text);
+        assert_eq!(utf16.to_vec(), keep.encode(&decoded, "hé").unwrap());
+        assert_eq!(
+            "hé".as_bytes(),
+            EncodingOptions::new().encode(&decoded, "hé").unwrap()
+        );
+    }
+
+    #[test]
+    fn explicit_encoding_errors() {
+        let shift_jis = EncodingOptions::new()
+            .encoding(SHIFT_JIS)
+            .keep_encoding(true);
+        let decoded = shift_jis.decode(b"\x93\xfa\x96\x7b").unwrap();
+        assert_eq!("日本", decoded.text);
+
+        assert!(matches!(
+            shift_jis.decode(b"\x93"),
+            Err(ConversionError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            shift_jis.encode(&decoded, "日本 🦀"),
+            Err(ConversionError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn lossy_and_skip_modes() {
+        let lossy = EncodingOptions::new()
+            .encoding(SHIFT_JIS)
+            .keep_encoding(true)
+            .mode(ConversionMode::Lossy);
+        let decoded = lossy.decode(b"\x93\xfa\x96\x7b\x93").unwrap();
+        assert_eq!("日本\u{fffd}", decoded.text);
+        assert_eq!(
+            b"\x93\xfa\x96\x7b ?!".to_vec(),
+            lossy.encode(&decoded, "日本 🦀!").unwrap()
+        );
+
+        let skip = lossy.mode(ConversionMode::Skip);
+        let decoded = skip.decode(b"\x93\xfa\xff\x96\x7b").unwrap();
+        assert_eq!("日本", decoded.text);
+        assert_eq!(
+            b"\x93\xfa\x96\x7b !".to_vec(),
+            skip.encode(&decoded, "日本 🦀!").unwrap()
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use alloc::string::String;
+use core::{error::Error, fmt, str::Utf8Error};
+#[cfg(feature = "std")]
+use std::io;
+
+/// Errors that can happen while fetching, converting or writing text
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ConversionError {
+    /// Reading from or writing to a file failed
+    #[cfg(feature = "std")]
+    Io(io::Error),
+    /// The clipboard could not be accessed, or it does not contain text. There is no system
+    /// clipboard on WebAssembly, so no such error either
+    #[cfg(all(feature = "clipboard", not(target_arch = "wasm32")))]
+    Clipboard(arboard::Error),
+    /// There is no system clipboard, like on CI or over SSH, and no fallback is set, see
+    /// [`TextClipboard`](crate::TextClipboard)
+    ClipboardUnavailable(String),
+    /// The input is not valid UTF-8
+    Encoding(Utf8Error),
+    /// The converter cannot handle this input, e.g. a decoder given text it did not encode
+    InvalidInput(String),
+    /// The input is not valid in the format the converter reads, like malformed JSON
+    Syntax {
+        /// Name of the format, like `JSON`
+        format: &'static str,
+        /// Line of the error, starting at 1
+        line: usize,
+        /// Column of the error in characters, starting at 1
+        column: usize,
+        /// What is wrong
+        message: String,
+    },
+    /// No converter is registered under this name
+    UnknownConverter(String),
+    /// The conversion was stopped through a [`CancellationToken`](crate::CancellationToken)
+    Cancelled,
+    /// Watching files for changes failed
+    #[cfg(feature = "notify")]
+    Watch(notify::Error),
+    /// Fetching text over HTTP failed, or the server answered with an error status
+    #[cfg(feature = "http")]
+    Http(ureq::Error),
+    /// Text could not be recognized in an image, or OCR is not available
+    #[cfg(feature = "ocr")]
+    Ocr(String),
+    /// Reading or writing a ZIP archive failed
+    #[cfg(feature = "zip")]
+    Archive(zip::result::ZipError),
+    /// A plugin could not be loaded, or one of its converters failed
+    #[cfg(any(feature = "plugins", feature = "wasm"))]
+    Plugin(String),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            Self::Io(e) => write!(f, "IO error: {e}"),
+            #[cfg(all(feature = "clipboard", not(target_arch = "wasm32")))]
+            Self::Clipboard(e) => write!(f, "clipboard error: {e}"),
+            Self::ClipboardUnavailable(reason) => write!(f, "clipboard unavailable: {reason}"),
+            Self::Encoding(e) => write!(f, "input is not valid UTF-8: {e}"),
+            Self::InvalidInput(reason) => write!(f, "invalid input: {reason}"),
+            Self::Syntax {
+                format,
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "invalid {format} at line {line}, column {column}: {message}"
+            ),
+            Self::UnknownConverter(name) => write!(f, "unknown converter `{name}`"),
+            Self::Cancelled => write!(f, "the conversion was cancelled"),
+            #[cfg(feature = "notify")]
+            Self::Watch(e) => write!(f, "watch error: {e}"),
+            #[cfg(feature = "http")]
+            Self::Http(e) => write!(f, "HTTP error: {e}"),
+            #[cfg(feature = "ocr")]
+            Self::Ocr(reason) => write!(f, "OCR error: {reason}"),
+            #[cfg(feature = "zip")]
+            Self::Archive(e) => write!(f, "archive error: {e}"),
+            #[cfg(any(feature = "plugins", feature = "wasm"))]
+            Self::Plugin(reason) => write!(f, "plugin error: {reason}"),
+        }
+    }
+}
+
+impl Error for ConversionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            Self::Io(e) => Some(e),
+            #[cfg(all(feature = "clipboard", not(target_arch = "wasm32")))]
+            Self::Clipboard(e) => Some(e),
+            Self::Encoding(e) => Some(e),
+            Self::ClipboardUnavailable(_)
+            | Self::InvalidInput(_)
+            | Self::Syntax { .. }
+            | Self::UnknownConverter(_)
+            | Self::Cancelled => None,
+            #[cfg(feature = "notify")]
+            Self::Watch(e) => Some(e),
+            #[cfg(feature = "http")]
+            Self::Http(e) => Some(e),
+            #[cfg(feature = "ocr")]
+            Self::Ocr(_) => None,
+            #[cfg(feature = "zip")]
+            Self::Archive(e) => Some(e),
+            #[cfg(any(feature = "plugins", feature = "wasm"))]
+            Self::Plugin(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for ConversionError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+#[cfg(all(feature = "clipboard", not(target_arch = "wasm32")))]
+impl From<arboard::Error> for ConversionError {
+    fn from(e: arboard::Error) -> Self {
+        Self::Clipboard(e)
+    }
+}
+
+impl From<Utf8Error> for ConversionError {
+    fn from(e: Utf8Error) -> Self {
+        Self::Encoding(e)
+    }
+}
+
+#[cfg(feature = "notify")]
+impl From<notify::Error> for ConversionError {
+    fn from(e: notify::Error) -> Self {
+        Self::Watch(e)
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<ureq::Error> for ConversionError {
+    fn from(e: ureq::Error) -> Self {
+        Self::Http(e)
+    }
+}
+
+#[cfg(feature = "zip")]
+impl From<zip::result::ZipError> for ConversionError {
+    fn from(e: zip::result::ZipError) -> Self {
+        Self::Archive(e)
+    }
+}
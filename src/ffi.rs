@@ -0,0 +1,242 @@
+//! C interface to the converters of a [`ConverterRegistry`]
+//!
+//! Enabled by the `capi` feature, so that editors and other native apps can link against this
+//! crate. The declarations are in `include/text_converter.h`, generated by
+//! [cbindgen](https://github.com/mozilla/cbindgen) from this module with `cbindgen --output
+//! include/text_converter.h`. Build a library to link against with
+//! `cargo rustc --release --lib --features capi --crate-type cdylib`, or `staticlib`
+//!
+//! Text always crosses the boundary as UTF-8 bytes with their length, and text the library
+//! hands back lives in a [`Buffer`] that must be given back to [`text_converter_buffer_free`]
+//!
+//! # Examples
+//!
+//! ```c
+//! #include <stdio.h>
+//! #include <string.h>
+//! #include "text_converter.h"
+//!
+//! int main(void) {
+//!     TextConverterRegistry *registry = text_converter_registry_new();
+//!     const char *input = "hello, world";
+//!     TextConverterBuffer output;
+//!
+//!     bool ok = text_converter_convert(registry, "title", (const uint8_t *)input,
+//!                                      strlen(input), &output);
+//!     printf("%s: %.*s\n", ok ? "converted" : "failed", (int)output.len, output.data);
+//!
+//!     text_converter_buffer_free(output);
+//!     text_converter_registry_free(registry);
+//! }
+//! ```
+
+use crate::{ConversionError, ConverterRegistry};
+use std::{
+    ffi::{c_char, CStr},
+    panic::{self, AssertUnwindSafe},
+    ptr, slice, str,
+};
+
+/// Bytes allocated by the library, to be freed with [`text_converter_buffer_free`]
+///
+/// They are UTF-8, and not NUL-terminated
+#[repr(C)]
+#[derive(Debug)]
+pub struct Buffer {
+    /// Start of the bytes
+    pub data: *mut u8,
+    /// Number of bytes
+    pub len: usize,
+}
+
+impl Buffer {
+    fn new(text: String) -> Self {
+        let bytes = Box::into_raw(text.into_bytes().into_boxed_slice());
+        Self {
+            data: bytes.cast(),
+            len: bytes.len(),
+        }
+    }
+}
+
+/// Creates a registry with all the built-in converters registered, see
+/// [`ConverterRegistry::with_builtins`]
+///
+/// It must be freed with [`text_converter_registry_free`]
+#[no_mangle]
+pub extern "C" fn text_converter_registry_new() -> *mut ConverterRegistry {
+    Box::into_raw(Box::new(ConverterRegistry::with_builtins()))
+}
+
+/// Frees a registry created by [`text_converter_registry_new`]. Does nothing if it is null
+///
+/// # Safety
+/// `registry` must come from [`text_converter_registry_new`], and not be used anymore
+#[no_mangle]
+pub unsafe extern "C" fn text_converter_registry_free(registry: *mut ConverterRegistry) {
+    if !registry.is_null() {
+        drop(Box::from_raw(registry));
+    }
+}
+
+/// Converts the UTF-8 `input` of `len` bytes with the converter registered under `name`
+///
+/// # Safety
+/// - `registry` must be a live registry from [`text_converter_registry_new`]
+/// - `name` must be a NUL-terminated string
+/// - `input` must point to `len` readable bytes, it may be null if `len` is 0
+/// - `output` must point to a [`Buffer`] that can be written
+///
+/// # Returns
+/// `true` with the converted text written to `output`. `false` with an error message written
+/// there instead if an argument is null or not UTF-8, no converter is registered under `name`,
+/// or the conversion failed. Either way, `output` must then be freed with
+/// [`text_converter_buffer_free`]
+#[no_mangle]
+pub unsafe extern "C" fn text_converter_convert(
+    registry: *const ConverterRegistry,
+    name: *const c_char,
+    input: *const u8,
+    len: usize,
+    output: *mut Buffer,
+) -> bool {
+    if output.is_null() {
+        return false;
+    }
+
+    let result = guard(|| {
+        let registry = registry.as_ref().ok_or_else(null_argument)?;
+        if name.is_null() || (input.is_null() && len > 0) {
+            return Err(null_argument());
+        }
+        let name = CStr::from_ptr(name).to_str()?;
+        let input = match len {
+            0 => "",
+            len => str::from_utf8(slice::from_raw_parts(input, len))?,
+        };
+        registry.convert(name, input)
+    });
+
+    write(output, result)
+}
+
+/// Writes the names of the converters in `registry` to `output`, one per line in alphabetical
+/// order
+///
+/// # Safety
+/// - `registry` must be a live registry from [`text_converter_registry_new`]
+/// - `output` must point to a [`Buffer`] that can be written
+///
+/// # Returns
+/// Same as [`text_converter_convert`]
+#[no_mangle]
+pub unsafe extern "C" fn text_converter_registry_names(
+    registry: *const ConverterRegistry,
+    output: *mut Buffer,
+) -> bool {
+    if output.is_null() {
+        return false;
+    }
+
+    let result = guard(|| {
+        let registry = registry.as_ref().ok_or_else(null_argument)?;
+        Ok(registry.names().collect::<Vec<_>>().join("\n"))
+    });
+
+    write(output, result)
+}
+
+/// Frees a buffer written by this library. Does nothing if its data is null
+///
+/// # Safety
+/// `buffer` must have been written by this library, and not be used anymore
+#[no_mangle]
+pub unsafe extern "C" fn text_converter_buffer_free(buffer: Buffer) {
+    if !buffer.data.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+            buffer.data,
+            buffer.len,
+        )));
+    }
+}
+
+/// Runs `f`, turning a panic into an error since it cannot unwind into C
+fn guard(f: impl FnOnce() -> Result<String, ConversionError>) -> Result<String, String> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result.map_err(|e| e.to_string()),
+        Err(_) => Err("the converter panicked".to_owned()),
+    }
+}
+
+unsafe fn write(output: *mut Buffer, result: Result<String, String>) -> bool {
+    let (text, ok) = match result {
+        Ok(text) => (text, true),
+        Err(message) => (message, false),
+    };
+    output.write(Buffer::new(text));
+    ok
+}
+
+fn null_argument() -> ConversionError {
+    ConversionError::InvalidInput("an argument is null".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty() -> Buffer {
+        Buffer {
+            data: ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    unsafe fn take(buffer: Buffer) -> String {
+        let text = str::from_utf8(slice::from_raw_parts(buffer.data, buffer.len))
+            .unwrap()
+            .to_owned();
+        text_converter_buffer_free(buffer);
+        text
+    }
+
+    unsafe fn convert(
+        registry: *const ConverterRegistry,
+        name: &CStr,
+        input: &[u8],
+    ) -> (bool, String) {
+        let mut output = empty();
+        let ok = text_converter_convert(
+            registry,
+            name.as_ptr(),
+            input.as_ptr(),
+            input.len(),
+            &mut output,
+        );
+        (ok, take(output))
+    }
+
+    #[test]
+    fn converts_through_c_interface() {
+        let registry = text_converter_registry_new();
+
+        unsafe {
+            assert_eq!(
+                (true, "Hello World".to_owned()),
+                convert(registry, c"title", b"hello world")
+            );
+            let (ok, message) = convert(registry, c"shout", b"hello world");
+            assert!(!ok && message.contains("shout"));
+            let (ok, message) = convert(registry, c"upper", b"\xff");
+            assert!(!ok && message.contains("UTF-8"));
+            let (ok, message) = convert(ptr::null(), c"upper", b"hi");
+            assert!(!ok && message.contains("null"));
+
+            let mut output = empty();
+            assert!(text_converter_registry_names(registry, &mut output));
+            assert!(take(output).lines().any(|name| name == "reverse-lines"));
+
+            text_converter_registry_free(registry);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Converters and directories shared by the unit tests
+
+use crate::TextConverter;
+use std::{
+    env, fs,
+    path::PathBuf,
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Uppercases the whole text, for tests that need any converter
+pub(crate) struct Uppercase;
+
+impl TextConverter for Uppercase {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        input.as_ref().to_uppercase()
+    }
+}
+
+/// Creates an empty directory for one test, named after `name` and unique to the process and
+/// the moment, so test runs going on at the same time never share it
+pub(crate) fn temp_dir(name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let dir = env::temp_dir().join(format!("text_converter_{name}_{}_{stamp}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
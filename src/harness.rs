@@ -0,0 +1,296 @@
+use crate::{
+    diff::labelled_diff, trace, write_atomic, ConversionError, ReversibleConverter, TextConverter,
+};
+use std::{
+    env, fmt, fs,
+    path::{Path, PathBuf},
+};
+
+/// Variable which, when set to anything but an empty string or `0`, makes every
+/// [`ConverterTestHarness`] regenerate its expected files instead of comparing to them
+pub const REGENERATE_VAR: &str = "TEXT_CONVERTER_REGENERATE";
+
+/// Extension of the fixture files given to the converter
+const INPUT: &str = "input";
+/// Extension of the fixture files holding what the converter should output
+const EXPECTED: &str = "expected";
+
+/// Runs a converter on every `NAME.input` file of a directory, and compares its output to the
+/// `NAME.expected` file next to it, so converter crates can keep their cases as golden files
+///
+/// Mismatches are reported with a unified diff from the expected output to the actual one.
+/// Regenerating, turned on by [regenerate](Self::regenerate()) or the [`REGENERATE_VAR`]
+/// variable, writes the actual outputs as the expected ones instead
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{converters::Uppercase, ConverterTestHarness};
+///
+/// let dir = std::env::temp_dir().join("text_converter_harness_doc");
+/// std::fs::create_dir_all(&dir).unwrap();
+/// std::fs::write(dir.join("greeting.input"), "hello").unwrap();
+/// std::fs::write(dir.join("greeting.expected"), "HELLO").unwrap();
+///
+/// let summary = ConverterTestHarness::new(Uppercase, &dir).run().unwrap();
+/// assert_eq!(vec!["greeting"], summary.passed);
+/// # std::fs::remove_dir_all(dir).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConverterTestHarness<C> {
+    converter: C,
+    dir: PathBuf,
+    regenerate: bool,
+}
+
+/// What happened to each case of a [`ConverterTestHarness`], by the name of its files without
+/// the extension
+///
+/// Displays the failures with their diffs, to panic with from a test
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FixtureSummary {
+    /// Cases whose output matched the expected one
+    pub passed: Vec<String>,
+    /// Cases whose expected file was written from the actual output
+    pub regenerated: Vec<String>,
+    /// Cases that failed, with a diff or the reason why
+    pub failed: Vec<(String, String)>,
+}
+
+impl<C: TextConverter> ConverterTestHarness<C> {
+    /// Checks `converter` against the fixtures in `dir`, regenerating them if [`REGENERATE_VAR`]
+    /// is set
+    pub fn new(converter: C, dir: impl Into<PathBuf>) -> Self {
+        let regenerate = env::var(REGENERATE_VAR).is_ok_and(|value| !matches!(&*value, "" | "0"));
+
+        Self {
+            converter,
+            dir: dir.into(),
+            regenerate,
+        }
+    }
+
+    /// Whether to write the actual outputs as the expected ones instead of comparing to them
+    pub fn regenerate(mut self, regenerate: bool) -> Self {
+        self.regenerate = regenerate;
+        self
+    }
+
+    /// Runs every case, in the order of their names
+    ///
+    /// A case fails if the converter returns an error, if its output differs from the expected
+    /// one, or if there is no expected file and regenerating is off
+    ///
+    /// # Errors
+    /// [`ConversionError::Io`] if the directory cannot be listed, or a fixture cannot be read or
+    /// written. [`ConversionError::Encoding`] if a fixture is not valid UTF-8
+    pub fn run(&self) -> Result<FixtureSummary, ConversionError> {
+        self.run_with(|_, _| Ok(()))
+    }
+
+    /// Runs every case like [run](Self::run()), and panics with the failures if there are any
+    ///
+    /// # Panics
+    /// If a case fails, or running them does
+    pub fn assert(&self) {
+        assert_passed(self.run());
+    }
+
+    fn run_with(
+        &self,
+        check: impl Fn(&str, &str) -> Result<(), String>,
+    ) -> Result<FixtureSummary, ConversionError> {
+        let mut names = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == INPUT) {
+                if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+                    names.push(name.to_owned());
+                }
+            }
+        }
+        names.sort();
+
+        let mut summary = FixtureSummary::default();
+        for name in names {
+            let input = read_fixture(&self.dir.join(format!("{name}.{INPUT}")))?;
+            let output = match self.converter.try_converter(&input) {
+                Ok(output) => output,
+                Err(e) => {
+                    summary
+                        .failed
+                        .push((name, format!("conversion failed: {e}")));
+                    continue;
+                }
+            };
+            if let Err(message) = check(&input, &output) {
+                summary.failed.push((name, message));
+                continue;
+            }
+
+            let expected_path = self.dir.join(format!("{name}.{EXPECTED}"));
+            if self.regenerate {
+                if read_fixture(&expected_path).ok().as_ref() != Some(&output) {
+                    write_atomic(&expected_path, output)?;
+                    summary.regenerated.push(name);
+                } else {
+                    summary.passed.push(name);
+                }
+                continue;
+            }
+            if !expected_path.exists() {
+                let message =
+                    format!("no `{name}.{EXPECTED}` file, set {REGENERATE_VAR}=1 to write it");
+                summary.failed.push((name, message));
+                continue;
+            }
+
+            let expected = read_fixture(&expected_path)?;
+            match expected == output {
+                true => summary.passed.push(name),
+                false => {
+                    let actual = format!("{name} (actual)");
+                    let diff =
+                        labelled_diff(&expected, &output, &format!("{name}.{EXPECTED}"), &actual);
+                    summary.failed.push((name, diff));
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+impl<C: ReversibleConverter> ConverterTestHarness<C> {
+    /// Runs every case like [run](Self::run()), also failing the ones whose output the
+    /// [try_deconverter](ReversibleConverter::try_deconverter()) method does not turn back into
+    /// their input
+    ///
+    /// # Errors
+    /// Same as [run](Self::run())
+    pub fn run_round_trip(&self) -> Result<FixtureSummary, ConversionError> {
+        self.run_with(
+            |input, output| match self.converter.try_deconverter(output) {
+                Ok(decoded) if decoded == input => Ok(()),
+                Ok(decoded) => Err(format!(
+                    "decoding the output did not give back the input\n{}",
+                    labelled_diff(input, &decoded, "input", "decoded")
+                )),
+                Err(e) => Err(format!("decoding the output failed: {e}")),
+            },
+        )
+    }
+
+    /// Runs every case like [run_round_trip](Self::run_round_trip()), and panics with the
+    /// failures if there are any
+    ///
+    /// # Panics
+    /// If a case fails, or running them does
+    pub fn assert_round_trip(&self) {
+        assert_passed(self.run_round_trip());
+    }
+}
+
+impl FixtureSummary {
+    /// Whether no case failed
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl fmt::Display for FixtureSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} passed, {} regenerated, {} failed",
+            self.passed.len(),
+            self.regenerated.len(),
+            self.failed.len()
+        )?;
+        for (name, message) in &self.failed {
+            write!(f, "\n\n`{name}` failed:\n{message}")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn read_fixture(path: &Path) -> Result<String, ConversionError> {
+    let bytes = trace::read_file(path)?;
+    String::from_utf8(bytes).map_err(|e| ConversionError::Encoding(e.utf8_error()))
+}
+
+fn assert_passed(summary: Result<FixtureSummary, ConversionError>) {
+    match summary {
+        Ok(summary) if summary.is_success() => {}
+        Ok(summary) => panic!("{summary}"),
+        Err(e) => panic!("Failed to run the fixtures: {e}"),
+    }
+}
+
+#[cfg(all(test, feature = "converters"))]
+mod tests {
+    use super::*;
+    use crate::converters::{Rot13, Uppercase};
+
+    fn fixtures(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for (file, contents) in files {
+            fs::write(dir.join(file), contents).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn compares_and_regenerates() {
+        let dir = fixtures(
+            "text_converter_harness",
+            &[
+                ("a.input", "one\ntwo\n"),
+                ("a.expected", "ONE\nTWO\n"),
+                ("b.input", "three\n"),
+                ("b.expected", "three\n"),
+                ("c.input", "four\n"),
+                ("notes.txt", "not a case"),
+            ],
+        );
+
+        let summary = ConverterTestHarness::new(Uppercase, &dir)
+            .regenerate(false)
+            .run()
+            .unwrap();
+        assert_eq!(vec!["a"], summary.passed);
+        assert_eq!(
+            "--- b.expected\n+++ b (actual)\n@@ -1 +1 @@\n-three\n+THREE\n",
+            summary.failed[0].1
+        );
+        assert_eq!("c", summary.failed[1].0);
+        assert!(summary
+            .to_string()
+            .starts_with("1 passed, 0 regenerated, 2 failed"));
+
+        let harness = ConverterTestHarness::new(Uppercase, &dir).regenerate(true);
+        let summary = harness.run().unwrap();
+        assert_eq!(vec!["a"], summary.passed);
+        assert_eq!(vec!["b", "c"], summary.regenerated);
+        assert_eq!(
+            "FOUR\n",
+            fs::read_to_string(dir.join("c.expected")).unwrap()
+        );
+        harness.regenerate(false).assert();
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn checks_round_trips() {
+        let dir = fixtures(
+            "text_converter_harness_round_trip",
+            &[("a.input", "Hello"), ("a.expected", "Uryyb")],
+        );
+
+        ConverterTestHarness::new(Rot13, &dir).assert_round_trip();
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,437 @@
+use crate::{
+    batch, write_atomic, Backup, BatchSummary, ConversionError, ConversionObserver, DirOptions,
+    TextConverter,
+};
+use std::{
+    any, fs,
+    fs::OpenOptions,
+    io::{ErrorKind, Write},
+    path::{self, Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Name of the journal file in the directory of a [`History`]
+const JOURNAL: &str = "journal";
+
+/// A file written by a conversion, as recorded in a [`History`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryEntry {
+    /// File that was converted
+    pub original: PathBuf,
+    /// File that was written, which is the original itself for in-place conversions
+    pub path: PathBuf,
+    /// Copy of what `path` held before the conversion, or `None` if it did not exist
+    pub backup: Option<PathBuf>,
+    /// Type name of the converter used, like `text_converter::converters::Trim`
+    pub converter: String,
+    /// When the file was written
+    pub timestamp: SystemTime,
+}
+
+/// Journal of the files written by conversions, which can restore them as they were before
+///
+/// A history lives in a directory holding the journal and copies of every file the conversions
+/// replaced, so it survives across runs. Paths are recorded as absolute paths, and must be
+/// valid UTF-8
+///
+/// # Examples
+///
+/// ```no_run
+/// use text_converter::{converters::Trim, DirOptions, History};
+///
+/// let mut history = History::open(".text_converter_history").unwrap();
+/// history.convert_file_in_place(&Trim, "notes.md").unwrap();
+/// history.convert_dir(&Trim, "drafts", &DirOptions::new()).unwrap();
+///
+/// // Puts notes.md back and removes the outputs written next to the drafts
+/// history.undo_all().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct History {
+    dir: PathBuf,
+    entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Opens the history kept in `dir`, creating it if it does not exist
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if `dir` or its journal cannot be read or created
+    /// - [`ConversionError::Syntax`] if the journal is corrupted
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self, ConversionError> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+
+        let entries = match fs::read_to_string(dir.join(JOURNAL)) {
+            Ok(journal) => journal
+                .lines()
+                .enumerate()
+                .map(|(index, line)| parse_entry(line, index + 1))
+                .collect::<Result<_, _>>()?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self { dir, entries })
+    }
+
+    /// Files written so far and not undone, from the oldest to the latest
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    /// Converts the file at `path` in place like
+    /// [try_convert_file_in_place](TextConverter::try_convert_file_in_place()) does, keeping
+    /// the original in the history instead of next to the file. Nothing is recorded if the file
+    /// is left unchanged
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if the original cannot be copied, or the journal written
+    /// - Same as [try_convert_file_in_place](TextConverter::try_convert_file_in_place())
+    ///   otherwise
+    pub fn convert_file_in_place<C: TextConverter + ?Sized>(
+        &mut self,
+        converter: &C,
+        path: impl AsRef<Path>,
+    ) -> Result<String, ConversionError> {
+        let path = path::absolute(path)?;
+        let backup = self.backup_path(&path)?;
+        fs::copy(&path, &backup)?;
+
+        let output = match converter.try_convert_file_in_place_with(&path, &Backup::None) {
+            Ok(output) => output,
+            Err(e) => {
+                let _ = fs::remove_file(&backup);
+                return Err(e);
+            }
+        };
+        if fs::read(&path)? == fs::read(&backup)? {
+            fs::remove_file(&backup)?;
+            return Ok(output);
+        }
+
+        self.record([entry::<C>(path.clone(), path, Some(backup))])?;
+        Ok(output)
+    }
+
+    /// Converts the files in `dir` like [new_from_dir](TextConverter::new_from_dir()) does,
+    /// recording every output written. Outputs that already existed are kept in the history
+    /// before being replaced. A [dry run](DirOptions::dry_run) records nothing
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if an existing output cannot be copied, or the journal written
+    /// - Same as [new_from_dir](TextConverter::new_from_dir()) otherwise. The outputs written
+    ///   before the batch stopped are still recorded
+    pub fn convert_dir<C: TextConverter + ?Sized>(
+        &mut self,
+        converter: &C,
+        dir: impl AsRef<Path>,
+        options: &DirOptions,
+    ) -> Result<BatchSummary, ConversionError> {
+        let dir = path::absolute(dir)?;
+        if options.is_dry_run() {
+            return converter.new_from_dir(&dir, options);
+        }
+
+        let mut planned = Vec::new();
+        for (original, output) in batch::planned_outputs(converter, &dir, options)? {
+            let backup = match output.exists() {
+                true => {
+                    let backup = self.backup_path(&output)?;
+                    fs::copy(&output, &backup)?;
+                    Some(backup)
+                }
+                false => None,
+            };
+            planned.push((original, output, backup));
+        }
+
+        let written = Arc::new(Written::default());
+        let summary = converter.new_from_dir(&dir, &options.clone().observe(Arc::clone(&written)));
+        let written = written.0.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut entries = Vec::new();
+        for (original, output, backup) in planned {
+            match written.contains(&output) {
+                true => entries.push(entry::<C>(original, output, backup)),
+                false => {
+                    if let Some(backup) = backup {
+                        fs::remove_file(backup)?;
+                    }
+                }
+            }
+        }
+        self.record(entries)?;
+
+        summary
+    }
+
+    /// Restores the file written last to what it was before, removing it if it did not exist
+    ///
+    /// # Errors
+    /// [`ConversionError::Io`] if the file or the journal cannot be written. The entry stays in
+    /// the history then
+    ///
+    /// # Returns
+    /// The entry that was undone, or `None` if the history is empty
+    pub fn undo_last(&mut self) -> Result<Option<HistoryEntry>, ConversionError> {
+        let Some(entry) = self.entries.last() else {
+            return Ok(None);
+        };
+
+        match &entry.backup {
+            Some(backup) => write_atomic(&entry.path, fs::read(backup)?)?,
+            None => match fs::remove_file(&entry.path) {
+                Err(e) if e.kind() != ErrorKind::NotFound => return Err(e.into()),
+                _ => {}
+            },
+        }
+
+        save(&self.dir, &self.entries[..self.entries.len() - 1])?;
+        let entry = self.entries.pop();
+        if let Some(backup) = entry.as_ref().and_then(|entry| entry.backup.as_ref()) {
+            let _ = fs::remove_file(backup);
+        }
+
+        Ok(entry)
+    }
+
+    /// Undoes every file written, from the latest to the oldest, see
+    /// [undo_last](Self::undo_last())
+    ///
+    /// # Errors
+    /// Same as [undo_last](Self::undo_last()). The files undone until then stay undone
+    ///
+    /// # Returns
+    /// The entries that were undone, from the latest to the oldest
+    pub fn undo_all(&mut self) -> Result<Vec<HistoryEntry>, ConversionError> {
+        let mut undone = Vec::new();
+        while let Some(entry) = self.undo_last()? {
+            undone.push(entry);
+        }
+
+        Ok(undone)
+    }
+
+    /// Path to copy `path` to, which is not taken by another backup
+    fn backup_path(&self, path: &Path) -> Result<PathBuf, ConversionError> {
+        let dir = self.dir.join("backups");
+        fs::create_dir_all(&dir)?;
+
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        Ok((0..)
+            .map(|n| dir.join(format!("{stamp}-{n}-{name}")))
+            .find(|backup| !backup.exists())
+            .expect("there are endless candidates"))
+    }
+
+    /// Appends `entries` to the journal
+    fn record(
+        &mut self,
+        entries: impl IntoIterator<Item = HistoryEntry>,
+    ) -> Result<(), ConversionError> {
+        let entries: Vec<_> = entries.into_iter().collect();
+        let mut lines = String::new();
+        for entry in &entries {
+            lines += &format_entry(entry)?;
+        }
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.dir.join(JOURNAL))?
+            .write_all(lines.as_bytes())?;
+        self.entries.extend(entries);
+
+        Ok(())
+    }
+}
+
+/// Writes the journal in `dir` again, with `entries`
+fn save(dir: &Path, entries: &[HistoryEntry]) -> Result<(), ConversionError> {
+    let mut journal = String::new();
+    for entry in entries {
+        journal += &format_entry(entry)?;
+    }
+
+    write_atomic(dir.join(JOURNAL), journal)
+}
+
+/// Collects the outputs of a directory conversion
+#[derive(Default)]
+struct Written(Mutex<Vec<PathBuf>>);
+
+impl ConversionObserver for Written {
+    fn on_output_written(&self, path: &Path) {
+        self.0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(path.to_owned());
+    }
+}
+
+fn entry<C: ?Sized>(original: PathBuf, path: PathBuf, backup: Option<PathBuf>) -> HistoryEntry {
+    HistoryEntry {
+        original,
+        path,
+        backup,
+        converter: any::type_name::<C>().to_owned(),
+        timestamp: SystemTime::now(),
+    }
+}
+
+/// Formats `entry` as a line of the journal: the timestamp, the converter, the original, the
+/// path written and the backup, separated by tabs
+fn format_entry(entry: &HistoryEntry) -> Result<String, ConversionError> {
+    let since_epoch = entry
+        .timestamp
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let backup = match &entry.backup {
+        Some(backup) => escape_path(backup)?,
+        None => String::new(),
+    };
+
+    Ok(format!(
+        "{}.{:09}\t{}\t{}\t{}\t{backup}\n",
+        since_epoch.as_secs(),
+        since_epoch.subsec_nanos(),
+        escape(&entry.converter),
+        escape_path(&entry.original)?,
+        escape_path(&entry.path)?,
+    ))
+}
+
+fn parse_entry(line: &str, number: usize) -> Result<HistoryEntry, ConversionError> {
+    let syntax_error = |message: &str| ConversionError::Syntax {
+        format: "history journal",
+        line: number,
+        column: 1,
+        message: message.to_owned(),
+    };
+
+    let fields: Vec<_> = line.split('\t').map(unescape).collect();
+    let [timestamp, converter, original, path, backup] = <[String; 5]>::try_from(fields)
+        .map_err(|_| syntax_error("expected 5 fields separated by tabs"))?;
+
+    let (secs, nanos) = timestamp
+        .split_once('.')
+        .and_then(|(secs, nanos)| Some((secs.parse().ok()?, nanos.parse().ok()?)))
+        .ok_or_else(|| syntax_error("invalid timestamp"))?;
+
+    Ok(HistoryEntry {
+        original: original.into(),
+        path: path.into(),
+        backup: (!backup.is_empty()).then(|| backup.into()),
+        converter,
+        timestamp: UNIX_EPOCH + Duration::new(secs, nanos),
+    })
+}
+
+fn escape_path(path: &Path) -> Result<String, ConversionError> {
+    let path = path.to_str().ok_or_else(|| {
+        ConversionError::InvalidInput(format!(
+            "{} is not valid UTF-8, so it cannot be recorded",
+            path.display()
+        ))
+    })?;
+
+    Ok(escape(path))
+}
+
+fn escape(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn unescape(field: &str) -> String {
+    let mut unescaped = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('t') => unescaped.push('\t'),
+                Some('n') => unescaped.push('\n'),
+                Some(other) => unescaped.push(other),
+                None => unescaped.push('\\'),
+            },
+            c => unescaped.push(c),
+        }
+    }
+
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, Uppercase};
+
+    #[test]
+    fn undoes_in_place_conversions() {
+        let dir = fixtures::temp_dir("history");
+        let file = dir.join("notes\twith tab.txt");
+        fs::write(&file, "hello").unwrap();
+
+        let mut history = History::open(dir.join("history")).unwrap();
+        assert_eq!(
+            "HELLO",
+            history.convert_file_in_place(&Uppercase, &file).unwrap()
+        );
+        assert_eq!(
+            "HELLO",
+            history.convert_file_in_place(&Uppercase, &file).unwrap()
+        );
+        assert_eq!(
+            1,
+            history.entries().len(),
+            "unchanged files are not recorded"
+        );
+        assert!(history.entries()[0].converter.ends_with("Uppercase"));
+
+        let mut reopened = History::open(dir.join("history")).unwrap();
+        assert_eq!(history.entries(), reopened.entries());
+        let undone = reopened.undo_last().unwrap().unwrap();
+        assert_eq!(file, undone.path);
+        assert_eq!("hello", fs::read_to_string(&file).unwrap());
+        assert!(!undone.backup.unwrap().exists());
+        assert_eq!(None, reopened.undo_last().unwrap());
+        assert!(History::open(dir.join("history"))
+            .unwrap()
+            .entries()
+            .is_empty());
+    }
+
+    #[test]
+    fn undoes_directory_conversions() {
+        let dir = fixtures::temp_dir("history_dir");
+        let input = dir.join("input");
+        fs::create_dir_all(&input).unwrap();
+        fs::write(input.join("a.txt"), "a").unwrap();
+        fs::write(input.join("b.txt"), "b").unwrap();
+        fs::write(input.join("b_converted.txt"), "previous").unwrap();
+
+        let mut history = History::open(dir.join("history")).unwrap();
+        let options = DirOptions::new().extensions(["txt"]);
+        history.convert_dir(&Uppercase, &input, &options).unwrap();
+        assert_eq!(2, history.entries().len());
+        assert_eq!(
+            "B",
+            fs::read_to_string(input.join("b_converted.txt")).unwrap()
+        );
+
+        assert_eq!(2, history.undo_all().unwrap().len());
+        assert!(!input.join("a_converted.txt").exists());
+        assert_eq!(
+            "previous",
+            fs::read_to_string(input.join("b_converted.txt")).unwrap()
+        );
+    }
+}
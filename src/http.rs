@@ -0,0 +1,106 @@
+//! Fetching text over HTTP(S)
+//!
+//! Enabled by the `http` feature
+
+use crate::{Bom, ConversionError};
+
+/// Fetches the text at `url`, decoded from the charset of its `Content-Type`
+///
+/// Enabled by the `http` feature. Other charsets than UTF-8 need the `encoding` feature
+///
+/// # Errors
+/// - [`ConversionError::Http`] if the request fails or the server answers with an error status
+/// - [`ConversionError::InvalidInput`] if the content type is not text, like an image, or if the
+///   charset is not supported
+/// - [`ConversionError::Encoding`] if the body is not valid UTF-8
+pub fn fetch_url(url: &str) -> Result<String, ConversionError> {
+    let mut response = ureq::get(url).call()?;
+    let body = response.body_mut();
+    let (mime_type, charset) = (
+        body.mime_type().map(str::to_owned),
+        body.charset().map(str::to_owned),
+    );
+
+    decode(
+        &body.read_to_vec()?,
+        mime_type.as_deref(),
+        charset.as_deref(),
+    )
+}
+
+/// Decodes a body, refusing content types that aren't text
+fn decode(
+    bytes: &[u8],
+    mime_type: Option<&str>,
+    charset: Option<&str>,
+) -> Result<String, ConversionError> {
+    if let Some(mime_type) = mime_type.filter(|mime_type| !is_text(mime_type)) {
+        return Err(ConversionError::InvalidInput(format!(
+            "the response is {mime_type}, not text"
+        )));
+    }
+
+    match charset.map(str::to_ascii_lowercase).as_deref() {
+        None | Some("utf-8" | "utf8" | "us-ascii") => Ok(Bom::decode(bytes)?.0.into_owned()),
+        #[cfg(feature = "encoding")]
+        Some(label) => {
+            let encoding = encoding_rs::Encoding::for_label(label.as_bytes()).ok_or_else(|| {
+                ConversionError::InvalidInput(format!("unknown charset `{label}`"))
+            })?;
+            let options = crate::EncodingOptions::new().encoding(encoding);
+            Ok(options.decode(bytes)?.text)
+        }
+        #[cfg(not(feature = "encoding"))]
+        Some(label) => Err(ConversionError::InvalidInput(format!(
+            "the response is in {label}, which needs the `encoding` feature"
+        ))),
+    }
+}
+
+fn is_text(mime_type: &str) -> bool {
+    let mime_type = mime_type.to_ascii_lowercase();
+    mime_type.starts_with("text/")
+        || mime_type.ends_with("+json")
+        || mime_type.ends_with("+xml")
+        || matches!(
+            mime_type.as_str(),
+            "application/json"
+                | "application/xml"
+                | "application/javascript"
+                | "application/x-yaml"
+                | "application/yaml"
+                | "application/toml"
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_text_bodies() {
+        assert_eq!(
+            "plain",
+            decode(b"plain", Some("text/plain"), Some("UTF-8")).unwrap()
+        );
+        assert_eq!(
+            "{}",
+            decode(b"\xef\xbb\xbf{}", Some("application/json"), None).unwrap()
+        );
+        assert_eq!("no type", decode(b"no type", None, None).unwrap());
+
+        assert!(matches!(
+            decode(b"\x89PNG", Some("image/png"), None),
+            Err(ConversionError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            decode(b"\xff", Some("text/plain"), None),
+            Err(ConversionError::Encoding(_))
+        ));
+        #[cfg(feature = "encoding")]
+        assert_eq!(
+            "café",
+            decode(b"caf\xe9", Some("text/html"), Some("ISO-8859-1")).unwrap()
+        );
+    }
+}
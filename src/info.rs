@@ -0,0 +1,101 @@
+use alloc::{string::String, vec::Vec};
+
+/// What a converter of a [`ConverterRegistry`](crate::ConverterRegistry) does, so command line
+/// and terminal interfaces can list and document the converters they offer
+///
+/// The name and whether the converter is configurable are filled in by the registry, the rest is
+/// given to [describe](crate::ConverterRegistry::describe())
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{converters::Uppercase, ConverterInfo, ConverterRegistry};
+///
+/// let mut registry = ConverterRegistry::new();
+/// registry
+///     .register("shout", Uppercase)
+///     .describe("shout", ConverterInfo::new("Make everything loud").category("case"));
+///
+/// let info = registry.info("shout").unwrap();
+/// assert_eq!(("shout", "Make everything loud"), (info.name(), info.description()));
+/// assert!(info.in_category("case") && !info.is_reversible());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ConverterInfo {
+    name: String,
+    description: String,
+    categories: Vec<String>,
+    reversible: bool,
+    configurable: bool,
+}
+
+impl ConverterInfo {
+    /// Describes a converter in one line, like `Convert every character to uppercase`
+    pub fn new(description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Tags the converter with a category, like `case` or `encoding`, to group it with similar
+    /// ones
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.categories.push(category.into());
+        self
+    }
+
+    /// Whether the converter has an inverse, as is the case for
+    /// [`ReversibleConverter`](crate::ReversibleConverter)s
+    pub fn reversible(mut self, reversible: bool) -> Self {
+        self.reversible = reversible;
+        self
+    }
+
+    /// Name the converter is registered under
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// What the converter does, empty if it was never described
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Categories the converter is tagged with, in the order they were added
+    pub fn categories(&self) -> &[String] {
+        &self.categories
+    }
+
+    /// Whether the converter is tagged with `category`
+    pub fn in_category(&self, category: &str) -> bool {
+        self.categories.iter().any(|tag| tag == category)
+    }
+
+    /// Whether the converter has an inverse
+    pub fn is_reversible(&self) -> bool {
+        self.reversible
+    }
+
+    /// Whether pipeline configs can override the fields of the converter, see
+    /// [register_configurable](crate::ConverterRegistry::register_configurable())
+    pub fn is_configurable(&self) -> bool {
+        self.configurable
+    }
+
+    /// Blank info for a converter just registered under `name`
+    pub(crate) fn registered(name: &str, configurable: bool) -> Self {
+        Self {
+            name: name.into(),
+            configurable,
+            ..Self::default()
+        }
+    }
+
+    /// This info with the description, categories and reversibility of `other`
+    pub(crate) fn describe(&mut self, other: Self) {
+        self.description = other.description;
+        self.categories = other.categories;
+        self.reversible = other.reversible;
+    }
+}
@@ -1,11 +1,189 @@
-use arboard::Clipboard;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+// Lets the code generated by `#[converter]` refer to this crate from inside it too
+extern crate self as text_converter;
+
+extern crate alloc;
+
+#[cfg(feature = "zip")]
+mod archive;
+#[cfg(feature = "tokio")]
+pub mod asynchronous;
+#[cfg(feature = "std")]
+mod atomic;
+#[cfg(feature = "std")]
+mod batch;
+mod bom;
+mod bytes;
+#[cfg(feature = "std")]
+mod cancel;
+#[cfg(feature = "clipboard")]
+mod clipboard;
+#[cfg(feature = "clipboard")]
+mod clipboard_watch;
+#[cfg(feature = "std")]
+mod concat;
+#[cfg(feature = "config")]
+mod config;
+#[cfg(feature = "converters")]
+pub mod converters;
+mod diff;
+mod dynamic;
+#[cfg(feature = "encoding")]
+mod encoding;
+mod error;
+#[cfg(feature = "capi")]
+pub mod ffi;
+#[cfg(test)]
+mod fixtures;
+#[cfg(feature = "std")]
+mod harness;
+#[cfg(feature = "std")]
+mod history;
+#[cfg(feature = "http")]
+mod http;
+mod info;
+#[cfg(feature = "std")]
+mod lines;
+#[cfg(feature = "memmap2")]
+mod mmap;
+mod mode;
+mod observer;
+#[cfg(feature = "ocr")]
+mod ocr;
+#[cfg(feature = "std")]
+mod output;
+#[cfg(feature = "rayon")]
+mod parallel;
+pub mod pipeline;
+#[cfg(feature = "plugins")]
+pub mod plugin;
+#[cfg(feature = "config")]
+mod profile;
+#[cfg(feature = "std")]
+mod progress;
+#[cfg(feature = "pyo3")]
+mod python;
+mod regions;
+#[cfg(feature = "inventory")]
+pub mod registration;
+mod registry;
+#[cfg(feature = "std")]
+pub mod repl;
+#[cfg(feature = "std")]
+mod report;
+mod reversible;
+#[cfg(feature = "std")]
+mod stdio;
+#[cfg(feature = "proptest")]
+pub mod testing;
+mod trace;
+#[cfg(feature = "tui")]
+mod tui;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "notify")]
+mod watch;
+
+#[cfg(feature = "zip")]
+pub use archive::ArchiveOptions;
+#[cfg(feature = "std")]
+pub use atomic::write_atomic;
+#[cfg(feature = "std")]
+pub use batch::{BatchSummary, DirOptions};
+pub use bom::Bom;
+pub use bytes::{AsBytes, AsText, BytesConverter};
+#[cfg(feature = "std")]
+pub use cancel::CancellationToken;
+#[cfg(feature = "clipboard")]
+pub use clipboard::{ClipboardTarget, TextClipboard, CLIPBOARD_FALLBACK_VAR};
+#[cfg(feature = "clipboard")]
+pub use clipboard_watch::ClipboardWatcher;
+#[cfg(feature = "std")]
+pub use concat::ConcatOptions;
+pub use diff::Conversion;
+pub use dynamic::DynTextConverter;
+#[cfg(feature = "encoding")]
+pub use encoding::{detect_encoding, EncodingOptions};
+#[cfg(feature = "encoding")]
+pub use encoding_rs;
+pub use error::ConversionError;
+#[cfg(feature = "std")]
+pub use harness::{ConverterTestHarness, FixtureSummary, REGENERATE_VAR};
+#[cfg(feature = "std")]
+pub use history::{History, HistoryEntry};
+#[cfg(feature = "http")]
+pub use http::fetch_url;
+pub use info::ConverterInfo;
+#[cfg(feature = "inventory")]
+#[doc(hidden)]
+pub use inventory;
+#[cfg(feature = "std")]
+pub use lines::ConvertedLines;
+pub use mode::ConversionMode;
+pub use observer::ConversionObserver;
+#[cfg(feature = "ocr")]
+pub use ocr::recognize_clipboard_image;
+#[cfg(feature = "std")]
+pub use output::{Backup, OutputOptions, OverwritePolicy, PlannedWrite};
+pub use pipeline::ConverterExt;
+#[cfg(feature = "config")]
+pub use profile::{Profile, ProfileInput, ProfileOutput, PROFILES_DIR_VAR};
+#[cfg(feature = "std")]
+pub use progress::Progress;
+pub use regions::{PerLine, Regions};
+pub use registry::ConverterRegistry;
+#[cfg(feature = "std")]
+pub use report::ConversionReport;
+pub use reversible::{Decode, ReversibleConverter};
+#[cfg(feature = "std")]
+pub use stdio::{read_stdin, write_to_stdout};
+#[cfg(feature = "derive")]
+pub use text_converter_derive::converter;
+#[cfg(feature = "tui")]
+pub use tui::{Tui, TuiSource};
+#[cfg(feature = "wasm")]
+pub use wasm::{WasmConverter, WasmLimits};
+#[cfg(feature = "notify")]
+pub use watch::{FileWatcher, WatchedConversion};
+
+#[cfg(feature = "std")]
+use crate::progress::Tracker;
+use alloc::{borrow::Cow, string::String};
+#[cfg(feature = "std")]
+use core::ptr;
+#[cfg(feature = "clipboard")]
+use std::time::Duration;
+#[cfg(feature = "std")]
 use std::{
     fs::{self, File},
-    io::Write,
+    io::{self, BufRead, BufReader, Write},
     path::Path,
+    str,
+    time::Instant,
 };
 
 /// Trait with all methods needed to convert text into a specific format
+///
+/// Methods take `&self`, so converters can carry their own configuration
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::TextConverter;
+///
+/// struct Repeat {
+///     times: usize,
+/// }
+///
+/// impl TextConverter for Repeat {
+///     fn converter(&self, input: impl AsRef<str>) -> String {
+///         input.as_ref().repeat(self.times)
+///     }
+/// }
+///
+/// assert_eq!("abab", Repeat { times: 2 }.new_from_text("ab"));
+/// ```
 pub trait TextConverter {
     /// Transforms the input into the desired form
     ///
@@ -14,39 +192,578 @@ pub trait TextConverter {
     /// # Examples
     ///
     /// ```
+    /// use text_converter::TextConverter;
+    ///
     /// struct ReverseText;
     ///
     /// impl TextConverter for ReverseText {
-    ///     fn converter(input: impl AsRef<str>) -> String {
+    ///     fn converter(&self, input: impl AsRef<str>) -> String {
     ///         input.as_ref().chars().rev().collect()
     ///     }
     /// }
     ///
     /// let text = "Hello World!";
-    /// let reverse_text = ReverseText::new_from_text(text);
+    /// let reverse_text = ReverseText.new_from_text(text);
     /// assert_eq!("!dlroW olleH", reverse_text);
     /// ```
-    fn converter(input: impl AsRef<str>) -> String;
+    fn converter(&self, input: impl AsRef<str>) -> String;
+
+    /// Fallible version of the [converter](Self::converter()) method, used by all `try_*` methods
+    ///
+    /// Converters that can fail on some inputs should override this to report the failure
+    /// instead of panicking. Defaults to calling [converter](Self::converter())
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        Ok(self.converter(input))
+    }
+
+    /// Same as [converter](Self::converter()), borrowing `input` instead of allocating when the
+    /// conversion leaves it unchanged
+    ///
+    /// In-place conversions of files and of the clipboard skip writing when the output is
+    /// `input` itself. Converters that can tell cheaply that nothing changes, like normalizers
+    /// on already-clean text, should override this, along with
+    /// [try_converter_cow](Self::try_converter_cow()). Defaults to calling
+    /// [converter](Self::converter()) and borrowing `input` if the output equals it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use text_converter::{converters::Trim, TextConverter};
+    ///
+    /// assert!(matches!(Trim.converter_cow("clean"), Cow::Borrowed("clean")));
+    /// assert!(matches!(Trim.converter_cow(" padded "), Cow::Borrowed("padded")));
+    /// ```
+    fn converter_cow<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        borrow_unchanged(input, self.converter(input))
+    }
+
+    /// Fallible version of the [converter_cow](Self::converter_cow()) method
+    ///
+    /// Defaults to calling [try_converter](Self::try_converter()) and borrowing `input` if the
+    /// output equals it. Infallible converters that override
+    /// [converter_cow](Self::converter_cow()) can override this to return its output
+    ///
+    /// # Errors
+    /// Returns whatever error the converter reports
+    fn try_converter_cow<'a>(&self, input: &'a str) -> Result<Cow<'a, str>, ConversionError> {
+        Ok(borrow_unchanged(input, self.try_converter(input)?))
+    }
+
+    /// Extension of the files this converter outputs, without the leading dot, when it differs
+    /// from the input's. For example, a Markdown renderer outputs `html` files
+    ///
+    /// Output files use it unless [`OutputOptions`] set an extension. Defaults to `None`, which
+    /// keeps the extension of the input file
+    fn output_extension(&self) -> Option<&str> {
+        None
+    }
+
+    /// Same as [converter](Self::converter()), writing the output into `output` instead of a
+    /// new string, so hot loops converting many small texts can reuse one buffer
+    ///
+    /// `output` is cleared first and keeps its capacity. Most built-in converters override this
+    /// to write directly into it, without allocating once it is large enough. Defaults to
+    /// copying the output of [converter](Self::converter()) into it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use text_converter::{converters::Uppercase, TextConverter};
+    ///
+    /// let mut output = String::new();
+    /// for word in ["one", "two"] {
+    ///     Uppercase.converter_into(word, &mut output);
+    ///     println!("{output}");
+    /// }
+    /// assert_eq!("TWO", output);
+    /// ```
+    fn converter_into(&self, input: &str, output: &mut String) {
+        output.clear();
+        output.push_str(&self.converter(input));
+    }
+
+    /// Converts `text` without allocating, for converters whose output has the same length as
+    /// their input, so huge buffers need no second copy
+    ///
+    /// Converters that can should override this, like [`Rot13`](crate::converters::Rot13), or
+    /// [`Uppercase`](crate::converters::Uppercase) on ASCII text. Defaults to converting
+    /// nothing. Use [convert_string_in_place](Self::convert_string_in_place()) to fall back to
+    /// [converter](Self::converter()) for the rest
+    ///
+    /// # Returns
+    /// Whether `text` was converted. It is left untouched otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use text_converter::{converters::Rot13, TextConverter};
+    ///
+    /// let mut text = String::from("Hello");
+    /// assert!(Rot13.converter_in_place(&mut text));
+    /// assert_eq!("Uryyb", text);
+    /// ```
+    fn converter_in_place(&self, _text: &mut str) -> bool {
+        false
+    }
+
+    /// Converts `text` in place with [converter_in_place](Self::converter_in_place()) if the
+    /// converter supports it, or replaces it with the output of [converter](Self::converter())
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use text_converter::{converters::Uppercase, TextConverter};
+    ///
+    /// let mut text = String::from("straße");
+    /// Uppercase.convert_string_in_place(&mut text);
+    /// assert_eq!("STRASSE", text);
+    /// ```
+    fn convert_string_in_place(&self, text: &mut String) {
+        if !self.converter_in_place(text) {
+            *text = self.converter(&*text);
+        }
+    }
 
     /// Converts given input with the [converter](Self::converter()) method
-    fn new_from_text(input: impl AsRef<str>) -> String {
-        Self::converter(input)
+    fn new_from_text(&self, input: impl AsRef<str>) -> String {
+        self.converter(input)
+    }
+
+    /// Converts given input with the [try_converter](Self::try_converter()) method
+    ///
+    /// # Errors
+    /// Returns whatever error the converter reports
+    fn try_new_from_text(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.try_converter(input)
+    }
+
+    /// Converts given input with the [converter](Self::converter()) method, along with a
+    /// [`ConversionReport`] about the conversion
+    #[cfg(feature = "std")]
+    fn new_from_text_with_report(&self, input: impl AsRef<str>) -> (String, ConversionReport) {
+        let start = Instant::now();
+        let output = self.converter(input.as_ref());
+        let report = ConversionReport::new(input.as_ref(), &output, start.elapsed());
+
+        (output, report)
+    }
+
+    /// Converts given input with the [try_converter](Self::try_converter()) method, along with a
+    /// [`ConversionReport`] about the conversion
+    ///
+    /// # Errors
+    /// Returns whatever error the converter reports
+    #[cfg(feature = "std")]
+    fn try_new_from_text_with_report(
+        &self,
+        input: impl AsRef<str>,
+    ) -> Result<(String, ConversionReport), ConversionError> {
+        let start = Instant::now();
+        let output = self.try_converter(input.as_ref())?;
+        let report = ConversionReport::new(input.as_ref(), &output, start.elapsed());
+
+        Ok((output, report))
     }
 
     /// Fetches clipboard contents and converts them with the [converter](Self::converter()) method
-    /// 
+    ///
     /// # Returns
     /// Returns the converted text from the clipboard
-    /// 
+    ///
     /// Will return an empty string if it fails to fetch the clipboard contents or if it contains something other than text
-    /// 
+    ///
+    /// # Panics
+    /// Will panic if it fails to fetch the clipboard
+    #[cfg(feature = "clipboard")]
+    fn new_from_clipboard(&self) -> String {
+        self.new_from_clipboard_target(ClipboardTarget::Clipboard)
+    }
+
+    /// Fetches clipboard contents and converts them with the [try_converter](Self::try_converter()) method
+    ///
+    /// # Errors
+    /// - [`ConversionError::ClipboardUnavailable`] if there is no clipboard, see [`TextClipboard`]
+    /// - [`ConversionError::Clipboard`] if the clipboard does not contain text
+    /// - Whatever error the converter reports
+    #[cfg(feature = "clipboard")]
+    fn try_new_from_clipboard(&self) -> Result<String, ConversionError> {
+        self.try_new_from_clipboard_target(ClipboardTarget::Clipboard)
+    }
+
+    /// Same as [new_from_clipboard](Self::new_from_clipboard()), from `target`, like the primary
+    /// selection on Linux
+    ///
+    /// Will return an empty string if `target` does not exist on this system
+    ///
     /// # Panics
     /// Will panic if it fails to fetch the clipboard
-    fn new_from_clipboard() -> String {
-        let mut clipboard = Clipboard::new().expect("Could not fetch the clipboard contents");
+    #[cfg(feature = "clipboard")]
+    fn new_from_clipboard_target(&self, target: ClipboardTarget) -> String {
+        let mut clipboard = TextClipboard::new()
+            .expect("Could not fetch the clipboard contents")
+            .target(target);
+        let input = clipboard.get_text().unwrap_or_default();
+
+        self.converter(input)
+    }
+
+    /// Same as [try_new_from_clipboard](Self::try_new_from_clipboard()), from `target`, like
+    /// the primary selection on Linux
+    ///
+    /// # Errors
+    /// - [`ConversionError::ClipboardUnavailable`] if there is no clipboard, see
+    ///   [`TextClipboard`], or if `target` does not exist on this system
+    /// - [`ConversionError::Clipboard`] if the clipboard does not contain text
+    /// - Whatever error the converter reports
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::{converters::TitleCase, ClipboardTarget, TextConverter};
+    ///
+    /// // Whatever text is selected, without copying it
+    /// let title = TitleCase.try_new_from_clipboard_target(ClipboardTarget::Primary).unwrap();
+    /// ```
+    #[cfg(feature = "clipboard")]
+    fn try_new_from_clipboard_target(
+        &self,
+        target: ClipboardTarget,
+    ) -> Result<String, ConversionError> {
+        let input = TextClipboard::new()?.target(target).get_text()?;
+
+        self.try_converter(input)
+    }
+
+    /// Recognizes the text in the clipboard image, like a screenshot, and converts it with the
+    /// [converter](Self::converter()) method
+    ///
+    /// Enabled by the `ocr` feature, see
+    /// [try_new_from_clipboard_image_ocr](Self::try_new_from_clipboard_image_ocr())
+    ///
+    /// # Panics
+    /// Will panic if it fails to fetch the clipboard, if it has no image, or if OCR fails
+    #[cfg(feature = "ocr")]
+    fn new_from_clipboard_image_ocr(&self) -> String {
+        let input = ocr::recognize_clipboard_image()
+            .expect("Could not recognize text in the clipboard image");
+
+        self.converter(input)
+    }
+
+    /// Recognizes the text in the clipboard image, like a screenshot, and converts it with the
+    /// [try_converter](Self::try_converter()) method
+    ///
+    /// Enabled by the `ocr` feature. Recognition runs the
+    /// [tesseract](https://github.com/tesseract-ocr/tesseract) command, which has to be installed
+    /// and on the `PATH`
+    ///
+    /// # Errors
+    /// - [`ConversionError::ClipboardUnavailable`] if there is no clipboard, see [`TextClipboard`]
+    /// - [`ConversionError::Clipboard`] if the clipboard has no image
+    /// - [`ConversionError::Ocr`] if tesseract is not installed or fails
+    /// - Whatever error the converter reports
+    #[cfg(feature = "ocr")]
+    fn try_new_from_clipboard_image_ocr(&self) -> Result<String, ConversionError> {
+        self.try_converter(ocr::recognize_clipboard_image()?)
+    }
+
+    /// Fetches the HTML version of the clipboard contents, like text copied from a web page, and
+    /// converts it with the [converter](Self::converter()) method
+    ///
+    /// The converter gets the HTML markup itself. To convert the text without the markup, chain
+    /// [`HtmlToText`](crate::converters::HtmlToText) before it, as in `(HtmlToText, Uppercase)`
+    ///
+    /// # Panics
+    /// Will panic if it fails to fetch the clipboard or if it has no HTML version
+    #[cfg(feature = "clipboard")]
+    fn new_from_clipboard_html(&self) -> String {
+        let mut clipboard = TextClipboard::new().expect("Could not fetch the clipboard contents");
+        let input = clipboard
+            .get_html()
+            .expect("Could not fetch the HTML clipboard contents");
+
+        self.converter(input)
+    }
+
+    /// Fetches the HTML version of the clipboard contents and converts it with the
+    /// [try_converter](Self::try_converter()) method
+    ///
+    /// # Errors
+    /// - [`ConversionError::ClipboardUnavailable`] if there is no clipboard, see [`TextClipboard`]
+    /// - [`ConversionError::Clipboard`] if the clipboard has no HTML version
+    /// - Whatever error the converter reports
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::{converters::{HtmlToText, Uppercase}, TextConverter};
+    ///
+    /// let shout = (HtmlToText, Uppercase).try_new_from_clipboard_html().unwrap();
+    /// ```
+    #[cfg(feature = "clipboard")]
+    fn try_new_from_clipboard_html(&self) -> Result<String, ConversionError> {
+        let input = TextClipboard::new()?.get_html()?;
+
+        self.try_converter(input)
+    }
+
+    /// Converts the clipboard text with the [converter](Self::converter()) method and puts the
+    /// result back into the clipboard as HTML, for converters that produce rich text
+    ///
+    /// The original text is kept as the plain-text version, for applications that can't paste
+    /// HTML. Converts an empty string if the clipboard contains something other than text
+    ///
+    /// # Panics
+    /// Will panic if it fails to fetch the clipboard or to write to it
+    #[cfg(feature = "clipboard")]
+    fn convert_clipboard_to_html(&self) -> String {
+        let mut clipboard = TextClipboard::new().expect("Could not fetch the clipboard contents");
+        let input = clipboard.get_text().unwrap_or_default();
+        let output = self.converter(&input);
+
+        clipboard
+            .set_html(&output, &input)
+            .expect("Could not write to the clipboard");
+
+        output
+    }
+
+    /// Converts the clipboard text with the [try_converter](Self::try_converter()) method and
+    /// puts the result back into the clipboard as HTML, with the original text as the plain-text
+    /// version
+    ///
+    /// The clipboard is left untouched if the conversion fails
+    ///
+    /// # Errors
+    /// - [`ConversionError::ClipboardUnavailable`] if there is no clipboard, see [`TextClipboard`]
+    /// - [`ConversionError::Clipboard`] if the clipboard does not contain text or cannot be
+    ///   written to
+    /// - Whatever error the converter reports
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::TextConverter;
+    ///
+    /// struct Bold;
+    ///
+    /// impl TextConverter for Bold {
+    ///     fn converter(&self, input: impl AsRef<str>) -> String {
+    ///         format!("<b>{}</b>", input.as_ref())
+    ///     }
+    /// }
+    ///
+    /// // Pastes as bold text in editors that read HTML, and as the original text elsewhere
+    /// Bold.try_convert_clipboard_to_html().unwrap();
+    /// ```
+    #[cfg(feature = "clipboard")]
+    fn try_convert_clipboard_to_html(&self) -> Result<String, ConversionError> {
+        let mut clipboard = TextClipboard::new()?;
+        let input = clipboard.get_text()?;
+        let output = self.try_converter(&input)?;
+
+        clipboard.set_html(&output, &input)?;
+
+        Ok(output)
+    }
+
+    /// Converts the clipboard contents with the [converter](Self::converter()) method and puts
+    /// the result back into the clipboard
+    ///
+    /// # Returns
+    /// Returns the converted text that was written to the clipboard
+    ///
+    /// Converts an empty string if the clipboard contains something other than text
+    ///
+    /// # Panics
+    /// Will panic if it fails to fetch the clipboard or to write to it
+    #[cfg(feature = "clipboard")]
+    fn convert_clipboard_in_place(&self) -> String {
+        self.convert_clipboard_target_in_place(ClipboardTarget::Clipboard)
+    }
+
+    /// Converts the clipboard contents with the [try_converter](Self::try_converter()) method and
+    /// puts the result back into the clipboard
+    ///
+    /// The clipboard is left untouched if the conversion fails
+    ///
+    /// # Errors
+    /// - [`ConversionError::ClipboardUnavailable`] if there is no clipboard, see [`TextClipboard`]
+    /// - [`ConversionError::Clipboard`] if the clipboard does not contain text or cannot be
+    ///   written to
+    /// - Whatever error the converter reports
+    #[cfg(feature = "clipboard")]
+    fn try_convert_clipboard_in_place(&self) -> Result<String, ConversionError> {
+        self.try_convert_clipboard_target_in_place(ClipboardTarget::Clipboard)
+    }
+
+    /// Same as [convert_clipboard_in_place](Self::convert_clipboard_in_place()), on `target`,
+    /// like the primary selection on Linux
+    ///
+    /// # Panics
+    /// Will panic if it fails to fetch the clipboard or to write to it, or if `target` does not
+    /// exist on this system
+    #[cfg(feature = "clipboard")]
+    fn convert_clipboard_target_in_place(&self, target: ClipboardTarget) -> String {
+        let mut clipboard = TextClipboard::new()
+            .expect("Could not fetch the clipboard contents")
+            .target(target);
         let input = clipboard.get_text().unwrap_or_default();
+        let output = self.converter_cow(&input);
+
+        if !is_unchanged(&input, &output) {
+            clipboard
+                .set_text(&output)
+                .expect("Could not write to the clipboard");
+        }
+
+        output.into_owned()
+    }
+
+    /// Same as [try_convert_clipboard_in_place](Self::try_convert_clipboard_in_place()), on
+    /// `target`, like the primary selection on Linux
+    ///
+    /// # Errors
+    /// - [`ConversionError::ClipboardUnavailable`] if there is no clipboard, see
+    ///   [`TextClipboard`], or if `target` does not exist on this system
+    /// - [`ConversionError::Clipboard`] if the clipboard does not contain text or cannot be
+    ///   written to
+    /// - Whatever error the converter reports
+    #[cfg(feature = "clipboard")]
+    fn try_convert_clipboard_target_in_place(
+        &self,
+        target: ClipboardTarget,
+    ) -> Result<String, ConversionError> {
+        let mut clipboard = TextClipboard::new()?.target(target);
+        let input = clipboard.get_text()?;
+        let output = self.try_converter_cow(&input)?;
+
+        if !is_unchanged(&input, &output) {
+            clipboard.set_text(&output)?;
+        }
 
-        Self::converter(input)
+        Ok(output.into_owned())
+    }
+
+    /// Watches the clipboard and converts every new text copied to it with the
+    /// [try_converter](Self::try_converter()) method, putting the output back into the clipboard
+    ///
+    /// The clipboard is checked every `interval`. The returned [`ClipboardWatcher`] is an
+    /// iterator that blocks until the next copy; watching stops when it is dropped
+    ///
+    /// # Errors
+    /// [`ConversionError::ClipboardUnavailable`] if there is no clipboard, see [`TextClipboard`],
+    /// or if the fallback is stdin, which cannot be watched
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use text_converter::{converters::TrimLines, TextConverter};
+    ///
+    /// for conversion in TrimLines.watch_clipboard(Duration::from_millis(500)).unwrap() {
+    ///     match conversion {
+    ///         Ok(conversion) if !conversion.is_unchanged() => eprintln!("{}", conversion.diff),
+    ///         Ok(_) => {}
+    ///         Err(e) => eprintln!("{e}"),
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "clipboard")]
+    fn watch_clipboard(
+        &self,
+        interval: Duration,
+    ) -> Result<ClipboardWatcher<'_, Self>, ConversionError> {
+        ClipboardWatcher::new(self, interval)
+    }
+
+    /// Fetches the text at `url` over HTTP(S) and converts it with the
+    /// [converter](Self::converter()) method
+    ///
+    /// Enabled by the `http` feature, see [try_new_from_url](Self::try_new_from_url())
+    ///
+    /// # Panics
+    /// If the request fails, or if the response is not text
+    #[cfg(feature = "http")]
+    fn new_from_url(&self, url: impl AsRef<str>) -> String {
+        self.converter(http::fetch_url(url.as_ref()).expect("Failed to fetch the URL"))
+    }
+
+    /// Fetches the text at `url` over HTTP(S) and converts it with the
+    /// [try_converter](Self::try_converter()) method, e.g. to convert raw files or gists
+    ///
+    /// Enabled by the `http` feature. The body is decoded as described in [`fetch_url`]
+    ///
+    /// # Errors
+    /// - Same as [`fetch_url`]
+    /// - Whatever error the converter reports
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::{converters::Uppercase, TextConverter};
+    ///
+    /// let readme = "https://raw.githubusercontent.com/fruit-bird/text_converter/main/README.md";
+    /// let shout = Uppercase.try_new_from_url(readme).unwrap();
+    /// ```
+    #[cfg(feature = "http")]
+    fn try_new_from_url(&self, url: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.try_converter(http::fetch_url(url.as_ref())?)
+    }
+
+    /// Reads all of stdin and converts it with the [converter](Self::converter()) method
+    ///
+    /// # Panics
+    /// - If stdin is an interactive terminal rather than a pipe or a file
+    /// - If it fails to read stdin or if its contents are not valid UTF-8
+    #[cfg(feature = "std")]
+    fn new_from_stdin(&self) -> String {
+        self.converter(stdio::read_stdin().expect("Failed to read stdin"))
+    }
+
+    /// Reads all of stdin and converts it with the [try_converter](Self::try_converter()) method
+    ///
+    /// Use [convert_stdin_to_stdout](Self::convert_stdin_to_stdout()) to avoid holding the whole
+    /// input in memory
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if stdin is an interactive terminal rather than a pipe or a file,
+    ///   since waiting for it to end would hang, or if reading fails
+    /// - [`ConversionError::Encoding`] if stdin is not valid UTF-8
+    /// - Whatever error the converter reports
+    #[cfg(feature = "std")]
+    fn try_new_from_stdin(&self) -> Result<String, ConversionError> {
+        self.try_converter(stdio::read_stdin()?)
+    }
+
+    /// Converts stdin line by line with [convert_stream](Self::convert_stream()), writing each line
+    /// to stdout as soon as it is converted, e.g. for use in `cat file | mytool`
+    ///
+    /// Both streams are locked for the whole conversion. Works on interactive terminals too,
+    /// converting each line as it is entered. A closed pipe on the stdout end is not treated as an
+    /// error
+    ///
+    /// # Errors
+    /// Same as [convert_stream](Self::convert_stream())
+    #[cfg(feature = "std")]
+    fn convert_stdin_to_stdout(&self) -> Result<(), ConversionError> {
+        let stdin = io::stdin().lock();
+        let stdout = io::stdout().lock();
+
+        stdio::ignore_broken_pipe(self.convert_stream(stdin, stdout))
+    }
+
+    /// Same as [convert_stdin_to_stdout](Self::convert_stdin_to_stdout()), reading from `reader`
+    /// instead of stdin, e.g. a buffered file
+    ///
+    /// # Errors
+    /// Same as [convert_stream](Self::convert_stream())
+    #[cfg(feature = "std")]
+    fn convert_reader_to_stdout(&self, reader: impl BufRead) -> Result<(), ConversionError> {
+        let stdout = io::stdout().lock();
+
+        stdio::ignore_broken_pipe(self.convert_stream(reader, stdout))
     }
 
     /// Fetches file contents and converts them with the [converter](Self::converter()) method
@@ -57,36 +774,784 @@ pub trait TextConverter {
     ///
     /// # Returns
     /// - The conversion string from the file contents
-    /// - Outputs the conversion into a file called originalname_converted.md
-    fn new_from_file(path: impl AsRef<Path>) -> String {
-        let input = fs::read_to_string(path.as_ref()).expect("Failed to read file contents");
-        let output = Self::converter(input);
-        let new_path = path
-            .as_ref()
-            .to_str()
-            .unwrap()
-            .split('.')
-            .take(1)
-            .collect::<String>()
-            + "_converted.md";
-
-        File::create(new_path)
-            .expect("Failed to create the output file")
-            .write_all(output.as_bytes())
+    /// - Outputs the conversion next to the original, in a file called originalname_converted
+    ///   with the original extension (report.txt becomes report_converted.txt), unless the
+    ///   converter has an [output extension](Self::output_extension())
+    #[cfg(feature = "std")]
+    fn new_from_file(&self, path: impl AsRef<Path>) -> String {
+        self.new_from_file_with(path, &OutputOptions::new())
+    }
+
+    /// Fetches file contents and converts them with the [try_converter](Self::try_converter()) method
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if the file is inaccessible or the output file cannot be written
+    /// - [`ConversionError::Encoding`] if the file is not valid UTF-8
+    /// - Whatever error the converter reports
+    ///
+    /// # Returns
+    /// - The conversion string from the file contents
+    /// - Outputs the conversion next to the original, in a file called originalname_converted
+    ///   with the original extension (report.txt becomes report_converted.txt), unless the
+    ///   converter has an [output extension](Self::output_extension())
+    #[cfg(feature = "std")]
+    fn try_new_from_file(&self, path: impl AsRef<Path>) -> Result<String, ConversionError> {
+        self.try_new_from_file_with(path, &OutputOptions::new())
+    }
+
+    /// Same as [new_from_file](Self::new_from_file()), along with a [`ConversionReport`] about
+    /// the conversion
+    ///
+    /// # Panics
+    /// - If file is inaccessible or if it is not in text format (.txt, .md...)
+    /// - If it fails to create the output file
+    #[cfg(feature = "std")]
+    fn new_from_file_with_report(&self, path: impl AsRef<Path>) -> (String, ConversionReport) {
+        let bytes = trace::read_file(path.as_ref()).expect("Failed to read file contents");
+        let (input, bom) = Bom::decode(&bytes).expect("Failed to decode file contents");
+        let (output, mut report) = self.new_from_text_with_report(input);
+        report.bom = bom;
+
+        OutputOptions::new()
+            .for_converter(self)
+            .write_text(path.as_ref(), &output, bom)
+            .expect("Failed to write to the output file");
+
+        (output, report)
+    }
+
+    /// Same as [try_new_from_file](Self::try_new_from_file()), along with a
+    /// [`ConversionReport`] about the conversion
+    ///
+    /// # Errors
+    /// Same as [try_new_from_file](Self::try_new_from_file())
+    #[cfg(feature = "std")]
+    fn try_new_from_file_with_report(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<(String, ConversionReport), ConversionError> {
+        let bytes = trace::read_file(path.as_ref())?;
+        let (input, bom) = Bom::decode(&bytes)?;
+        let (output, mut report) = self.try_new_from_text_with_report(input)?;
+        report.bom = bom;
+
+        OutputOptions::new()
+            .for_converter(self)
+            .write_text(path.as_ref(), &output, bom)?;
+
+        Ok((output, report))
+    }
+
+    /// Fetches file contents and converts them with the [converter](Self::converter()) method,
+    /// writing the output as configured by `options`
+    ///
+    /// # Panics
+    /// - If file is inaccessible or if it is not in text format (.txt, .md...)
+    /// - If it fails to write the output file, or if it exists and the
+    ///   [overwrite policy](OverwritePolicy::Fail) forbids replacing it
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::{converters::Uppercase, OutputOptions, TextConverter};
+    ///
+    /// // Writes report.upper.txt next to report.txt
+    /// let options = OutputOptions::new().suffix(".upper").extension("txt");
+    /// let shout = Uppercase.new_from_file_with("report.txt", &options);
+    /// ```
+    #[cfg(feature = "std")]
+    fn new_from_file_with(&self, path: impl AsRef<Path>, options: &OutputOptions) -> String {
+        let bytes = trace::read_file(path.as_ref()).expect("Failed to read file contents");
+        let (input, bom) = Bom::decode(&bytes).expect("Failed to decode file contents");
+        let output = self.converter(input);
+
+        options
+            .for_converter(self)
+            .write_text(path.as_ref(), &output, bom)
+            .expect("Failed to write to the output file");
+
+        output
+    }
+
+    /// Fetches file contents and converts them with the [try_converter](Self::try_converter())
+    /// method, writing the output as configured by `options`
+    ///
+    /// Files starting with a [byte order mark](Bom) are read without it, and files with a UTF-16
+    /// one are read as UTF-16. The output only gets the mark back if `options` ask to
+    /// [keep it](OutputOptions::keep_bom())
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if the file is inaccessible, the output file cannot be written,
+    ///   or it exists and the [overwrite policy](OverwritePolicy::Fail) forbids replacing it
+    /// - [`ConversionError::Encoding`] if the file is not valid UTF-8, or not valid UTF-16 after
+    ///   a UTF-16 byte order mark
+    /// - Whatever error the converter reports
+    #[cfg(feature = "std")]
+    fn try_new_from_file_with(
+        &self,
+        path: impl AsRef<Path>,
+        options: &OutputOptions,
+    ) -> Result<String, ConversionError> {
+        let bytes = trace::read_file(path.as_ref())?;
+        let (input, bom) = Bom::decode(&bytes)?;
+        let output = self.try_converter(input)?;
+
+        options
+            .for_converter(self)
+            .write_text(path.as_ref(), &output, bom)?;
+
+        Ok(output)
+    }
+
+    /// Converts a file with the [converter](Self::converter()) method and replaces its contents
+    /// with the output, after copying the original to a `.bak` file next to it
+    ///
+    /// # Panics
+    /// - If file is inaccessible or if it is not in text format (.txt, .md...)
+    /// - If it fails to write the backup or the file
+    #[cfg(feature = "std")]
+    fn convert_file_in_place(&self, path: impl AsRef<Path>) -> String {
+        self.convert_file_in_place_with(path, &Backup::default())
+    }
+
+    /// Converts a file with the [try_converter](Self::try_converter()) method and replaces its
+    /// contents with the output, after copying the original to a `.bak` file next to it
+    ///
+    /// # Errors
+    /// Same as [try_convert_file_in_place_with](Self::try_convert_file_in_place_with())
+    #[cfg(feature = "std")]
+    fn try_convert_file_in_place(&self, path: impl AsRef<Path>) -> Result<String, ConversionError> {
+        self.try_convert_file_in_place_with(path, &Backup::default())
+    }
+
+    /// Same as [convert_file_in_place](Self::convert_file_in_place()), keeping the original as
+    /// set by `backup`
+    ///
+    /// # Panics
+    /// - If file is inaccessible or if it is not in text format (.txt, .md...)
+    /// - If it fails to write the backup or the file
+    #[cfg(feature = "std")]
+    fn convert_file_in_place_with(&self, path: impl AsRef<Path>, backup: &Backup) -> String {
+        let path = path.as_ref();
+        let bytes = trace::read_file(path).expect("Failed to read file contents");
+        let (input, bom) = Bom::decode(&bytes).expect("Failed to decode file contents");
+        let output = self.converter_cow(&input);
+
+        if !is_unchanged(&input, &output) {
+            backup.write(path).expect("Failed to write the backup");
+            write_atomic(path, output::with_bom(&output, bom))
+                .expect("Failed to write to the file");
+        }
+
+        output.into_owned()
+    }
+
+    /// Converts a file with the [try_converter](Self::try_converter()) method and replaces its
+    /// contents with the output, keeping the original as set by `backup`
+    ///
+    /// The file keeps its [byte order mark](Bom), if it had one. Nothing is written if the
+    /// conversion fails or [leaves the file unchanged](Self::converter_cow()), and the file is
+    /// [replaced atomically](write_atomic()) otherwise, so a crash never leaves it half-written
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if the file is inaccessible, or if the backup or the file
+    ///   cannot be written
+    /// - [`ConversionError::Encoding`] if the file is not valid UTF-8, or not valid UTF-16 after
+    ///   a UTF-16 byte order mark
+    /// - Whatever error the converter reports
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::{converters::Trim, Backup, TextConverter};
+    ///
+    /// // Keeps the original as backups/notes.md
+    /// Trim.try_convert_file_in_place_with("notes.md", &Backup::Dir("backups".into()))
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    fn try_convert_file_in_place_with(
+        &self,
+        path: impl AsRef<Path>,
+        backup: &Backup,
+    ) -> Result<String, ConversionError> {
+        let path = path.as_ref();
+        let bytes = trace::read_file(path)?;
+        let (input, bom) = Bom::decode(&bytes)?;
+        let output = self.try_converter_cow(&input)?;
+
+        if !is_unchanged(&input, &output) {
+            backup.write(path)?;
+            write_atomic(path, output::with_bom(&output, bom))?;
+        }
+
+        Ok(output.into_owned())
+    }
+
+    /// Same as [try_new_from_file_with](Self::try_new_from_file_with()), calling `progress` as
+    /// the file is read with the number of bytes read so far, and once more when the output is
+    /// written
+    ///
+    /// # Errors
+    /// Same as [try_new_from_file_with](Self::try_new_from_file_with())
+    #[cfg(feature = "std")]
+    fn try_new_from_file_with_progress(
+        &self,
+        path: impl AsRef<Path>,
+        options: &OutputOptions,
+        progress: impl FnMut(Progress),
+    ) -> Result<String, ConversionError> {
+        let size = fs::metadata(path.as_ref())?.len();
+        let mut tracker = Tracker::new(Some(size), Some(1), progress);
+        let bytes = tracker.read_file(path.as_ref())?;
+        let (input, bom) = Bom::decode(&bytes)?;
+        let output = self.try_converter(input)?;
+
+        options
+            .for_converter(self)
+            .write_text(path.as_ref(), &output, bom)?;
+        tracker.advance(0, 1);
+
+        Ok(output)
+    }
+
+    /// Opens a file and returns an iterator converting it one line at a time with the
+    /// [try_converter](Self::try_converter()) method, see [`ConvertedLines`]
+    ///
+    /// A UTF-8 [byte order mark](Bom) at the start of the file is skipped
+    ///
+    /// # Errors
+    /// [`ConversionError::Io`] if the file cannot be opened. Errors reading, decoding or
+    /// converting a line are yielded by the iterator instead
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::{converters::TrimLines, TextConverter};
+    ///
+    /// for line in TrimLines.lines_from_file("huge.log").unwrap().take(10) {
+    ///     println!("{}", line.unwrap());
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    fn lines_from_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<ConvertedLines<'_, Self, BufReader<File>>, ConversionError> {
+        let file = File::open(path.as_ref())?;
+        Ok(ConvertedLines::new(self, BufReader::new(file)))
+    }
+
+    /// Returns an iterator converting `reader` one line at a time with the
+    /// [try_converter](Self::try_converter()) method, see [`ConvertedLines`]
+    #[cfg(feature = "std")]
+    fn lines_from_reader<R: BufRead>(&self, reader: R) -> ConvertedLines<'_, Self, R> {
+        ConvertedLines::new(self, reader)
+    }
+
+    /// Converts a file line by line into `writer` like [convert_stream](Self::convert_stream()),
+    /// reading it through a memory map instead of loading it into memory
+    ///
+    /// Enabled by the `memmap2` feature. Meant for files of several gigabytes: only the line
+    /// being converted is copied, and it is checked to be valid UTF-8 on its own, so the lines
+    /// before an invalid one are already written when the conversion fails. A UTF-8
+    /// [byte order mark](Bom) is skipped
+    ///
+    /// Changing the file from another process while it is being converted is undefined
+    /// behavior, as with every memory map
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if the file cannot be mapped or `writer` fails
+    /// - [`ConversionError::Encoding`] if a line is not valid UTF-8
+    /// - Whatever error the converter reports
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::{fs::File, io::BufWriter};
+    /// use text_converter::{converters::TrimLines, TextConverter};
+    ///
+    /// let output = BufWriter::new(File::create("huge_trimmed.log").unwrap());
+    /// TrimLines.convert_file_mmap("huge.log", output).unwrap();
+    /// ```
+    #[cfg(feature = "memmap2")]
+    fn convert_file_mmap(
+        &self,
+        path: impl AsRef<Path>,
+        writer: impl Write,
+    ) -> Result<(), ConversionError> {
+        let map = mmap::map(path.as_ref())?;
+        let bytes = map.strip_prefix(Bom::Utf8.as_bytes()).unwrap_or(&map);
+
+        let tracker = Tracker::new(Some(bytes.len() as u64), None, |_| {});
+        convert_lines(self, bytes, writer, tracker, None)
+    }
+
+    /// Converts a file like [try_new_from_file_with](Self::try_new_from_file_with()), but only
+    /// reports what would be written instead of touching the filesystem
+    ///
+    /// # Errors
+    /// Same as [try_new_from_file_with](Self::try_new_from_file_with()), without the failure to
+    /// write
+    ///
+    /// # Returns
+    /// The file that would be written, or `None` if `options` would write none
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::{converters::TrimLines, OutputOptions, TextConverter};
+    ///
+    /// let planned = TrimLines
+    ///     .try_dry_run_file_with("notes.md", &OutputOptions::new())
+    ///     .unwrap();
+    /// if let Some(planned) = planned {
+    ///     println!("{planned}\n{}", planned.conversion.diff);
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    fn try_dry_run_file_with(
+        &self,
+        path: impl AsRef<Path>,
+        options: &OutputOptions,
+    ) -> Result<Option<PlannedWrite>, ConversionError> {
+        let bytes = trace::read_file(path.as_ref())?;
+        let (input, bom) = Bom::decode(&bytes)?;
+        let output = self.try_converter(&input)?;
+
+        options
+            .for_converter(self)
+            .plan_text(path.as_ref(), Conversion::new(input, output), bom)
+    }
+
+    /// Fetches file contents in any encoding and converts them with the
+    /// [converter](Self::converter()) method, writing the output as configured by `options`
+    ///
+    /// Enabled by the `encoding` feature. The input is decoded as set by `encoding`, see
+    /// [try_new_from_file_with_encoding](Self::try_new_from_file_with_encoding())
+    ///
+    /// # Panics
+    /// - If file is inaccessible or if it is not valid in its encoding
+    /// - If the output cannot be written in the encoding of the input
+    /// - If it fails to write the output file, or if it exists and the
+    ///   [overwrite policy](OverwritePolicy::Fail) forbids replacing it
+    #[cfg(feature = "encoding")]
+    fn new_from_file_with_encoding(
+        &self,
+        path: impl AsRef<Path>,
+        encoding: &EncodingOptions,
+        options: &OutputOptions,
+    ) -> String {
+        let bytes = trace::read_file(path.as_ref()).expect("Failed to read file contents");
+        let input = encoding
+            .decode(&bytes)
+            .expect("Failed to decode file contents");
+        let output = self.converter(&input.text);
+
+        let encoded = encoding
+            .encode(&input, &output)
+            .expect("Failed to encode the output");
+        options
+            .for_converter(self)
+            .write(path.as_ref(), encoded)
             .expect("Failed to write to the output file");
 
         output
     }
+
+    /// Fetches file contents in any encoding and converts them with the
+    /// [try_converter](Self::try_converter()) method, writing the output as configured by
+    /// `options`
+    ///
+    /// Enabled by the `encoding` feature. The input is decoded from the encoding set in
+    /// `encoding`, or from the one [detected](detect_encoding()) otherwise, so Latin-1,
+    /// Shift_JIS or UTF-16 files can be converted too. The output is written as UTF-8, unless
+    /// `encoding` asks to [keep](EncodingOptions::keep_encoding()) the encoding of the input
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if the file is inaccessible, the output file cannot be written,
+    ///   or it exists and the [overwrite policy](OverwritePolicy::Fail) forbids replacing it
+    /// - [`ConversionError::InvalidInput`] if the file is not valid in its encoding, or if the
+    ///   output has characters that the encoding of the input cannot represent, unless
+    ///   `encoding` sets another [mode](EncodingOptions::mode()) than the strict one
+    /// - Whatever error the converter reports
+    ///
+    /// # Returns
+    /// The conversion string from the file contents, in UTF-8 whatever the encoding of the file
+    #[cfg(feature = "encoding")]
+    fn try_new_from_file_with_encoding(
+        &self,
+        path: impl AsRef<Path>,
+        encoding: &EncodingOptions,
+        options: &OutputOptions,
+    ) -> Result<String, ConversionError> {
+        let input = encoding.decode(&trace::read_file(path.as_ref())?)?;
+        let output = self.try_converter(&input.text)?;
+
+        options
+            .for_converter(self)
+            .write(path.as_ref(), encoding.encode(&input, &output)?)?;
+
+        Ok(output)
+    }
+
+    /// Converts every text file in a directory with the [try_converter](Self::try_converter())
+    /// method, as configured by `options`
+    ///
+    /// Outputs are written next to the originals, following the naming of
+    /// [new_from_file](Self::new_from_file()), unless an output directory is set. Files that look
+    /// like outputs of a previous conversion and files that are not valid UTF-8 are skipped
+    ///
+    /// # Errors
+    /// [`ConversionError::Io`] if the directory cannot be walked. Failures on individual files do
+    /// not stop the batch and are reported in the [`BatchSummary`] instead
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::{converters::Trim, DirOptions, TextConverter};
+    ///
+    /// let summary = Trim.new_from_dir("notes", &DirOptions::new().extensions(["md"])).unwrap();
+    /// println!("converted {} files", summary.converted.len());
+    /// ```
+    #[cfg(feature = "std")]
+    fn new_from_dir(
+        &self,
+        path: impl AsRef<Path>,
+        options: &DirOptions,
+    ) -> Result<BatchSummary, ConversionError> {
+        self.new_from_dir_with_progress(path, options, |_| {})
+    }
+
+    /// Same as [new_from_dir](Self::new_from_dir()), calling `progress` after each file with the
+    /// number of files and bytes done so far, out of the totals found before starting
+    ///
+    /// # Errors
+    /// Same as [new_from_dir](Self::new_from_dir())
+    #[cfg(feature = "std")]
+    fn new_from_dir_with_progress(
+        &self,
+        path: impl AsRef<Path>,
+        options: &DirOptions,
+        progress: impl FnMut(Progress),
+    ) -> Result<BatchSummary, ConversionError> {
+        batch::convert_dir(self, path.as_ref(), options, progress)
+    }
+
+    /// Joins the contents of several files into one input, in order, converts it with the
+    /// [converter](Self::converter()) method and writes the output to a single file, as
+    /// configured by `options`
+    ///
+    /// # Panics
+    /// - If any file is inaccessible or if it is not in text format (.txt, .md...)
+    /// - If it fails to write the output file
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::{converters::Trim, ConcatOptions, TextConverter};
+    ///
+    /// let options = ConcatOptions::new("report.md").header("## {name}\n\n").separator("\n\n");
+    /// Trim.new_from_files(["monday.md", "tuesday.md"], &options);
+    /// ```
+    #[cfg(feature = "std")]
+    fn new_from_files(
+        &self,
+        paths: impl IntoIterator<Item = impl AsRef<Path>>,
+        options: &ConcatOptions,
+    ) -> String {
+        let input = concat::concat(paths, options).expect("Failed to read file contents");
+        let output = self.converter(input);
+        write_atomic(options.output(), &output).expect("Failed to write to the output file");
+
+        output
+    }
+
+    /// Same as [new_from_files](Self::new_from_files()), converting with the
+    /// [try_converter](Self::try_converter()) method
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if a file is inaccessible or the output file cannot be written
+    /// - [`ConversionError::Encoding`] if a file is not valid UTF-8
+    /// - Whatever error the converter reports
+    #[cfg(feature = "std")]
+    fn try_new_from_files(
+        &self,
+        paths: impl IntoIterator<Item = impl AsRef<Path>>,
+        options: &ConcatOptions,
+    ) -> Result<String, ConversionError> {
+        concat::convert_files(self, paths, options)
+    }
+
+    /// Converts the text entries of a ZIP archive with the [try_converter](Self::try_converter())
+    /// method, writing a new archive with the converted entries as configured by `options`
+    ///
+    /// Enabled by the `zip` feature. Entries keep their names, compression and timestamps.
+    /// Directories, entries that do not match the options or are not valid UTF-8 text, and
+    /// entries that fail to convert are copied to the new archive unchanged
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if the archive is inaccessible or the new one cannot be written
+    /// - [`ConversionError::Archive`] if it is not a valid ZIP archive
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::{converters::Trim, ArchiveOptions, TextConverter};
+    ///
+    /// let options = ArchiveOptions::new().extensions(["md"]);
+    /// let summary = Trim.new_from_archive("docs.zip", &options).unwrap();
+    /// println!("converted {} entries", summary.converted.len());
+    /// ```
+    #[cfg(feature = "zip")]
+    fn new_from_archive(
+        &self,
+        path: impl AsRef<Path>,
+        options: &ArchiveOptions,
+    ) -> Result<BatchSummary, ConversionError> {
+        archive::convert_archive(self, path.as_ref(), options)
+    }
+
+    /// Converts several files concurrently with [try_new_from_file](Self::try_new_from_file()),
+    /// on a dedicated thread pool of `threads` threads
+    ///
+    /// Enabled by the `rayon` feature. Passing `0` threads lets rayon pick the number of threads,
+    /// usually one per CPU core
+    ///
+    /// # Errors
+    /// [`ConversionError::Io`] if the thread pool cannot be created. Failures on individual files
+    /// do not stop the others
+    ///
+    /// # Returns
+    /// Every path with the result of its conversion, in the same order as `paths`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::{converters::Trim, TextConverter};
+    ///
+    /// let results = Trim.new_from_files_parallel(["a.txt", "b.txt"], 0).unwrap();
+    /// for (path, result) in results {
+    ///     if let Err(e) = result {
+    ///         eprintln!("{}: {e}", path.display());
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[allow(clippy::type_complexity)]
+    fn new_from_files_parallel(
+        &self,
+        paths: impl IntoIterator<Item = impl AsRef<Path>>,
+        threads: usize,
+    ) -> Result<Vec<(std::path::PathBuf, Result<String, ConversionError>)>, ConversionError>
+    where
+        Self: Sync,
+    {
+        let paths = paths.into_iter().map(|p| p.as_ref().to_owned()).collect();
+        parallel::convert_files(self, paths, threads)
+    }
+
+    /// Watches a file, or every file in a directory, and converts it again with the
+    /// [try_converter](Self::try_converter()) method every time it changes, writing the output
+    /// as configured by `options`
+    ///
+    /// Enabled by the `notify` feature. The returned [`FileWatcher`] is an iterator that blocks
+    /// until the next change; watching stops when it is dropped. Outputs written into a watched
+    /// directory are not converted again
+    ///
+    /// # Errors
+    /// [`ConversionError::Io`] if the path does not exist, or [`ConversionError::Watch`] if it
+    /// cannot be watched
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::{converters::TitleCase, OutputOptions, TextConverter};
+    ///
+    /// for conversion in TitleCase.watch_file("notes.md", &OutputOptions::new()).unwrap() {
+    ///     match conversion {
+    ///         Ok(conversion) => println!("{}", conversion.output),
+    ///         Err(e) => eprintln!("{e}"),
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "notify")]
+    fn watch_file(
+        &self,
+        path: impl AsRef<Path>,
+        options: &OutputOptions,
+    ) -> Result<FileWatcher<'_, Self>, ConversionError> {
+        FileWatcher::new(self, path.as_ref(), options.for_converter(self))
+    }
+
+    /// Converts everything read from `reader` line by line with the
+    /// [try_converter](Self::try_converter()) method, writing each converted line to `writer`
+    ///
+    /// Only one line is held in memory at a time, which makes this suitable for very large inputs.
+    /// Line endings are kept as they are and not passed to the converter, so converters that work
+    /// across lines (e.g. reversing the line order) only see one line at a time
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if reading or writing fails
+    /// - [`ConversionError::Encoding`] if a line is not valid UTF-8
+    /// - Whatever error the converter reports
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use text_converter::TextConverter;
+    ///
+    /// struct ReverseText;
+    ///
+    /// impl TextConverter for ReverseText {
+    ///     fn converter(&self, input: impl AsRef<str>) -> String {
+    ///         input.as_ref().chars().rev().collect()
+    ///     }
+    /// }
+    ///
+    /// let mut output = Vec::new();
+    /// ReverseText.convert_stream("abc\r\ndef\n".as_bytes(), &mut output).unwrap();
+    /// assert_eq!(b"cba\r\nfed\n", output.as_slice());
+    /// ```
+    #[cfg(feature = "std")]
+    fn convert_stream(
+        &self,
+        reader: impl BufRead,
+        writer: impl Write,
+    ) -> Result<(), ConversionError> {
+        self.convert_stream_with_progress(reader, writer, None, |_| {})
+    }
+
+    /// Same as [convert_stream](Self::convert_stream()), calling `progress` after each line with
+    /// the number of bytes read so far, out of `total_bytes` if the length of the stream is known
+    ///
+    /// # Errors
+    /// Same as [convert_stream](Self::convert_stream())
+    #[cfg(feature = "std")]
+    fn convert_stream_with_progress(
+        &self,
+        reader: impl BufRead,
+        writer: impl Write,
+        total_bytes: Option<u64>,
+        progress: impl FnMut(Progress),
+    ) -> Result<(), ConversionError> {
+        let tracker = Tracker::new(total_bytes, None, progress);
+        convert_lines(self, reader, writer, tracker, None)
+    }
+
+    /// Same as [convert_stream](Self::convert_stream()), stopping before the next line once
+    /// `token` is cancelled
+    ///
+    /// The lines converted until then are written and flushed
+    ///
+    /// # Errors
+    /// - [`ConversionError::Cancelled`] if `token` was cancelled before the end of the stream
+    /// - Same as [convert_stream](Self::convert_stream()) otherwise
+    #[cfg(feature = "std")]
+    fn convert_stream_with_cancellation(
+        &self,
+        reader: impl BufRead,
+        writer: impl Write,
+        token: &CancellationToken,
+    ) -> Result<(), ConversionError> {
+        let tracker = Tracker::new(None, None, |_| {});
+        convert_lines(self, reader, writer, tracker, Some(token))
+    }
+}
+
+impl<T: TextConverter + ?Sized> TextConverter for &T {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        (**self).converter(input)
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        (**self).try_converter(input)
+    }
+
+    fn converter_cow<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        (**self).converter_cow(input)
+    }
+
+    fn try_converter_cow<'a>(&self, input: &'a str) -> Result<Cow<'a, str>, ConversionError> {
+        (**self).try_converter_cow(input)
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        (**self).output_extension()
+    }
+
+    fn converter_into(&self, input: &str, output: &mut String) {
+        (**self).converter_into(input, output);
+    }
+
+    fn converter_in_place(&self, text: &mut str) -> bool {
+        (**self).converter_in_place(text)
+    }
+}
+
+/// `input` borrowed if `output` equals it, so that callers can tell nothing changed
+fn borrow_unchanged(input: &str, output: String) -> Cow<'_, str> {
+    match output == input {
+        true => Cow::Borrowed(input),
+        false => Cow::Owned(output),
+    }
+}
+
+/// Whether `output` is `input` itself, borrowed by [`TextConverter::converter_cow`]
+#[cfg(feature = "std")]
+fn is_unchanged(input: &str, output: &str) -> bool {
+    ptr::eq(input, output)
+}
+
+/// Converts `reader` line by line into `writer`, reporting the bytes read to `tracker` and
+/// checking `token` before each line
+#[cfg(feature = "std")]
+fn convert_lines<C: TextConverter + ?Sized>(
+    converter: &C,
+    mut reader: impl BufRead,
+    mut writer: impl Write,
+    mut tracker: Tracker<impl FnMut(Progress)>,
+    token: Option<&CancellationToken>,
+) -> Result<(), ConversionError> {
+    let mut line = Vec::new();
+
+    while reader.read_until(b'\n', &mut line)? != 0 {
+        if let Some(token) = token.filter(|token| token.is_cancelled()) {
+            writer.flush()?;
+            return token.check();
+        }
+
+        let (content, ending) = split_line_ending(str::from_utf8(&line)?);
+        writer.write_all(converter.try_converter(content)?.as_bytes())?;
+        writer.write_all(ending.as_bytes())?;
+        tracker.advance(line.len() as u64, 0);
+        line.clear();
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Splits a line that may end with `\n` or `\r\n` into its content and its line ending
+pub(crate) fn split_line_ending(line: &str) -> (&str, &str) {
+    let content = line
+        .strip_suffix("\r\n")
+        .or_else(|| line.strip_suffix('\n'))
+        .unwrap_or(line);
+
+    line.split_at(content.len())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "std")]
+    use std::env;
 
     struct ReverseText;
 
     impl TextConverter for ReverseText {
-        fn converter(input: impl AsRef<str>) -> String {
+        fn converter(&self, input: impl AsRef<str>) -> String {
             input.as_ref().chars().rev().collect()
         }
     }
@@ -94,7 +1559,219 @@ mod tests {
     #[test]
     fn reverse_conversion() {
         let text = "Hello World!";
-        let reverse_text = ReverseText::new_from_text(text);
+        let reverse_text = ReverseText.new_from_text(text);
         assert_eq!("!dlroW olleH", reverse_text);
     }
+
+    #[test]
+    fn stateful_conversion() {
+        struct Surround(&'static str);
+
+        impl TextConverter for Surround {
+            fn converter(&self, input: impl AsRef<str>) -> String {
+                format!("{}{}{}", self.0, input.as_ref(), self.0)
+            }
+        }
+
+        assert_eq!("**bold**", Surround("**").new_from_text("bold"));
+        assert_eq!(
+            "_!olleh_",
+            (&ReverseText, Surround("_")).new_from_text("hello!")
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn stream_conversion() {
+        let mut output = Vec::new();
+        ReverseText
+            .convert_stream("Hello\nWorld!\r\n\nlast".as_bytes(), &mut output)
+            .unwrap();
+        assert_eq!(
+            "olleH\n!dlroW\r\n\ntsal",
+            String::from_utf8(output).unwrap()
+        );
+
+        let invalid: &[u8] = b"ok\n\xff\n";
+        assert!(matches!(
+            ReverseText.convert_stream(invalid, Vec::new()),
+            Err(ConversionError::Encoding(_))
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_conversion_with_progress() {
+        let path = env::temp_dir().join("text_converter_progress.txt");
+        fs::write(&path, "Hello").unwrap();
+
+        let mut last = Progress::default();
+        let output = ReverseText
+            .try_new_from_file_with_progress(&path, &OutputOptions::no_file(), |p| last = p)
+            .unwrap();
+        assert_eq!("olleH", output);
+        assert_eq!((5, 1), (last.bytes_processed, last.files_completed));
+        assert_eq!(Some(1.0), last.fraction());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn cancelled_stream_conversion() {
+        let token = CancellationToken::new();
+        let mut output = Vec::new();
+        ReverseText
+            .convert_stream_with_cancellation("ab\ncd\n".as_bytes(), &mut output, &token)
+            .unwrap();
+        assert_eq!("ba\ndc\n", String::from_utf8(output).unwrap());
+
+        token.cancel();
+        assert!(matches!(
+            ReverseText.convert_stream_with_cancellation("ab\n".as_bytes(), Vec::new(), &token),
+            Err(ConversionError::Cancelled)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "memmap2")]
+    fn conversion_through_mmap() {
+        let path = env::temp_dir().join("text_converter_mmap.txt");
+        fs::write(&path, "\u{feff}Hello\nWorld!\r\n").unwrap();
+
+        let mut output = Vec::new();
+        ReverseText.convert_file_mmap(&path, &mut output).unwrap();
+        assert_eq!("olleH\n!dlroW\r\n", String::from_utf8(output).unwrap());
+
+        fs::write(&path, "").unwrap();
+        let mut output = Vec::new();
+        ReverseText.convert_file_mmap(&path, &mut output).unwrap();
+        assert!(output.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn lazy_lines_from_file() {
+        let path = env::temp_dir().join("text_converter_lines.txt");
+        fs::write(&path, b"\xef\xbb\xbfHello\r\n\xff\nWorld").unwrap();
+
+        let mut lines = ReverseText.lines_from_file(&path).unwrap();
+        assert_eq!("olleH", lines.next().unwrap().unwrap());
+        assert!(matches!(
+            lines.next(),
+            Some(Err(ConversionError::Encoding(_)))
+        ));
+        assert_eq!("dlroW", lines.next().unwrap().unwrap());
+        assert!(lines.next().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn try_conversion_from_file() {
+        let path = env::temp_dir().join("text_converter_try_reverse.txt");
+        fs::write(&path, "Hello World!").unwrap();
+
+        let reverse_text = ReverseText.try_new_from_file(&path).unwrap();
+        assert_eq!("!dlroW olleH", reverse_text);
+        assert_eq!(
+            reverse_text,
+            fs::read_to_string(OutputOptions::new().output_path(&path)).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "encoding")]
+    fn conversion_from_latin1_file() {
+        let path = env::temp_dir().join("text_converter_latin1.txt");
+        fs::write(&path, b"na\xefve caf\xe9").unwrap();
+
+        let keep = EncodingOptions::new().keep_encoding(true);
+        let output = ReverseText
+            .try_new_from_file_with_encoding(&path, &keep, &OutputOptions::new())
+            .unwrap();
+        assert_eq!("éfac evïan", output);
+        assert_eq!(
+            b"\xe9fac ev\xefan".as_slice(),
+            fs::read(OutputOptions::new().output_path(&path)).unwrap()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn conversion_keeping_bom() {
+        let path = env::temp_dir().join("text_converter_bom.txt");
+        fs::write(&path, Bom::Utf16Le.encode("Hello")).unwrap();
+
+        let keep = OutputOptions::new().suffix("_kept").keep_bom(true);
+        assert_eq!(
+            "olleH",
+            ReverseText.try_new_from_file_with(&path, &keep).unwrap()
+        );
+        assert_eq!(
+            Bom::Utf16Le.encode("olleH"),
+            fs::read(keep.output_path(&path)).unwrap()
+        );
+
+        let (output, report) = ReverseText.try_new_from_file_with_report(&path).unwrap();
+        assert_eq!((5, Some(Bom::Utf16Le)), (report.input_chars, report.bom));
+        assert_eq!(
+            output,
+            fs::read_to_string(OutputOptions::new().output_path(&path)).unwrap()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn conversion_in_place() {
+        let path = env::temp_dir().join("text_converter_in_place.txt");
+        fs::write(&path, "Hello").unwrap();
+
+        assert_eq!(
+            "olleH",
+            ReverseText.try_convert_file_in_place(&path).unwrap()
+        );
+        assert_eq!("olleH", fs::read_to_string(&path).unwrap());
+        assert_eq!(
+            "Hello",
+            fs::read_to_string(path.with_extension("txt.bak")).unwrap()
+        );
+
+        fs::write(&path, Bom::Utf8.encode("Hello")).unwrap();
+        ReverseText
+            .try_convert_file_in_place_with(&path, &Backup::None)
+            .unwrap();
+        assert_eq!(Bom::Utf8.encode("olleH"), fs::read(&path).unwrap());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn unchanged_file_is_not_rewritten() {
+        let path = env::temp_dir().join("text_converter_unchanged.txt");
+        let backup = Backup::Suffix(".unchanged".to_owned());
+        let _ = fs::remove_file(backup.path(&path).unwrap());
+        fs::write(&path, "level").unwrap();
+
+        assert_eq!(
+            "level",
+            ReverseText
+                .try_convert_file_in_place_with(&path, &backup)
+                .unwrap()
+        );
+        assert!(!backup.path(&path).unwrap().exists());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn try_conversion_from_invalid_file() {
+        let missing = env::temp_dir().join("text_converter_missing.txt");
+        assert!(matches!(
+            ReverseText.try_new_from_file(missing),
+            Err(ConversionError::Io(_))
+        ));
+
+        let binary = env::temp_dir().join("text_converter_binary.txt");
+        fs::write(&binary, [0xff, 0xfe, 0xfd]).unwrap();
+        assert!(matches!(
+            ReverseText.try_new_from_file(binary),
+            Err(ConversionError::Encoding(_))
+        ));
+    }
 }
@@ -0,0 +1,66 @@
+use crate::{split_line_ending, ConversionError, TextConverter};
+use std::{io::BufRead, str};
+
+/// Iterator over the converted lines of a file or reader, returned by
+/// [`TextConverter::lines_from_file`] and [`TextConverter::lines_from_reader`]
+///
+/// Each line is read and converted only when [`next`](Iterator::next) is called, so the output
+/// never has to be held in memory as a whole. Lines are yielded without their line ending, like
+/// [`BufRead::lines`], and a failing line does not stop the ones after it
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{converters::Uppercase, TextConverter};
+///
+/// let errors: Vec<_> = Uppercase
+///     .lines_from_reader("ok\nerror: disk full\r\nok\n".as_bytes())
+///     .filter_map(Result::ok)
+///     .filter(|line| line.starts_with("ERROR"))
+///     .collect();
+/// assert_eq!(vec!["ERROR: DISK FULL"], errors);
+/// ```
+pub struct ConvertedLines<'a, C: ?Sized, R> {
+    converter: &'a C,
+    reader: R,
+    line: Vec<u8>,
+    first: bool,
+}
+
+impl<'a, C: TextConverter + ?Sized, R: BufRead> ConvertedLines<'a, C, R> {
+    pub(crate) fn new(converter: &'a C, reader: R) -> Self {
+        Self {
+            converter,
+            reader,
+            line: Vec::new(),
+            first: true,
+        }
+    }
+
+    fn convert_line(&self) -> Result<String, ConversionError> {
+        let (content, _) = split_line_ending(str::from_utf8(&self.line)?);
+        let content = match self.first {
+            true => content.strip_prefix('\u{feff}').unwrap_or(content),
+            false => content,
+        };
+
+        self.converter.try_converter(content)
+    }
+}
+
+impl<C: TextConverter + ?Sized, R: BufRead> Iterator for ConvertedLines<'_, C, R> {
+    type Item = Result<String, ConversionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.line.clear();
+        match self.reader.read_until(b'\n', &mut self.line) {
+            Ok(0) => None,
+            Ok(_) => {
+                let line = self.convert_line();
+                self.first = false;
+                Some(line)
+            }
+            Err(e) => Some(Err(e.into())),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Reading files through memory maps
+//!
+//! Enabled by the `memmap2` feature
+
+use crate::ConversionError;
+use memmap2::Mmap;
+use std::{fs::File, path::Path};
+
+/// Maps the file at `path` into memory, read-only
+pub(crate) fn map(path: &Path) -> Result<Mmap, ConversionError> {
+    let file = File::open(path)?;
+    // SAFETY: the map is only ever read, and callers document that changing the file while it is
+    // mapped is undefined behavior, like every memory map
+    let map = unsafe { Mmap::map(&file)? };
+
+    // The file is read once from start to end
+    #[cfg(unix)]
+    let _ = map.advise(memmap2::Advice::Sequential);
+
+    Ok(map)
+}
@@ -0,0 +1,29 @@
+/// How a conversion handles input it cannot convert, like characters without a Morse code or
+/// that the target encoding cannot represent
+///
+/// Converters that take an [`Unmappable`](crate::converters::Unmappable) policy also take a
+/// mode, and [`EncodingOptions`](crate::EncodingOptions) uses it when decoding and encoding
+/// files
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{converters::Morse, ConversionMode, TextConverter};
+///
+/// assert!(Morse::new(ConversionMode::Strict).try_new_from_text("SOS#").is_err());
+/// assert_eq!("... --- ... ?", Morse::new(ConversionMode::Lossy).new_from_text("SOS#"));
+/// assert_eq!("... --- ...", Morse::new(ConversionMode::Skip).new_from_text("SOS#"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConversionMode {
+    /// Fail with [`ConversionError::InvalidInput`](crate::ConversionError::InvalidInput) on the
+    /// first input that cannot be converted
+    #[default]
+    Strict,
+    /// Substitute a replacement: `?` in converted text, and U+FFFD REPLACEMENT CHARACTER for
+    /// bytes that are not valid in the encoding they are decoded from
+    Lossy,
+    /// Leave what cannot be converted out of the output
+    Skip,
+}
@@ -0,0 +1,90 @@
+use crate::ConversionError;
+use alloc::{boxed::Box, sync::Arc};
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// Callbacks for the steps of a conversion, to plug in custom logging, metrics or progress
+/// displays
+///
+/// Attach observers to a [`Pipeline`](crate::pipeline::Pipeline) with
+/// [observe](crate::pipeline::Pipeline::observe()), or to a directory conversion with
+/// [`DirOptions::observe`](crate::DirOptions::observe). Every callback does nothing by default,
+/// so observers only implement the ones they need. Observers are shared between threads, so
+/// the callbacks take `&self`
+///
+/// Implemented for [`Arc`] and [`Box`] of observers, so an observer can be kept around to read
+/// what it collected
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::{atomic::{AtomicUsize, Ordering}, Arc};
+/// use text_converter::{converters::*, pipeline::Pipeline, ConversionObserver, TextConverter};
+///
+/// #[derive(Default)]
+/// struct Stages(AtomicUsize);
+///
+/// impl ConversionObserver for Stages {
+///     fn on_stage_complete(&self, _stage: usize, _output: &str) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let stages = Arc::new(Stages::default());
+/// let mut pipeline = Pipeline::new();
+/// pipeline.push(Trim).push(Uppercase).observe(Arc::clone(&stages));
+///
+/// assert_eq!("HELLO", pipeline.new_from_text("  hello  "));
+/// assert_eq!(2, stages.0.load(Ordering::Relaxed));
+/// ```
+pub trait ConversionObserver: Send + Sync {
+    /// Called before converting `input`
+    fn on_start(&self, _input: &str) {}
+
+    /// Called after stage number `stage` of a pipeline produced `output`, counting from 0
+    fn on_stage_complete(&self, _stage: usize, _output: &str) {}
+
+    /// Called when a conversion fails, before the error is returned or recorded
+    fn on_error(&self, _error: &ConversionError) {}
+
+    /// Called after the output of a conversion was written to the file at `path`
+    #[cfg(feature = "std")]
+    fn on_output_written(&self, _path: &Path) {}
+}
+
+macro_rules! impl_observer_for_pointer {
+    ($($pointer:ident),*) => {
+        $(
+            impl<T: ConversionObserver + ?Sized> ConversionObserver for $pointer<T> {
+                fn on_start(&self, input: &str) {
+                    (**self).on_start(input);
+                }
+
+                fn on_stage_complete(&self, stage: usize, output: &str) {
+                    (**self).on_stage_complete(stage, output);
+                }
+
+                fn on_error(&self, error: &ConversionError) {
+                    (**self).on_error(error);
+                }
+
+                #[cfg(feature = "std")]
+                fn on_output_written(&self, path: &Path) {
+                    (**self).on_output_written(path);
+                }
+            }
+        )*
+    };
+}
+
+impl_observer_for_pointer!(Arc, Box);
+
+/// Observers attached to something, notified in the order they were attached
+pub(crate) type Observers = alloc::vec::Vec<Arc<dyn ConversionObserver>>;
+
+/// Calls `notify` with each of `observers`
+pub(crate) fn notify(observers: &Observers, notify: impl Fn(&dyn ConversionObserver)) {
+    for observer in observers {
+        notify(observer.as_ref());
+    }
+}
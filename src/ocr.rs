@@ -0,0 +1,98 @@
+//! Recognizing text in clipboard images
+//!
+//! Enabled by the `ocr` feature. Recognition runs the `tesseract` command, which has to be
+//! installed and on the `PATH`
+
+use crate::ConversionError;
+use crate::TextClipboard;
+use arboard::ImageData;
+use std::{
+    io::{ErrorKind, Write},
+    process::{Command, Stdio},
+    thread,
+};
+
+/// Recognizes the text in the clipboard image, like a screenshot, with tesseract
+///
+/// Enabled by the `ocr` feature
+///
+/// # Errors
+/// - [`ConversionError::ClipboardUnavailable`] if there is no clipboard to get an image from
+/// - [`ConversionError::Clipboard`] if the clipboard has no image
+/// - [`ConversionError::Ocr`] if tesseract is not installed or fails
+pub fn recognize_clipboard_image() -> Result<String, ConversionError> {
+    recognize(&TextClipboard::new()?.get_image()?)
+}
+
+/// Runs tesseract on an image, returning the text it recognized
+fn recognize(image: &ImageData) -> Result<String, ConversionError> {
+    let mut tesseract = Command::new("tesseract")
+        .args(["stdin", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| match e.kind() {
+            ErrorKind::NotFound => ConversionError::Ocr("tesseract is not installed".to_owned()),
+            _ => e.into(),
+        })?;
+
+    let ppm = to_ppm(image);
+    let mut stdin = tesseract.stdin.take().expect("stdin is piped");
+    let (written, output) = thread::scope(|scope| {
+        // Written from another thread so that tesseract can't block on a full stdout meanwhile
+        let writer = scope.spawn(move || stdin.write_all(&ppm));
+        let output = tesseract.wait_with_output();
+        (
+            writer.join().expect("writing to tesseract panicked"),
+            output,
+        )
+    });
+
+    let output = output?;
+    if !output.status.success() {
+        let reason = String::from_utf8_lossy(&output.stderr);
+        return Err(ConversionError::Ocr(reason.trim().to_owned()));
+    }
+    written?;
+
+    let text = String::from_utf8(output.stdout).map_err(|e| e.utf8_error())?;
+    Ok(text.trim_end_matches(['\n', '\x0c']).to_owned())
+}
+
+/// Encodes RGBA pixels as a binary PPM image, which tesseract reads without extra libraries.
+/// Transparent pixels are blended onto white, the usual background of text
+fn to_ppm(image: &ImageData) -> Vec<u8> {
+    let mut ppm = format!("P6\n{} {}\n255\n", image.width, image.height).into_bytes();
+    ppm.reserve(image.width * image.height * 3);
+
+    for pixel in image.bytes.chunks_exact(4) {
+        let alpha = u16::from(pixel[3]);
+        ppm.extend(
+            pixel[..3]
+                .iter()
+                .map(|&c| ((u16::from(c) * alpha + 255 * (255 - alpha)) / 255) as u8),
+        );
+    }
+
+    ppm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn ppm_encoding() {
+        let image = ImageData {
+            width: 2,
+            height: 1,
+            bytes: Cow::Borrowed(&[10, 20, 30, 255, 0, 0, 0, 0]),
+        };
+        assert_eq!(
+            b"P6\n2 1\n255\n\x0a\x14\x1e\xff\xff\xff".as_slice(),
+            to_ppm(&image)
+        );
+    }
+}
@@ -0,0 +1,468 @@
+use crate::{atomic, Bom, Conversion, ConversionError, TextConverter};
+use std::{
+    borrow::Cow,
+    ffi::OsStr,
+    fmt, fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+/// What to do when the output file already exists
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverwritePolicy {
+    /// Replace the existing file
+    #[default]
+    Replace,
+    /// Keep the existing file and don't write the output
+    Skip,
+    /// Fail with [`ConversionError::Io`]
+    Fail,
+}
+
+/// Copy of the original file kept by
+/// [`TextConverter::try_convert_file_in_place_with`](crate::TextConverter::try_convert_file_in_place_with)
+/// before replacing it with the output
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use text_converter::Backup;
+///
+/// assert_eq!(Some("notes.md.bak".into()), Backup::default().path("notes.md"));
+/// assert_eq!(Some("old/notes.md".into()), Backup::Dir("old".into()).path("notes.md"));
+/// assert_eq!(None, Backup::None.path(Path::new("notes.md")));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Backup {
+    /// Replace the file without keeping a copy
+    None,
+    /// Copy it next to the original, with this appended to its name. `.bak` by default
+    Suffix(String),
+    /// Copy it with the same name into this directory, which is created if needed
+    Dir(PathBuf),
+}
+
+impl Backup {
+    /// Path the backup of `input` would be written to, if any
+    pub fn path(&self, input: impl AsRef<Path>) -> Option<PathBuf> {
+        let input = input.as_ref();
+        match self {
+            Self::None => None,
+            Self::Suffix(suffix) => {
+                let mut name = input.file_name().unwrap_or_default().to_os_string();
+                name.push(suffix);
+                Some(input.with_file_name(name))
+            }
+            Self::Dir(dir) => Some(dir.join(input.file_name().unwrap_or_default())),
+        }
+    }
+
+    /// Copies `input` to its backup path, returning that path
+    pub(crate) fn write(&self, input: &Path) -> Result<Option<PathBuf>, ConversionError> {
+        let Some(path) = self.path(input) else {
+            return Ok(None);
+        };
+        if let Self::Dir(dir) = self {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::copy(input, &path)?;
+        Ok(Some(path))
+    }
+}
+
+impl Default for Backup {
+    fn default() -> Self {
+        Self::Suffix(".bak".to_owned())
+    }
+}
+
+/// Controls where [`TextConverter::new_from_file_with`](crate::TextConverter::new_from_file_with)
+/// writes its output
+///
+/// By default the output goes next to the input file, named `originalname_converted` with the
+/// [extension of the converter](crate::TextConverter::output_extension), or the original
+/// extension if it has none
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use text_converter::{OutputOptions, OverwritePolicy};
+///
+/// let options = OutputOptions::new()
+///     .suffix(".upper")
+///     .extension("txt")
+///     .dir("out")
+///     .overwrite(OverwritePolicy::Fail);
+///
+/// assert_eq!(Path::new("out/notes.upper.txt"), options.output_path("notes/notes.md"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct OutputOptions {
+    suffix: String,
+    extension: Extension,
+    dir: Option<PathBuf>,
+    overwrite: OverwritePolicy,
+    write_file: bool,
+    keep_bom: bool,
+}
+
+impl OutputOptions {
+    /// Writes `originalname_converted` next to the input file, replacing it if it exists
+    pub fn new() -> Self {
+        Self {
+            suffix: "_converted".to_owned(),
+            extension: Extension::Converter,
+            dir: None,
+            overwrite: OverwritePolicy::default(),
+            write_file: true,
+            keep_bom: false,
+        }
+    }
+
+    /// Only converts, without writing any output file
+    pub fn no_file() -> Self {
+        Self::new().write_file(false)
+    }
+
+    /// Text appended to the input file stem
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Extension of the output file, without the leading dot. An empty extension leaves the
+    /// output without one
+    pub fn extension(mut self, extension: impl Into<String>) -> Self {
+        self.extension = Extension::Custom(extension.into());
+        self
+    }
+
+    /// Uses the extension of the input file for the output file, even when the converter has
+    /// an extension of its own
+    pub fn keep_extension(mut self) -> Self {
+        self.extension = Extension::Input;
+        self
+    }
+
+    /// Directory the output is written to, instead of the input file's directory
+    pub fn dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    /// What to do when the output file already exists
+    pub fn overwrite(mut self, overwrite: OverwritePolicy) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// Whether to write an output file at all
+    pub fn write_file(mut self, write_file: bool) -> Self {
+        self.write_file = write_file;
+        self
+    }
+
+    /// Whether to start the output with the [byte order mark](Bom) the input started with, in
+    /// the same encoding, so files from tools that expect one keep working. Off by default
+    pub fn keep_bom(mut self, keep_bom: bool) -> Self {
+        self.keep_bom = keep_bom;
+        self
+    }
+
+    /// Path the output for `input` would be written to
+    pub fn output_path(&self, input: impl AsRef<Path>) -> PathBuf {
+        let input = input.as_ref();
+        let mut name = input.file_stem().unwrap_or_default().to_os_string();
+        name.push(&self.suffix);
+
+        let extension = match &self.extension {
+            Extension::Custom(extension) => Some(extension.as_ref()),
+            Extension::Converter | Extension::Input => input.extension(),
+        };
+        if let Some(extension) = extension.filter(|e| !e.is_empty()) {
+            name.push(".");
+            name.push(extension);
+        }
+
+        match &self.dir {
+            Some(dir) => dir.join(name),
+            None => input.with_file_name(name),
+        }
+    }
+
+    /// These options with the default extension replaced by the converter's one, if it has one
+    pub(crate) fn for_converter<C: TextConverter + ?Sized>(&self, converter: &C) -> Self {
+        self.with_converter_extension(converter.output_extension())
+    }
+
+    /// These options with the default extension replaced by `extension`, if there is one
+    pub(crate) fn with_converter_extension(&self, extension: Option<&str>) -> Self {
+        let mut options = self.clone();
+        if let (Extension::Converter, Some(extension)) = (&options.extension, extension) {
+            options.extension = Extension::Custom(extension.to_owned());
+        }
+        options
+    }
+
+    /// Whether `path` looks like an output written with these options
+    pub(crate) fn is_output(&self, path: &Path) -> bool {
+        let in_dir = self.dir.as_ref().is_some_and(|dir| {
+            path.starts_with(dir) || fs::canonicalize(dir).is_ok_and(|dir| path.starts_with(dir))
+        });
+        let has_suffix = !self.suffix.is_empty()
+            && path
+                .file_stem()
+                .and_then(OsStr::to_str)
+                .is_some_and(|stem| stem.ends_with(&self.suffix));
+
+        in_dir || has_suffix
+    }
+
+    /// What [write_text](Self::write_text()) would write for `conversion` of `input`, without
+    /// touching the filesystem
+    pub(crate) fn plan_text(
+        &self,
+        input: &Path,
+        conversion: Conversion,
+        bom: Option<Bom>,
+    ) -> Result<Option<PlannedWrite>, ConversionError> {
+        if !self.write_file {
+            return Ok(None);
+        }
+
+        let size = with_bom(&conversion.output, bom.filter(|_| self.keep_bom)).len();
+        let planned = PlannedWrite::new(self.output_path(input), size, conversion);
+        match self.overwrite {
+            OverwritePolicy::Skip if planned.replaces => Ok(None),
+            OverwritePolicy::Fail if planned.replaces => Err(io::Error::new(
+                ErrorKind::AlreadyExists,
+                format!("{} already exists", planned.path.display()),
+            )
+            .into()),
+            _ => Ok(Some(planned)),
+        }
+    }
+
+    /// Writes the text output for `input`, with the byte order mark of the input if it had one
+    /// and it should be kept
+    pub(crate) fn write_text(
+        &self,
+        input: &Path,
+        output: &str,
+        bom: Option<Bom>,
+    ) -> Result<Option<PathBuf>, ConversionError> {
+        self.write(input, with_bom(output, bom.filter(|_| self.keep_bom)))
+    }
+
+    /// Writes the output for `input` [atomically](crate::write_atomic()), following the
+    /// overwrite policy
+    ///
+    /// Returns the path that was written to, if any
+    pub(crate) fn write(
+        &self,
+        input: &Path,
+        output: impl AsRef<[u8]>,
+    ) -> Result<Option<PathBuf>, ConversionError> {
+        if !self.write_file {
+            return Ok(None);
+        }
+
+        let path = self.output_path(input);
+        if let Some(dir) = &self.dir {
+            fs::create_dir_all(dir)?;
+        }
+
+        let replace = self.overwrite == OverwritePolicy::Replace;
+        match atomic::persist(&path, output.as_ref(), replace) {
+            Ok(()) => {}
+            Err(e)
+                if e.kind() == ErrorKind::AlreadyExists
+                    && self.overwrite == OverwritePolicy::Skip =>
+            {
+                return Ok(None)
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        Ok(Some(path))
+    }
+}
+
+/// A file a dry run would have written, from
+/// [`TextConverter::try_dry_run_file_with`](crate::TextConverter::try_dry_run_file_with) or a
+/// [dry run of a directory](crate::DirOptions::dry_run)
+///
+/// Displays as a one-line summary of the write
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlannedWrite {
+    /// Path the output would be written to
+    pub path: PathBuf,
+    /// Size of the output file in bytes
+    pub size: usize,
+    /// Whether a file already exists at the path and would be replaced
+    pub replaces: bool,
+    /// The input, the output and the diff between them
+    pub conversion: Conversion,
+}
+
+impl PlannedWrite {
+    pub(crate) fn new(path: PathBuf, size: usize, conversion: Conversion) -> Self {
+        Self {
+            replaces: path.exists(),
+            path,
+            size,
+            conversion,
+        }
+    }
+}
+
+impl fmt::Display for PlannedWrite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "would write {} bytes to {}",
+            self.size,
+            self.path.display()
+        )?;
+        match self.replaces {
+            true => f.write_str(", replacing it"),
+            false => Ok(()),
+        }
+    }
+}
+
+/// The bytes of `output`, in the encoding of `bom` and starting with it if there is one
+pub(crate) fn with_bom(output: &str, bom: Option<Bom>) -> Cow<'_, [u8]> {
+    match bom {
+        Some(bom) => Cow::Owned(bom.encode(output)),
+        None => Cow::Borrowed(output.as_bytes()),
+    }
+}
+
+/// Where the extension of output files comes from
+#[derive(Debug, Clone)]
+enum Extension {
+    /// The converter's, or the input file's if it has none
+    Converter,
+    Input,
+    Custom(String),
+}
+
+impl Default for OutputOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn output_paths() {
+        let options = OutputOptions::new();
+        assert_eq!(
+            Path::new("a/b_converted.txt"),
+            options.output_path("a/b.txt")
+        );
+
+        assert_eq!(
+            Path::new("./notes/my.file.v2_converted.txt"),
+            options.output_path("./notes/my.file.v2.txt")
+        );
+        assert_eq!(
+            Path::new("Makefile_converted"),
+            options.output_path("Makefile")
+        );
+
+        let options = OutputOptions::new().suffix("").extension("md").dir("out");
+        assert_eq!(Path::new("out/b.md"), options.output_path("a/b.txt"));
+
+        let options = OutputOptions::new().extension("");
+        assert_eq!(
+            Path::new("notes_converted"),
+            options.output_path("notes.txt")
+        );
+    }
+
+    #[test]
+    fn backups() {
+        let dir = env::temp_dir().join("text_converter_backup");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("input.txt");
+        fs::write(&input, "original").unwrap();
+
+        let backup = Backup::default().write(&input).unwrap().unwrap();
+        assert_eq!(dir.join("input.txt.bak"), backup);
+        assert_eq!("original", fs::read_to_string(backup).unwrap());
+
+        let backup = Backup::Dir(dir.join("old")).write(&input).unwrap().unwrap();
+        assert_eq!(dir.join("old/input.txt"), backup);
+        assert_eq!(None, Backup::None.write(&input).unwrap());
+    }
+
+    #[test]
+    fn dry_runs() {
+        let dir = env::temp_dir().join("text_converter_dry_run");
+        let _ = fs::remove_dir_all(&dir);
+        let input = dir.join("input.txt");
+        let options = OutputOptions::new().dir(&dir).keep_bom(true);
+
+        let planned = options
+            .plan_text(&input, Conversion::new("a", "b"), Some(Bom::Utf8))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            (dir.join("input_converted.txt"), 4),
+            (planned.path, planned.size)
+        );
+        assert!(!planned.replaces);
+        assert!(!dir.exists());
+
+        options.write(&input, "b").unwrap();
+        let fail = options.overwrite(OverwritePolicy::Fail);
+        assert!(matches!(
+            fail.plan_text(&input, Conversion::new("a", "b"), None),
+            Err(ConversionError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn overwrite_policies() {
+        let dir = env::temp_dir().join("text_converter_overwrite");
+        let _ = fs::remove_dir_all(&dir);
+        let input = dir.join("input.txt");
+        let options = OutputOptions::new().dir(&dir);
+
+        assert!(options.write(&input, "first").unwrap().is_some());
+
+        let skip = options.clone().overwrite(OverwritePolicy::Skip);
+        assert_eq!(None, skip.write(&input, "second").unwrap());
+        assert_eq!(
+            "first",
+            fs::read_to_string(options.output_path(&input)).unwrap()
+        );
+
+        let fail = options.clone().overwrite(OverwritePolicy::Fail);
+        assert!(matches!(
+            fail.write(&input, "third"),
+            Err(ConversionError::Io(_))
+        ));
+
+        assert!(options.write(&input, "fourth").unwrap().is_some());
+        assert_eq!(
+            "fourth",
+            fs::read_to_string(options.output_path(&input)).unwrap()
+        );
+
+        assert_eq!(
+            None,
+            OutputOptions::no_file().write(&input, "fifth").unwrap()
+        );
+    }
+}
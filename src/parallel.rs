@@ -0,0 +1,55 @@
+use crate::{ConversionError, TextConverter};
+use rayon::{prelude::*, ThreadPoolBuilder};
+use std::{io, path::PathBuf};
+
+/// Implementation of [`TextConverter::new_from_files_parallel`]
+#[allow(clippy::type_complexity)]
+pub(crate) fn convert_files<C: TextConverter + Sync + ?Sized>(
+    converter: &C,
+    paths: Vec<PathBuf>,
+    threads: usize,
+) -> Result<Vec<(PathBuf, Result<String, ConversionError>)>, ConversionError> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .map_err(io::Error::other)?;
+
+    Ok(pool.install(|| {
+        paths
+            .into_par_iter()
+            .map(|path| {
+                let result = converter.try_new_from_file(&path);
+                (path, result)
+            })
+            .collect()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, Uppercase};
+    use std::fs;
+
+    #[test]
+    fn results_keep_input_order() {
+        let dir = fixtures::temp_dir("parallel");
+
+        let paths: Vec<_> = (0..20).map(|i| dir.join(format!("{i}.txt"))).collect();
+        for (i, path) in paths.iter().enumerate() {
+            fs::write(path, format!("file {i}")).unwrap();
+        }
+        let missing = dir.join("missing.txt");
+
+        let results = Uppercase
+            .new_from_files_parallel(paths.iter().chain([&missing]), 4)
+            .unwrap();
+
+        assert_eq!(21, results.len());
+        for (i, (path, result)) in results.iter().take(20).enumerate() {
+            assert_eq!(&paths[i], path);
+            assert_eq!(&format!("FILE {i}"), result.as_ref().unwrap());
+        }
+        assert!(matches!(results[20], (_, Err(ConversionError::Io(_)))));
+    }
+}
@@ -0,0 +1,514 @@
+//! Chaining several converters into a single one
+//!
+//! Tuples of converters are converters themselves: each element is applied in order, with the
+//! output of one becoming the input of the next. Since the tuple implements [`TextConverter`],
+//! every input source (text, clipboard, file) is available on the whole pipeline at once
+//!
+//! Slices and vectors of converters work the same way, for pipelines that are only known at
+//! runtime. Use [`DynTextConverter`] trait objects to mix different converters in them, or a
+//! [`Pipeline`], which can also be loaded from a config file. [`ConverterExt`] adds combinators
+//! to build pipelines with method calls, like `Trim.then(Uppercase)`
+//!
+//! # Examples
+//!
+//! ```
+//! use text_converter::TextConverter;
+//!
+//! struct Trim;
+//! struct Uppercase;
+//!
+//! impl TextConverter for Trim {
+//!     fn converter(&self, input: impl AsRef<str>) -> String {
+//!         input.as_ref().trim().to_owned()
+//!     }
+//! }
+//!
+//! impl TextConverter for Uppercase {
+//!     fn converter(&self, input: impl AsRef<str>) -> String {
+//!         input.as_ref().to_uppercase()
+//!     }
+//! }
+//!
+//! let shout = (Trim, Uppercase).new_from_text("  hello world  ");
+//! assert_eq!("HELLO WORLD", shout);
+//! ```
+
+#[cfg(feature = "config")]
+use crate::ConverterRegistry;
+use crate::{
+    observer::{self, Observers},
+    trace::{self, timed},
+    ConversionError, ConversionObserver, DynTextConverter, PerLine, ReversibleConverter,
+    TextConverter,
+};
+use alloc::{borrow::Cow, borrow::ToOwned, string::String, sync::Arc, vec::Vec};
+use core::fmt;
+#[cfg(feature = "config")]
+use std::path::Path;
+
+macro_rules! impl_pipeline {
+    ($first:ident $(, $rest:ident)*) => {
+        impl<$first: TextConverter, $($rest: TextConverter),*> TextConverter for ($first, $($rest,)*) {
+            #[allow(non_snake_case)]
+            fn converter(&self, input: impl AsRef<str>) -> String {
+                let ($first, $($rest,)*) = self;
+                let output = $first.converter(input);
+                $(let output = $rest.converter(output);)*
+                output
+            }
+
+            #[allow(non_snake_case)]
+            fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+                let ($first, $($rest,)*) = self;
+                let output = $first.try_converter(input)?;
+                $(let output = $rest.try_converter(output)?;)*
+                Ok(output)
+            }
+
+            // The last stage with an extension decides the format of the output
+            #[allow(non_snake_case)]
+            fn output_extension(&self) -> Option<&str> {
+                let ($first, $($rest,)*) = self;
+                let extension = $first.output_extension();
+                $(let extension = $rest.output_extension().or(extension);)*
+                extension
+            }
+        }
+
+        impl<$first: ReversibleConverter, $($rest: ReversibleConverter),*> ReversibleConverter
+            for ($first, $($rest,)*)
+        {
+            // Undoes the stages from last to first
+            #[allow(non_snake_case)]
+            fn deconverter(&self, input: impl AsRef<str>) -> String {
+                let ($first, $($rest,)*) = self;
+                decode_nested!(input; $first $(, $rest)*)
+            }
+
+            #[allow(non_snake_case)]
+            fn try_deconverter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+                let ($first, $($rest,)*) = self;
+                Ok(try_decode_nested!(input; $first $(, $rest)*))
+            }
+        }
+    };
+}
+
+/// Expands to `a.deconverter(b.deconverter(c.deconverter(input)))`
+macro_rules! decode_nested {
+    ($input:expr; $stage:ident) => {
+        $stage.deconverter($input)
+    };
+    ($input:expr; $stage:ident, $($rest:ident),+) => {
+        $stage.deconverter(decode_nested!($input; $($rest),+))
+    };
+}
+
+/// Expands to `a.try_deconverter(b.try_deconverter(c.try_deconverter(input)?)?)?`
+macro_rules! try_decode_nested {
+    ($input:expr; $stage:ident) => {
+        $stage.try_deconverter($input)?
+    };
+    ($input:expr; $stage:ident, $($rest:ident),+) => {
+        $stage.try_deconverter(try_decode_nested!($input; $($rest),+))?
+    };
+}
+
+impl<T: TextConverter> TextConverter for [T] {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        timed!("pipeline", stages = self.len());
+        self.iter()
+            .enumerate()
+            .fold(input.as_ref().to_owned(), |output, (index, stage)| {
+                trace::stage(index, &output, |input| stage.converter(input))
+            })
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        timed!("pipeline", stages = self.len());
+        self.iter()
+            .enumerate()
+            .try_fold(input.as_ref().to_owned(), |output, (index, stage)| {
+                trace::stage(index, &output, |input| stage.try_converter(input))
+            })
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        self.iter().rev().find_map(TextConverter::output_extension)
+    }
+}
+
+impl<T: TextConverter> TextConverter for Vec<T> {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.as_slice().converter(input)
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.as_slice().try_converter(input)
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        self.as_slice().output_extension()
+    }
+}
+
+impl<T: ReversibleConverter> ReversibleConverter for [T] {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        self.iter()
+            .rev()
+            .fold(input.as_ref().to_owned(), |output, stage| {
+                stage.deconverter(output)
+            })
+    }
+
+    fn try_deconverter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.iter()
+            .rev()
+            .try_fold(input.as_ref().to_owned(), |output, stage| {
+                stage.try_deconverter(output)
+            })
+    }
+}
+
+impl<T: ReversibleConverter> ReversibleConverter for Vec<T> {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        self.as_slice().deconverter(input)
+    }
+
+    fn try_deconverter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.as_slice().try_deconverter(input)
+    }
+}
+
+/// Pipeline of shared converters, built at runtime or loaded from a config file
+///
+/// Cloning a pipeline is cheap, since its stages are shared between the clones
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{converters::*, pipeline::Pipeline, TextConverter};
+///
+/// let mut pipeline = Pipeline::new();
+/// pipeline.push(Trim).push(Uppercase);
+/// assert_eq!("HELLO", pipeline.new_from_text("  hello  "));
+/// ```
+#[derive(Clone, Default)]
+pub struct Pipeline {
+    stages: Vec<Arc<dyn DynTextConverter + Send + Sync>>,
+    observers: Observers,
+}
+
+impl Pipeline {
+    /// Creates a pipeline without any stage, which leaves text unchanged
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the pipeline described by the TOML or YAML file at `path`, with the converters of
+    /// [`ConverterRegistry::with_builtins`]
+    ///
+    /// Enabled by the `config` feature. The format is picked from the extension, `.toml`,
+    /// `.yaml` or `.yml`. The file lists the steps in order, each naming a converter and
+    /// optionally overriding some of its fields:
+    ///
+    /// ```toml
+    /// [[steps]]
+    /// converter = "trim-lines"
+    ///
+    /// [[steps]]
+    /// converter = "wrap"
+    /// width = 72
+    /// ```
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if the file is inaccessible
+    /// - [`ConversionError::Syntax`] if it is not valid TOML or YAML, or does not list steps
+    /// - [`ConversionError::UnknownConverter`] if a step names a converter that does not exist
+    /// - [`ConversionError::InvalidInput`] if the extension is not a known one, or the
+    ///   parameters of a step do not fit its converter
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::{pipeline::Pipeline, TextConverter};
+    ///
+    /// let cleanup = Pipeline::from_config("cleanup.toml").unwrap();
+    /// cleanup.new_from_file("notes.md");
+    /// ```
+    #[cfg(feature = "config")]
+    pub fn from_config(path: impl AsRef<Path>) -> Result<Self, ConversionError> {
+        Self::from_config_with(path, &ConverterRegistry::with_builtins())
+    }
+
+    /// Same as [from_config](Self::from_config()), resolving converter names in `registry`
+    ///
+    /// # Errors
+    /// Same as [from_config](Self::from_config())
+    #[cfg(feature = "config")]
+    pub fn from_config_with(
+        path: impl AsRef<Path>,
+        registry: &ConverterRegistry,
+    ) -> Result<Self, ConversionError> {
+        crate::config::load(path.as_ref(), registry)
+    }
+
+    /// Adds a stage at the end of the pipeline
+    pub fn push(&mut self, converter: impl TextConverter + Send + Sync + 'static) -> &mut Self {
+        self.stages.push(Arc::new(converter));
+        self
+    }
+
+    /// Adds a stage that is already shared, like one taken from a registry
+    pub fn push_shared(&mut self, converter: Arc<dyn DynTextConverter + Send + Sync>) -> &mut Self {
+        self.stages.push(converter);
+        self
+    }
+
+    /// Notifies `observer` of every conversion run by the pipeline, after the observers
+    /// already attached. Clones of the pipeline share it, see [`ConversionObserver`]
+    pub fn observe(&mut self, observer: impl ConversionObserver + 'static) -> &mut Self {
+        self.observers.push(Arc::new(observer));
+        self
+    }
+
+    /// Number of stages
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Whether there are no stages
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+}
+
+impl TextConverter for Pipeline {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        timed!("pipeline", stages = self.len());
+        observer::notify(&self.observers, |o| o.on_start(input.as_ref()));
+        self.stages
+            .iter()
+            .enumerate()
+            .fold(input.as_ref().to_owned(), |output, (index, stage)| {
+                let output = trace::stage(index, &output, |input| stage.convert(input));
+                observer::notify(&self.observers, |o| o.on_stage_complete(index, &output));
+                output
+            })
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        timed!("pipeline", stages = self.len());
+        observer::notify(&self.observers, |o| o.on_start(input.as_ref()));
+        self.stages
+            .iter()
+            .enumerate()
+            .try_fold(input.as_ref().to_owned(), |output, (index, stage)| {
+                let output = trace::stage(index, &output, |input| stage.try_convert(input))?;
+                observer::notify(&self.observers, |o| o.on_stage_complete(index, &output));
+                Ok(output)
+            })
+            .inspect_err(|e| observer::notify(&self.observers, |o| o.on_error(e)))
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        self.stages.iter().rev().find_map(|stage| stage.extension())
+    }
+}
+
+impl fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pipeline")
+            .field("stages", &self.stages.len())
+            .field("observers", &self.observers.len())
+            .finish()
+    }
+}
+
+/// Combinators building pipelines in code, without a struct per combination
+///
+/// Implemented for every [`TextConverter`]. Each combinator wraps the converter into a new one,
+/// so they chain together and the result has every input source
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{converters::*, ConverterExt, TextConverter};
+///
+/// let shout = Trim
+///     .then(Uppercase)
+///     .map(|output| output + "!")
+///     .when(|input| input.len() < 1000)
+///     .tee(|output| println!("converted to {output}"));
+/// assert_eq!("HELLO!", shout.new_from_text("  hello "));
+/// ```
+pub trait ConverterExt: TextConverter + Sized {
+    /// Converts with this converter, then with `next`. Same as the pipeline `(self, next)`
+    fn then<N: TextConverter>(self, next: N) -> (Self, N) {
+        (self, next)
+    }
+
+    /// Transforms the output of this converter with `f`
+    fn map<F: Fn(String) -> String>(self, f: F) -> Map<Self, F> {
+        Map(self, f)
+    }
+
+    /// Only converts inputs for which `predicate` is true, returning the others unchanged
+    fn when<P: Fn(&str) -> bool>(self, predicate: P) -> When<Self, P> {
+        When(self, predicate)
+    }
+
+    /// Calls `f` with every output of this converter, like for logging, and passes it on
+    /// unchanged. Failed conversions are not passed to `f`
+    fn tee<F: Fn(&str)>(self, f: F) -> Tee<Self, F> {
+        Tee(self, f)
+    }
+
+    /// Applies this converter to each line on its own, keeping the line endings
+    fn per_line(self) -> PerLine<Self> {
+        PerLine(self)
+    }
+}
+
+impl<T: TextConverter> ConverterExt for T {}
+
+/// Converter whose output is transformed by a function, from [`ConverterExt::map`]
+#[derive(Clone, Copy)]
+pub struct Map<C, F>(C, F);
+
+impl<C: TextConverter, F: Fn(String) -> String> TextConverter for Map<C, F> {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        (self.1)(self.0.converter(input))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        Ok((self.1)(self.0.try_converter(input)?))
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        self.0.output_extension()
+    }
+}
+
+/// Converter that only runs on inputs matching a predicate, from [`ConverterExt::when`]
+#[derive(Clone, Copy)]
+pub struct When<C, P>(C, P);
+
+impl<C: TextConverter, P: Fn(&str) -> bool> TextConverter for When<C, P> {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.converter_cow(input.as_ref()).into_owned()
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        Ok(self.try_converter_cow(input.as_ref())?.into_owned())
+    }
+
+    fn converter_cow<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        match (self.1)(input) {
+            true => self.0.converter_cow(input),
+            false => Cow::Borrowed(input),
+        }
+    }
+
+    fn try_converter_cow<'a>(&self, input: &'a str) -> Result<Cow<'a, str>, ConversionError> {
+        match (self.1)(input) {
+            true => self.0.try_converter_cow(input),
+            false => Ok(Cow::Borrowed(input)),
+        }
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        self.0.output_extension()
+    }
+}
+
+/// Converter that shows its outputs to a function, from [`ConverterExt::tee`]
+#[derive(Clone, Copy)]
+pub struct Tee<C, F>(C, F);
+
+impl<C: TextConverter, F: Fn(&str)> TextConverter for Tee<C, F> {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let output = self.0.converter(input);
+        (self.1)(&output);
+        output
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let output = self.0.try_converter(input)?;
+        (self.1)(&output);
+        Ok(output)
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        self.0.output_extension()
+    }
+}
+
+impl_pipeline!(A);
+impl_pipeline!(A, B);
+impl_pipeline!(A, B, C);
+impl_pipeline!(A, B, C, D);
+impl_pipeline!(A, B, C, D, E);
+impl_pipeline!(A, B, C, D, E, F);
+impl_pipeline!(A, B, C, D, E, F, G);
+impl_pipeline!(A, B, C, D, E, F, G, H);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Trim;
+    struct Reverse;
+    struct Exclaim;
+
+    impl TextConverter for Trim {
+        fn converter(&self, input: impl AsRef<str>) -> String {
+            input.as_ref().trim().to_owned()
+        }
+    }
+
+    impl TextConverter for Reverse {
+        fn converter(&self, input: impl AsRef<str>) -> String {
+            input.as_ref().chars().rev().collect()
+        }
+    }
+
+    impl TextConverter for Exclaim {
+        fn converter(&self, input: impl AsRef<str>) -> String {
+            input.as_ref().to_owned() + "!"
+        }
+    }
+
+    #[test]
+    fn stages_run_in_order() {
+        assert_eq!("olleh!", (Trim, Reverse, Exclaim).new_from_text("  hello "));
+        assert_eq!("!olleh", (Trim, Exclaim, Reverse).new_from_text("  hello "));
+    }
+
+    #[test]
+    fn runtime_pipeline() {
+        let stages: Vec<Box<dyn crate::DynTextConverter>> =
+            vec![Box::new(Trim), Box::new(Reverse), Box::new(Exclaim)];
+        assert_eq!("olleh!", stages.new_from_text("  hello "));
+        assert_eq!("  hello ", Vec::<Trim>::new().new_from_text("  hello "));
+    }
+
+    #[test]
+    fn single_stage_pipeline() {
+        assert_eq!("olleh", (Reverse,).new_from_text("hello"));
+    }
+
+    #[test]
+    fn combinators() {
+        let seen = std::cell::RefCell::new(Vec::new());
+        let converter = Trim
+            .then(Reverse)
+            .map(|output| output.to_uppercase())
+            .when(|input| !input.starts_with('#'))
+            .tee(|output| seen.borrow_mut().push(output.to_owned()));
+
+        assert_eq!("OLLEH", converter.new_from_text(" hello "));
+        assert_eq!("# keep", converter.new_from_text("# keep"));
+        assert_eq!(vec!["OLLEH", "# keep"], *seen.borrow());
+    }
+}
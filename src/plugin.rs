@@ -0,0 +1,377 @@
+//! Loading third-party converters from shared libraries at runtime
+//!
+//! Enabled by the `plugins` feature. A plugin is a shared library (`.so`, `.dylib` or `.dll`)
+//! exporting a function named [`ENTRY_POINT`] of type [`PluginEntryPoint`], which describes the
+//! converters it provides through a [`PluginDeclaration`]. Only C types cross the boundary, so
+//! plugins do not have to be built with the same compiler as this crate, or be written in Rust
+//! at all. [`ConverterRegistry::load_plugins`] registers the converters of every plugin found
+//! in a directory
+//!
+//! # Examples
+//!
+//! A plugin, built as a `cdylib`:
+//!
+//! ```no_run
+//! use std::{ffi::c_char, ptr};
+//! use text_converter::plugin::{PluginBuffer, PluginConverter, PluginDeclaration, ABI_VERSION};
+//!
+//! unsafe extern "C" fn shout(input: *const u8, len: usize, output: *mut PluginBuffer) -> bool {
+//!     let input = std::str::from_utf8_unchecked(std::slice::from_raw_parts(input, len));
+//!     let mut shouted = input.to_uppercase().into_bytes().into_boxed_slice();
+//!     *output = PluginBuffer { data: shouted.as_mut_ptr(), len: shouted.len() };
+//!     std::mem::forget(shouted);
+//!     true
+//! }
+//!
+//! unsafe extern "C" fn free(buffer: PluginBuffer) {
+//!     drop(Box::from_raw(ptr::slice_from_raw_parts_mut(buffer.data, buffer.len)));
+//! }
+//!
+//! static CONVERTERS: [PluginConverter; 1] = [PluginConverter {
+//!     name: c"shout".as_ptr() as *const c_char,
+//!     convert: shout,
+//!     free,
+//! }];
+//! static DECLARATION: PluginDeclaration = PluginDeclaration {
+//!     abi_version: ABI_VERSION,
+//!     converters: CONVERTERS.as_ptr(),
+//!     count: CONVERTERS.len(),
+//! };
+//!
+//! #[no_mangle]
+//! pub extern "C" fn text_converter_plugin() -> *const PluginDeclaration {
+//!     &DECLARATION
+//! }
+//! ```
+
+use crate::{ConversionError, ConverterRegistry, TextConverter};
+use libloading::Library;
+use std::{
+    env::consts::DLL_EXTENSION,
+    ffi::{c_char, CStr, OsStr},
+    fs,
+    path::Path,
+    slice, str,
+    sync::Arc,
+};
+
+/// Name of the function every plugin exports
+pub const ENTRY_POINT: &str = "text_converter_plugin";
+
+/// Version of the plugin interface described here. Plugins declaring another version are
+/// rejected
+pub const ABI_VERSION: u32 = 1;
+
+/// Type of the [`ENTRY_POINT`] function
+///
+/// It returns a declaration that stays valid for as long as the library is loaded
+pub type PluginEntryPoint = unsafe extern "C" fn() -> *const PluginDeclaration;
+
+/// Bytes allocated by a plugin, given back to it to be freed
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct PluginBuffer {
+    /// Start of the bytes
+    pub data: *mut u8,
+    /// Number of bytes
+    pub len: usize,
+}
+
+/// A converter provided by a plugin
+///
+/// The functions may be called from several threads at the same time
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct PluginConverter {
+    /// Name to register the converter under, as a NUL-terminated UTF-8 string
+    pub name: *const c_char,
+    /// Converts the UTF-8 input of `len` bytes, writing the output into `output` and returning
+    /// `true`. On failure, it writes an error message there instead and returns `false`. Both
+    /// must be UTF-8
+    pub convert:
+        unsafe extern "C" fn(input: *const u8, len: usize, output: *mut PluginBuffer) -> bool,
+    /// Frees a buffer written by `convert`
+    pub free: unsafe extern "C" fn(buffer: PluginBuffer),
+}
+
+// SAFETY: the plugin interface requires converters to be callable from any thread, and their
+// names to never change
+unsafe impl Send for PluginConverter {}
+unsafe impl Sync for PluginConverter {}
+
+/// What a plugin provides, returned by its [`ENTRY_POINT`]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct PluginDeclaration {
+    /// Must be [`ABI_VERSION`]
+    pub abi_version: u32,
+    /// Start of an array of `count` converters
+    pub converters: *const PluginConverter,
+    /// Number of converters
+    pub count: usize,
+}
+
+// SAFETY: declarations are only read, and the converters they point to are Sync
+unsafe impl Send for PluginDeclaration {}
+unsafe impl Sync for PluginDeclaration {}
+
+/// A [`PluginConverter`], along with the library it comes from so that it stays loaded
+struct Plugin {
+    converter: PluginConverter,
+    _library: Option<Arc<Library>>,
+}
+
+impl TextConverter for Plugin {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Plugin failed to convert: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let input = input.as_ref();
+        let mut buffer = PluginBuffer {
+            data: std::ptr::null_mut(),
+            len: 0,
+        };
+
+        // SAFETY: the plugin only reads `len` bytes of the input, and writes a buffer it owns
+        let converted =
+            unsafe { (self.converter.convert)(input.as_ptr(), input.len(), &mut buffer) };
+        let text = match buffer.data.is_null() {
+            true => Ok(String::new()),
+            // SAFETY: the buffer was just written by the plugin and is freed right after
+            false => str::from_utf8(unsafe { slice::from_raw_parts(buffer.data, buffer.len) })
+                .map(str::to_owned),
+        };
+        if !buffer.data.is_null() {
+            // SAFETY: the buffer comes from this plugin and is not used anymore
+            unsafe { (self.converter.free)(buffer) };
+        }
+
+        match (converted, text) {
+            (true, text) => Ok(text?),
+            (false, message) => {
+                Err(ConversionError::Plugin(message.unwrap_or_else(|_| {
+                    "the plugin failed to convert".to_owned()
+                })))
+            }
+        }
+    }
+}
+
+impl ConverterRegistry {
+    /// Loads every plugin in `dir` and registers its converters under the names they declare,
+    /// replacing converters already registered under them
+    ///
+    /// Enabled by the `plugins` feature. Plugins are the files with the shared library extension
+    /// of the platform, loaded in alphabetical order, see the [`plugin`](crate::plugin) module
+    ///
+    /// # Safety
+    /// Loading a library runs its initialization code, and calling its converters runs its
+    /// code too. Only load plugins that are trusted and follow the plugin interface
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if `dir` cannot be read
+    /// - [`ConversionError::Plugin`] if a library cannot be loaded, has no [`ENTRY_POINT`] or
+    ///   declares another [`ABI_VERSION`], or a converter name is not valid UTF-8
+    ///
+    /// # Returns
+    /// Names of the converters registered, in the order they were loaded
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use text_converter::ConverterRegistry;
+    ///
+    /// let mut registry = ConverterRegistry::with_builtins();
+    /// // SAFETY: the plugins directory only holds trusted plugins
+    /// let names = unsafe { registry.load_plugins("plugins") }.unwrap();
+    /// println!("loaded {}", names.join(", "));
+    /// ```
+    pub unsafe fn load_plugins(
+        &mut self,
+        dir: impl AsRef<Path>,
+    ) -> Result<Vec<String>, ConversionError> {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_file() && path.extension() == Some(OsStr::new(DLL_EXTENSION)) {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut names = Vec::new();
+        for path in paths {
+            names.extend(self.load_plugin(&path)?);
+        }
+
+        Ok(names)
+    }
+
+    /// Loads the plugin at `path` and registers its converters, like
+    /// [load_plugins](Self::load_plugins()) does for a whole directory
+    ///
+    /// # Safety
+    /// Same as [load_plugins](Self::load_plugins())
+    ///
+    /// # Errors
+    /// Same as [load_plugins](Self::load_plugins()), except for reading the directory
+    pub unsafe fn load_plugin(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<Vec<String>, ConversionError> {
+        let path = path.as_ref();
+        let plugin_error =
+            |e: libloading::Error| ConversionError::Plugin(format!("{}: {e}", path.display()));
+
+        let library = Arc::new(Library::new(path).map_err(plugin_error)?);
+        let declaration = {
+            let entry_point = library
+                .get::<PluginEntryPoint>(ENTRY_POINT.as_bytes())
+                .map_err(plugin_error)?;
+            entry_point()
+        };
+
+        self.register_declaration(declaration, Some(library))
+            .map_err(|e| ConversionError::Plugin(format!("{}: {e}", path.display())))
+    }
+
+    /// Registers the converters of `declaration`, keeping `library` loaded while they live
+    unsafe fn register_declaration(
+        &mut self,
+        declaration: *const PluginDeclaration,
+        library: Option<Arc<Library>>,
+    ) -> Result<Vec<String>, String> {
+        let declaration = declaration.as_ref().ok_or("the plugin declared nothing")?;
+        if declaration.abi_version != ABI_VERSION {
+            return Err(format!(
+                "the plugin was built for version {} of the plugin interface, not {ABI_VERSION}",
+                declaration.abi_version
+            ));
+        }
+
+        let converters = match declaration.count {
+            0 => &[][..],
+            _ if declaration.converters.is_null() => {
+                return Err("the plugin declared converters it does not have".to_owned())
+            }
+            count if count > isize::MAX as usize / size_of::<PluginConverter>() => {
+                return Err(format!("the plugin declared too many converters: {count}"))
+            }
+            count => slice::from_raw_parts(declaration.converters, count),
+        };
+        // Every name is checked before any converter is registered, so a plugin that fails to
+        // load leaves the registry as it was
+        let mut names = Vec::with_capacity(converters.len());
+        for converter in converters {
+            if converter.name.is_null() {
+                return Err("a converter has no name".to_owned());
+            }
+            let name = CStr::from_ptr(converter.name)
+                .to_str()
+                .map_err(|_| "a converter name is not valid UTF-8")?;
+            names.push(name.to_owned());
+        }
+
+        for (name, &converter) in names.iter().zip(converters) {
+            self.register(
+                name.clone(),
+                Plugin {
+                    converter,
+                    _library: library.clone(),
+                },
+            );
+        }
+
+        Ok(names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    unsafe extern "C" fn reverse(input: *const u8, len: usize, output: *mut PluginBuffer) -> bool {
+        let input = str::from_utf8(slice::from_raw_parts(input, len)).unwrap();
+        let (result, ok) = match input.is_empty() {
+            true => ("nothing to reverse".to_owned(), false),
+            false => (input.chars().rev().collect(), true),
+        };
+
+        let mut bytes = result.into_bytes().into_boxed_slice();
+        *output = PluginBuffer {
+            data: bytes.as_mut_ptr(),
+            len: bytes.len(),
+        };
+        std::mem::forget(bytes);
+        ok
+    }
+
+    unsafe extern "C" fn free(buffer: PluginBuffer) {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+            buffer.data,
+            buffer.len,
+        )));
+    }
+
+    #[test]
+    fn registers_declared_converters() {
+        let converters = [PluginConverter {
+            name: c"plugin-reverse".as_ptr(),
+            convert: reverse,
+            free,
+        }];
+        let mut declaration = PluginDeclaration {
+            abi_version: ABI_VERSION,
+            converters: converters.as_ptr(),
+            count: converters.len(),
+        };
+
+        let mut registry = ConverterRegistry::new();
+        let names = unsafe { registry.register_declaration(&declaration, None) }.unwrap();
+        assert_eq!(vec!["plugin-reverse"], names);
+        assert_eq!("cba", registry.convert("plugin-reverse", "abc").unwrap());
+        assert!(matches!(
+            registry.convert("plugin-reverse", ""),
+            Err(ConversionError::Plugin(message)) if message == "nothing to reverse"
+        ));
+
+        let invalid = [
+            PluginConverter {
+                name: c"plugin-first".as_ptr(),
+                convert: reverse,
+                free,
+            },
+            PluginConverter {
+                name: c"plugin-\xff".as_ptr(),
+                convert: reverse,
+                free,
+            },
+        ];
+        let invalid_declaration = PluginDeclaration {
+            abi_version: ABI_VERSION,
+            converters: invalid.as_ptr(),
+            count: invalid.len(),
+        };
+        assert!(unsafe { registry.register_declaration(&invalid_declaration, None) }.is_err());
+        assert!(!registry.contains("plugin-first"));
+
+        let missing = PluginDeclaration {
+            abi_version: ABI_VERSION,
+            converters: ptr::null(),
+            count: 1,
+        };
+        assert!(unsafe { registry.register_declaration(&missing, None) }.is_err());
+        let oversized = PluginDeclaration {
+            count: usize::MAX,
+            ..declaration
+        };
+        assert!(unsafe { registry.register_declaration(&oversized, None) }.is_err());
+
+        declaration.abi_version = ABI_VERSION + 1;
+        assert!(unsafe { registry.register_declaration(&declaration, None) }.is_err());
+        assert!(unsafe { registry.load_plugin("missing.so") }.is_err());
+    }
+}
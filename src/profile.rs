@@ -0,0 +1,388 @@
+#[cfg(feature = "clipboard")]
+use crate::TextClipboard;
+use crate::{
+    config::{self, Step},
+    pipeline::Pipeline,
+    trace, write_atomic, write_to_stdout, Bom, ConversionError, ConverterRegistry, TextConverter,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::{
+    env,
+    ffi::OsStr,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable naming the directory profiles are saved in, instead of the
+/// `text_converter/profiles` directory of the user's config directory
+pub const PROFILES_DIR_VAR: &str = "TEXT_CONVERTER_PROFILES";
+
+/// Where a [`Profile`] reads the text to convert
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProfileInput {
+    /// The whole standard input
+    #[default]
+    Stdin,
+    /// The text in the clipboard
+    #[cfg(feature = "clipboard")]
+    Clipboard,
+    /// The contents of a file
+    File(PathBuf),
+}
+
+/// Where a [`Profile`] writes the output
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProfileOutput {
+    /// The standard output
+    #[default]
+    Stdout,
+    /// The clipboard
+    #[cfg(feature = "clipboard")]
+    Clipboard,
+    /// A file, replaced atomically if it exists
+    File(PathBuf),
+    /// The input file itself, keeping a [backup](crate::Backup::default()) of it
+    InPlace,
+}
+
+/// A pipeline saved under a name with where it reads from and writes to, for conversions done
+/// over and over
+///
+/// Enabled by the `config` feature. Profiles are TOML files named after the profile, in
+/// [`Profile::dir`]:
+///
+/// ```toml
+/// input = { file = "logs/today.log" }
+/// output = "in-place"
+///
+/// [[steps]]
+/// converter = "trim-lines"
+///
+/// [[steps]]
+/// converter = "dedup-lines"
+/// ```
+///
+/// # Examples
+///
+/// ```no_run
+/// use text_converter::{Profile, ProfileInput, ProfileOutput};
+///
+/// Profile::new(ProfileInput::File("logs/today.log".into()), ProfileOutput::InPlace)
+///     .step("trim-lines")
+///     .step("dedup-lines")
+///     .save("clean-logs")
+///     .unwrap();
+///
+/// Profile::run("clean-logs").unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    #[serde(default)]
+    input: ProfileInput,
+    #[serde(default)]
+    output: ProfileOutput,
+    steps: Vec<Step>,
+}
+
+impl Profile {
+    /// Creates a profile converting `input` into `output`, without any step yet
+    pub fn new(input: ProfileInput, output: ProfileOutput) -> Self {
+        Self {
+            input,
+            output,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Adds a step running the built-in converter named `converter`
+    pub fn step(self, converter: impl Into<String>) -> Self {
+        self.step_with(converter, Map::new())
+    }
+
+    /// Adds a step running the converter named `converter` with its fields overridden by
+    /// `params`, like in a [pipeline config file](Pipeline::from_config())
+    pub fn step_with(mut self, converter: impl Into<String>, params: Map<String, Value>) -> Self {
+        self.steps.push(Step {
+            converter: converter.into(),
+            params,
+        });
+        self
+    }
+
+    /// Where the profile reads the text to convert
+    pub fn input(&self) -> &ProfileInput {
+        &self.input
+    }
+
+    /// Where the profile writes the output
+    pub fn output(&self) -> &ProfileOutput {
+        &self.output
+    }
+
+    /// Directory profiles are saved in: [`PROFILES_DIR_VAR`] if it is set, or
+    /// `text_converter/profiles` in `$XDG_CONFIG_HOME` or `~/.config`, `~/Library/Application
+    /// Support` on macOS or `%APPDATA%` on Windows
+    ///
+    /// # Errors
+    /// [`ConversionError::InvalidInput`] if none of these is known
+    pub fn dir() -> Result<PathBuf, ConversionError> {
+        if let Some(dir) = env::var_os(PROFILES_DIR_VAR) {
+            return Ok(dir.into());
+        }
+
+        let home = || env::var_os("HOME").map(PathBuf::from);
+        let config = if cfg!(windows) {
+            env::var_os("APPDATA").map(PathBuf::from)
+        } else if cfg!(target_os = "macos") {
+            home().map(|home| home.join("Library/Application Support"))
+        } else {
+            env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .or_else(|| home().map(|home| home.join(".config")))
+        };
+
+        config
+            .map(|config| config.join("text_converter").join("profiles"))
+            .ok_or_else(|| {
+                ConversionError::InvalidInput(format!(
+                    "no config directory to keep profiles in, set {PROFILES_DIR_VAR}"
+                ))
+            })
+    }
+
+    /// Names of the profiles saved in [`Profile::dir`], in alphabetical order
+    ///
+    /// # Errors
+    /// - [`ConversionError::InvalidInput`] if there is no directory for profiles
+    /// - [`ConversionError::Io`] if it exists and cannot be read
+    pub fn names() -> Result<Vec<String>, ConversionError> {
+        names_in(&Self::dir()?)
+    }
+
+    /// Loads the profile saved as `name` in [`Profile::dir`]
+    ///
+    /// # Errors
+    /// Same as [load_from](Self::load_from())
+    pub fn load(name: &str) -> Result<Self, ConversionError> {
+        Self::load_from(Self::dir()?, name)
+    }
+
+    /// Loads the profile saved as `name` in `dir`
+    ///
+    /// # Errors
+    /// - [`ConversionError::InvalidInput`] if no profile is saved as `name`, or it is not a valid
+    ///   name
+    /// - [`ConversionError::Io`] if the profile is inaccessible
+    /// - [`ConversionError::Syntax`] if it is not valid TOML or does not describe a profile
+    pub fn load_from(dir: impl AsRef<Path>, name: &str) -> Result<Self, ConversionError> {
+        let path = path(dir.as_ref(), name)?;
+        let text = match fs::read(&path) {
+            Ok(bytes) => String::from_utf8(bytes).map_err(|e| e.utf8_error())?,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return Err(ConversionError::InvalidInput(format!(
+                    "no profile named `{name}` in {}",
+                    dir.as_ref().display()
+                )))
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        toml::from_str(&text).map_err(|e| config::toml_error(&text, e))
+    }
+
+    /// Saves the profile as `name` in [`Profile::dir`], replacing any profile saved under it
+    ///
+    /// Returns the path of the profile
+    ///
+    /// # Errors
+    /// Same as [save_to](Self::save_to())
+    pub fn save(&self, name: &str) -> Result<PathBuf, ConversionError> {
+        self.save_to(Self::dir()?, name)
+    }
+
+    /// Saves the profile as `name` in `dir`, which is created if needed
+    ///
+    /// Returns the path of the profile
+    ///
+    /// # Errors
+    /// - [`ConversionError::InvalidInput`] if `name` is not a valid name, like one with a path
+    ///   separator
+    /// - [`ConversionError::Io`] if the profile cannot be written
+    pub fn save_to(&self, dir: impl AsRef<Path>, name: &str) -> Result<PathBuf, ConversionError> {
+        let path = path(dir.as_ref(), name)?;
+        let text = toml::to_string(self).map_err(|e| {
+            ConversionError::InvalidInput(format!("profile cannot be written as TOML: {e}"))
+        })?;
+
+        fs::create_dir_all(dir)?;
+        write_atomic(&path, text)?;
+        Ok(path)
+    }
+
+    /// Loads the profile saved as `name` in [`Profile::dir`] and runs it with the converters of
+    /// [`ConverterRegistry::with_builtins`]
+    ///
+    /// Returns the output, which was also written where the profile says
+    ///
+    /// # Errors
+    /// - Same as [load](Self::load())
+    /// - Same as [apply](Self::apply())
+    pub fn run(name: &str) -> Result<String, ConversionError> {
+        Self::load(name)?.apply(&ConverterRegistry::with_builtins())
+    }
+
+    /// Reads the input, converts it with the steps resolved in `registry` and writes the output
+    ///
+    /// Returns the output
+    ///
+    /// # Errors
+    /// - [`ConversionError::UnknownConverter`] if a step names a converter that does not exist
+    /// - [`ConversionError::InvalidInput`] if the parameters of a step do not fit its converter,
+    ///   or converting in place does not read from a file
+    /// - Whatever error reading the input, converting or writing the output reports
+    pub fn apply(&self, registry: &ConverterRegistry) -> Result<String, ConversionError> {
+        let pipeline = self.pipeline(registry)?;
+        if self.output == ProfileOutput::InPlace {
+            let ProfileInput::File(path) = &self.input else {
+                return Err(ConversionError::InvalidInput(
+                    "converting in place needs a file as input".to_owned(),
+                ));
+            };
+            return pipeline.try_convert_file_in_place(path);
+        }
+
+        let input = match &self.input {
+            ProfileInput::Stdin => crate::read_stdin()?,
+            #[cfg(feature = "clipboard")]
+            ProfileInput::Clipboard => TextClipboard::new()?.get_text()?,
+            ProfileInput::File(path) => {
+                let bytes = trace::read_file(path)?;
+                Bom::decode(&bytes)?.0.into_owned()
+            }
+        };
+        let output = pipeline.try_converter(input)?;
+
+        match &self.output {
+            ProfileOutput::Stdout => write_to_stdout(&output)?,
+            #[cfg(feature = "clipboard")]
+            ProfileOutput::Clipboard => TextClipboard::new()?.set_text(&output)?,
+            ProfileOutput::File(path) => write_atomic(path, &output)?,
+            ProfileOutput::InPlace => {}
+        }
+
+        Ok(output)
+    }
+
+    /// The steps of the profile as a pipeline, with the converters of `registry`
+    ///
+    /// # Errors
+    /// - [`ConversionError::UnknownConverter`] if a step names a converter that does not exist
+    /// - [`ConversionError::InvalidInput`] if the parameters of a step do not fit its converter
+    pub fn pipeline(&self, registry: &ConverterRegistry) -> Result<Pipeline, ConversionError> {
+        config::build_steps(&self.steps, registry)
+    }
+}
+
+/// Path of the profile saved as `name` in `dir`
+fn path(dir: &Path, name: &str) -> Result<PathBuf, ConversionError> {
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+        return Err(ConversionError::InvalidInput(format!(
+            "`{name}` is not a valid profile name"
+        )));
+    }
+
+    Ok(dir.join(format!("{name}.toml")))
+}
+
+fn names_in(dir: &Path) -> Result<Vec<String>, ConversionError> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension() == Some(OsStr::new("toml")) {
+            if let Some(name) = path.file_stem().and_then(OsStr::to_str) {
+                names.push(name.to_owned());
+            }
+        }
+    }
+    names.sort();
+
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saves_loads_and_applies() {
+        let dir = env::temp_dir().join("text_converter_profiles");
+        let _ = fs::remove_dir_all(&dir);
+        let input = dir.join("input.txt");
+        let output = dir.join("output.txt");
+
+        let mut params = Map::new();
+        params.insert("width".to_owned(), 5.into());
+        let profile = Profile::new(
+            ProfileInput::File(input.clone()),
+            ProfileOutput::File(output.clone()),
+        )
+        .step("trim")
+        .step_with("wrap", params);
+        profile.save_to(&dir, "wrap-notes").unwrap();
+        Profile::default().save_to(&dir, "empty").unwrap();
+
+        assert_eq!(vec!["empty", "wrap-notes"], names_in(&dir).unwrap());
+        let loaded = Profile::load_from(&dir, "wrap-notes").unwrap();
+        assert_eq!(profile, loaded);
+
+        fs::write(&input, "  ab cd ef  ").unwrap();
+        let registry = ConverterRegistry::with_builtins();
+        assert_eq!("ab cd\nef", loaded.apply(&registry).unwrap());
+        assert_eq!("ab cd\nef", fs::read_to_string(&output).unwrap());
+
+        let in_place =
+            Profile::new(ProfileInput::File(input.clone()), ProfileOutput::InPlace).step("upper");
+        assert_eq!("  AB CD EF  ", in_place.apply(&registry).unwrap());
+        assert_eq!("  AB CD EF  ", fs::read_to_string(&input).unwrap());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn invalid_profiles() {
+        let dir = env::temp_dir().join("text_converter_invalid_profiles");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("typo.toml"), "[[stepz]]\n").unwrap();
+        let registry = ConverterRegistry::with_builtins();
+
+        assert!(matches!(
+            Profile::load_from(&dir, "missing"),
+            Err(ConversionError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            Profile::default().save_to(&dir, "../escape"),
+            Err(ConversionError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            Profile::load_from(&dir, "typo"),
+            Err(ConversionError::Syntax { .. })
+        ));
+        assert!(matches!(
+            Profile::new(ProfileInput::Stdin, ProfileOutput::InPlace).apply(&registry),
+            Err(ConversionError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            Profile::default().step("shout").apply(&registry),
+            Err(ConversionError::UnknownConverter(_))
+        ));
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// Size of the chunks files are read in, and so how often reading them reports progress
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// How far a long conversion got, passed to the progress hook of the `*_with_progress` methods
+/// of [`TextConverter`](crate::TextConverter)
+///
+/// # Examples
+///
+/// ```no_run
+/// use text_converter::{converters::Trim, DirOptions, TextConverter};
+///
+/// Trim.new_from_dir_with_progress("notes", &DirOptions::new(), |progress| {
+///     if let (Some(fraction), Some(eta)) = (progress.fraction(), progress.eta()) {
+///         eprint!("\r{:.0}%, {:.0?} left", fraction * 100.0, eta);
+///     }
+/// })
+/// .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Progress {
+    /// Bytes of input read so far
+    pub bytes_processed: u64,
+    /// Bytes of input in total, when known up front
+    pub total_bytes: Option<u64>,
+    /// Files done so far, whether they were converted, skipped or failed
+    pub files_completed: usize,
+    /// Files in total, when known up front
+    pub total_files: Option<usize>,
+    /// Time since the conversion started
+    pub elapsed: Duration,
+}
+
+impl Progress {
+    /// Share of the work done, from `0.0` to `1.0`, by bytes if their total is known and by
+    /// files otherwise
+    pub fn fraction(&self) -> Option<f64> {
+        match (self.total_bytes, self.total_files) {
+            (Some(0), _) | (None, Some(0)) => Some(1.0),
+            (Some(total), _) => Some(self.bytes_processed as f64 / total as f64),
+            (None, Some(total)) => Some(self.files_completed as f64 / total as f64),
+            (None, None) => None,
+        }
+    }
+
+    /// Estimated time left, assuming the rest goes at the same speed as so far
+    pub fn eta(&self) -> Option<Duration> {
+        let fraction = self.fraction()?;
+        match fraction {
+            _ if fraction >= 1.0 => Some(Duration::ZERO),
+            _ if fraction > 0.0 => Some(self.elapsed.mul_f64((1.0 - fraction) / fraction)),
+            _ => None,
+        }
+    }
+}
+
+/// Keeps the [`Progress`] of a conversion up to date, reporting every change to the hook
+pub(crate) struct Tracker<F> {
+    start: Instant,
+    progress: Progress,
+    hook: F,
+}
+
+impl<F: FnMut(Progress)> Tracker<F> {
+    pub(crate) fn new(total_bytes: Option<u64>, total_files: Option<usize>, hook: F) -> Self {
+        Self {
+            start: Instant::now(),
+            progress: Progress {
+                total_bytes,
+                total_files,
+                ..Progress::default()
+            },
+            hook,
+        }
+    }
+
+    /// Adds `bytes` processed and `files` completed, then reports the progress
+    pub(crate) fn advance(&mut self, bytes: u64, files: usize) {
+        self.progress.bytes_processed += bytes;
+        self.progress.files_completed += files;
+        self.progress.elapsed = self.start.elapsed();
+        (self.hook)(self.progress);
+    }
+
+    /// Reads the whole file at `path` in chunks, reporting progress after each of them
+    pub(crate) fn read_file(&mut self, path: &Path) -> io::Result<Vec<u8>> {
+        let mut file = File::open(path)?;
+        let mut bytes = Vec::with_capacity(file.metadata().map_or(0, |m| m.len() as usize));
+        let mut chunk = vec![0; CHUNK_SIZE];
+
+        loop {
+            match file.read(&mut chunk) {
+                Ok(0) => return Ok(bytes),
+                Ok(read) => {
+                    bytes.extend_from_slice(&chunk[..read]);
+                    self.advance(read as u64, 0);
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fraction_and_eta() {
+        let progress = Progress {
+            bytes_processed: 25,
+            total_bytes: Some(100),
+            files_completed: 3,
+            total_files: Some(4),
+            elapsed: Duration::from_secs(10),
+        };
+        assert_eq!(Some(0.25), progress.fraction());
+        assert_eq!(Some(Duration::from_secs(30)), progress.eta());
+
+        let by_files = Progress {
+            total_bytes: None,
+            ..progress
+        };
+        assert_eq!(Some(0.75), by_files.fraction());
+
+        assert_eq!(None, Progress::default().eta());
+        let empty = Progress {
+            total_files: Some(0),
+            ..Progress::default()
+        };
+        assert_eq!(Some(Duration::ZERO), empty.eta());
+    }
+}
@@ -0,0 +1,249 @@
+//! Python bindings, as the `text_converter` Python module
+//!
+//! Enabled by the `pyo3` feature. Build and install the module with
+//! [maturin](https://github.com/PyO3/maturin), which picks the feature up from `pyproject.toml`:
+//! `maturin develop --release`
+//!
+//! ```python
+//! import text_converter
+//!
+//! text_converter.convert("rot13", "hello")  # 'uryyb'
+//! text_converter.convert_file("title", "notes.txt")  # also writes notes_converted.txt
+//!
+//! cleanup = text_converter.Pipeline(["trim", "collapse-whitespace", "title"])
+//! cleanup.convert_clipboard()
+//!
+//! registry = text_converter.Registry()
+//! registry.register("shout", lambda text: text.upper() + "!")
+//! registry.pipeline(["trim", "shout"]).convert("  hey ")  # 'HEY!'
+//! ```
+//!
+//! Failures raise `text_converter.ConversionError`
+
+use crate::{
+    pipeline::Pipeline, ConversionError as Error, ConverterRegistry, DynTextConverter,
+    TextConverter,
+};
+use pyo3::{create_exception, exceptions::PyException, exceptions::PyTypeError, prelude::*};
+use std::{path::PathBuf, sync::OnceLock};
+
+create_exception!(
+    text_converter,
+    ConversionError,
+    PyException,
+    "Raised when a conversion fails"
+);
+
+impl From<Error> for PyErr {
+    fn from(e: Error) -> Self {
+        ConversionError::new_err(e.to_string())
+    }
+}
+
+/// Registry backing the module-level functions and `Pipeline(names)`
+fn builtins() -> &'static ConverterRegistry {
+    static BUILTINS: OnceLock<ConverterRegistry> = OnceLock::new();
+    BUILTINS.get_or_init(ConverterRegistry::with_builtins)
+}
+
+fn lookup<'a>(
+    registry: &'a ConverterRegistry,
+    name: &str,
+) -> Result<&'a (dyn DynTextConverter + Send + Sync), Error> {
+    registry
+        .get(name)
+        .ok_or_else(|| Error::UnknownConverter(name.to_owned()))
+}
+
+/// Converts `text` with the built-in converter registered under `name`
+#[pyfunction]
+fn convert(py: Python<'_>, name: &str, text: &str) -> PyResult<String> {
+    Ok(py.detach(|| builtins().convert(name, text))?)
+}
+
+/// Converts the file at `path` with the built-in converter registered under `name`, writing the
+/// output next to it like `TextConverter::new_from_file` does
+#[pyfunction]
+fn convert_file(py: Python<'_>, name: &str, path: PathBuf) -> PyResult<String> {
+    Ok(py.detach(|| lookup(builtins(), name)?.try_new_from_file(path))?)
+}
+
+/// Converts the contents of the clipboard with the built-in converter registered under `name`
+#[cfg(feature = "clipboard")]
+#[pyfunction]
+fn convert_clipboard(py: Python<'_>, name: &str) -> PyResult<String> {
+    Ok(py.detach(|| lookup(builtins(), name)?.try_new_from_clipboard())?)
+}
+
+/// Names of the built-in converters, in alphabetical order
+#[pyfunction]
+fn converters() -> Vec<String> {
+    builtins().names().map(str::to_owned).collect()
+}
+
+/// Converters registered under names. Starts with the built-in ones unless `builtins` is false
+#[pyclass(name = "Registry", module = "text_converter")]
+struct PyRegistry(ConverterRegistry);
+
+#[pymethods]
+impl PyRegistry {
+    #[new]
+    #[pyo3(signature = (builtins = true))]
+    fn new(builtins: bool) -> Self {
+        match builtins {
+            true => Self(ConverterRegistry::with_builtins()),
+            false => Self(ConverterRegistry::new()),
+        }
+    }
+
+    /// Registers `function`, taking a string and returning one, under `name`
+    fn register(&mut self, name: String, function: Bound<'_, PyAny>) -> PyResult<()> {
+        if !function.is_callable() {
+            return Err(PyTypeError::new_err("the converter must be callable"));
+        }
+        self.0.register(name, PyConverter(function.unbind()));
+        Ok(())
+    }
+
+    /// Names of the registered converters, in alphabetical order
+    fn names(&self) -> Vec<String> {
+        self.0.names().map(str::to_owned).collect()
+    }
+
+    /// Converts `text` with the converter registered under `name`
+    fn convert(&self, py: Python<'_>, name: &str, text: &str) -> PyResult<String> {
+        Ok(py.detach(|| self.0.convert(name, text))?)
+    }
+
+    /// A pipeline running the converters registered under `names`, in order
+    fn pipeline(&self, names: Vec<String>) -> PyResult<PyPipeline> {
+        Ok(PyPipeline(pipeline(&self.0, &names)?))
+    }
+
+    fn __contains__(&self, name: &str) -> bool {
+        self.0.contains(name)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Registry({:?})", self.names())
+    }
+}
+
+/// Built-in converters run one after the other, in the order of `names`
+#[pyclass(name = "Pipeline", module = "text_converter", frozen)]
+struct PyPipeline(Pipeline);
+
+#[pymethods]
+impl PyPipeline {
+    #[new]
+    fn new(names: Vec<String>) -> PyResult<Self> {
+        Ok(Self(pipeline(builtins(), &names)?))
+    }
+
+    /// Loads the pipeline described by the TOML or YAML file at `path`
+    #[cfg(feature = "config")]
+    #[staticmethod]
+    fn from_config(path: PathBuf) -> PyResult<Self> {
+        Ok(Self(Pipeline::from_config(path)?))
+    }
+
+    /// Converts `text`
+    fn convert(&self, py: Python<'_>, text: &str) -> PyResult<String> {
+        Ok(py.detach(|| self.0.try_new_from_text(text))?)
+    }
+
+    /// Converts the file at `path`, writing the output next to it
+    fn convert_file(&self, py: Python<'_>, path: PathBuf) -> PyResult<String> {
+        Ok(py.detach(|| self.0.try_new_from_file(path))?)
+    }
+
+    /// Converts the contents of the clipboard
+    #[cfg(feature = "clipboard")]
+    fn convert_clipboard(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(py.detach(|| self.0.try_new_from_clipboard())?)
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+fn pipeline(registry: &ConverterRegistry, names: &[String]) -> Result<Pipeline, Error> {
+    let mut pipeline = Pipeline::new();
+    for name in names {
+        let converter = registry
+            .get_shared(name)
+            .ok_or_else(|| Error::UnknownConverter(name.clone()))?;
+        pipeline.push_shared(converter);
+    }
+
+    Ok(pipeline)
+}
+
+/// A Python function registered as a converter
+struct PyConverter(Py<PyAny>);
+
+impl TextConverter for PyConverter {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("Python converter failed: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, Error> {
+        Python::attach(|py| {
+            self.0
+                .call1(py, (input.as_ref(),))
+                .and_then(|output| output.extract::<String>(py))
+        })
+        .map_err(|e| Error::InvalidInput(format!("the Python function failed: {e}")))
+    }
+}
+
+/// Text conversion with the converters of the text_converter Rust crate
+#[pymodule(name = "text_converter")]
+mod module {
+    #[pymodule_export]
+    use super::{convert, convert_file, converters, ConversionError, PyPipeline, PyRegistry};
+
+    #[cfg(feature = "clipboard")]
+    #[pymodule_export]
+    use super::convert_clipboard;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::py_run;
+
+    #[test]
+    fn runs_from_python() {
+        Python::initialize();
+        Python::attach(|py| {
+            assert_eq!("uryyb", convert(py, "rot13", "hello").unwrap());
+            assert!(convert(py, "shout", "hello")
+                .unwrap_err()
+                .is_instance_of::<ConversionError>(py));
+
+            let registry = Bound::new(py, PyRegistry::new(true)).unwrap();
+            py_run!(
+                py,
+                registry,
+                r#"
+registry.register("shout", lambda text: text.upper() + "!")
+assert "shout" in registry
+assert registry.pipeline(["trim", "shout"]).convert("  hey ") == "HEY!"
+registry.register("broken", lambda text: 42)
+try:
+    registry.convert("broken", "hey")
+    assert False
+except Exception as e:
+    assert "Python function failed" in str(e)
+"#
+            );
+        });
+    }
+}
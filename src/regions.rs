@@ -0,0 +1,283 @@
+use crate::{ConversionError, TextConverter};
+use alloc::{string::String, vec::Vec};
+use core::ops::Range;
+#[cfg(feature = "regex")]
+use regex::Regex;
+
+/// Applies a converter only to some regions of the text, leaving the rest untouched
+///
+/// Each region is converted on its own, and the output is the input with every region replaced
+/// by its conversion
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{converters::Uppercase, Regions, TextConverter};
+///
+/// let code = Regions::between(Uppercase, "`", "`");
+/// assert_eq!("run `LS -A` first", code.new_from_text("run `ls -a` first"));
+///
+/// let todos = Regions::lines_starting_with(Uppercase, "TODO");
+/// assert_eq!(
+///     "done\nTODO: SHIP IT\n",
+///     todos.new_from_text("done\nTODO: ship it\n")
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Regions<C> {
+    converter: C,
+    finder: Finder,
+}
+
+#[derive(Debug, Clone)]
+enum Finder {
+    Between {
+        open: String,
+        close: String,
+    },
+    LinesStartingWith(String),
+    #[cfg(feature = "regex")]
+    Matches(Regex),
+}
+
+impl<C> Regions<C> {
+    /// Converts the text between `open` and `close`, without the delimiters themselves. An
+    /// `open` that is never closed starts no region
+    ///
+    /// # Panics
+    /// Will panic if `open` or `close` is empty
+    pub fn between(converter: C, open: impl Into<String>, close: impl Into<String>) -> Self {
+        let (open, close) = (open.into(), close.into());
+        assert!(
+            !open.is_empty() && !close.is_empty(),
+            "Region delimiters cannot be empty"
+        );
+
+        Self {
+            converter,
+            finder: Finder::Between { open, close },
+        }
+    }
+
+    /// Converts every line starting with `prefix`, prefix included, without its line ending
+    pub fn lines_starting_with(converter: C, prefix: impl Into<String>) -> Self {
+        Self {
+            converter,
+            finder: Finder::LinesStartingWith(prefix.into()),
+        }
+    }
+
+    /// Converts every match of `pattern`
+    ///
+    /// Enabled by the `regex` feature
+    ///
+    /// # Errors
+    /// [`ConversionError::InvalidInput`] if `pattern` is not a valid regular expression
+    #[cfg(feature = "regex")]
+    pub fn matching(converter: C, pattern: &str) -> Result<Self, ConversionError> {
+        let regex = Regex::new(pattern).map_err(|e| {
+            ConversionError::InvalidInput(format!("Invalid regex {pattern:?}: {e}"))
+        })?;
+
+        Ok(Self {
+            converter,
+            finder: Finder::Matches(regex),
+        })
+    }
+
+    /// Byte ranges of the regions of `input` that get converted, in order and without overlaps
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use text_converter::{converters::Uppercase, Regions};
+    ///
+    /// let quoted = Regions::between(Uppercase, "\"", "\"");
+    /// assert_eq!(vec![1..3, 9..11], quoted.spans(r#""ab" cd "ef" "gh"#));
+    /// ```
+    pub fn spans(&self, input: &str) -> Vec<Range<usize>> {
+        match &self.finder {
+            Finder::Between { open, close } => {
+                let mut spans = Vec::new();
+                let mut rest = 0;
+
+                while let Some(start) = input[rest..].find(open.as_str()) {
+                    let start = rest + start + open.len();
+                    let Some(len) = input[start..].find(close.as_str()) else {
+                        break;
+                    };
+                    spans.push(start..start + len);
+                    rest = start + len + close.len();
+                }
+
+                spans
+            }
+            Finder::LinesStartingWith(prefix) => {
+                line_spans_where(input, |line| line.starts_with(prefix.as_str()))
+            }
+            #[cfg(feature = "regex")]
+            Finder::Matches(regex) => regex.find_iter(input).map(|m| m.range()).collect(),
+        }
+    }
+}
+
+impl<C: TextConverter> TextConverter for Regions<C> {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let input = input.as_ref();
+        let spans = self.spans(input);
+        let converted = spans
+            .iter()
+            .map(|span| self.converter.converter(&input[span.clone()]));
+
+        reassemble(input, &spans, converted)
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let input = input.as_ref();
+        let spans = self.spans(input);
+        let converted = spans
+            .iter()
+            .map(|span| self.converter.try_converter(&input[span.clone()]))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(reassemble(input, &spans, converted))
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        self.converter.output_extension()
+    }
+}
+
+/// Applies a converter to each line on its own, keeping the line endings as they were
+///
+/// Turns whole-text converters into line-wise ones. The converter gets each line without its
+/// `\n` or `\r\n`
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{converters::Uppercase, PerLine, TextConverter};
+///
+/// struct Reverse;
+///
+/// impl TextConverter for Reverse {
+///     fn converter(&self, input: impl AsRef<str>) -> String {
+///         input.as_ref().chars().rev().collect()
+///     }
+/// }
+///
+/// assert_eq!("cba\r\nfed\n", PerLine(Reverse).new_from_text("abc\r\ndef\n"));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PerLine<C>(pub C);
+
+impl<C: TextConverter> TextConverter for PerLine<C> {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        let input = input.as_ref();
+        let spans = line_spans(input);
+        let converted = spans
+            .iter()
+            .map(|span| self.0.converter(&input[span.clone()]));
+
+        reassemble(input, &spans, converted)
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        let input = input.as_ref();
+        let spans = line_spans(input);
+        let converted = spans
+            .iter()
+            .map(|span| self.0.try_converter(&input[span.clone()]))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(reassemble(input, &spans, converted))
+    }
+
+    fn output_extension(&self) -> Option<&str> {
+        self.0.output_extension()
+    }
+}
+
+/// Byte ranges of the lines of `input` for which `keep` is true, without their line endings
+fn line_spans_where(input: &str, keep: impl Fn(&str) -> bool) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut start = 0;
+
+    for line in input.split_inclusive('\n') {
+        let (content, _) = crate::split_line_ending(line);
+        if keep(content) {
+            spans.push(start..start + content.len());
+        }
+        start += line.len();
+    }
+
+    spans
+}
+
+fn line_spans(input: &str) -> Vec<Range<usize>> {
+    line_spans_where(input, |_| true)
+}
+
+/// `input` with each of `spans` replaced by its conversion
+fn reassemble(
+    input: &str,
+    spans: &[Range<usize>],
+    converted: impl IntoIterator<Item = String>,
+) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut end = 0;
+
+    for (span, region) in spans.iter().zip(converted) {
+        output.push_str(&input[end..span.start]);
+        output.push_str(&region);
+        end = span.end;
+    }
+
+    output.push_str(&input[end..]);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Reverse;
+
+    impl TextConverter for Reverse {
+        fn converter(&self, input: impl AsRef<str>) -> String {
+            input.as_ref().chars().rev().collect()
+        }
+    }
+
+    #[test]
+    fn delimited_regions() {
+        let tags = Regions::between(Reverse, "<<", ">>");
+        assert_eq!("a <<cb>> d <<fe", tags.new_from_text("a <<bc>> d <<fe"));
+        assert_eq!("<<>>", tags.new_from_text("<<>>"));
+    }
+
+    #[test]
+    fn line_regions() {
+        let todos = Regions::lines_starting_with(Reverse, "TODO");
+        assert_eq!(vec![5..12], todos.spans("done\nTODO 42\r\nx\n"));
+        assert_eq!(
+            "done\n24 ODOT\r\nx\n",
+            todos.new_from_text("done\nTODO 42\r\nx\n")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn matching_regions() {
+        let numbers = Regions::matching(Reverse, r"\d+").unwrap();
+        assert_eq!("21 and 543", numbers.new_from_text("12 and 345"));
+        assert!(Regions::matching(Reverse, "(").is_err());
+    }
+
+    #[test]
+    fn each_line_on_its_own() {
+        assert_eq!("ba\n\ndc", PerLine(Reverse).new_from_text("ab\n\ncd"));
+        assert_eq!("", PerLine(Reverse).new_from_text(""));
+    }
+}
@@ -0,0 +1,179 @@
+//! Converters registering themselves, so that the crates defining them only need to be linked in
+//! for every registry made with [`ConverterRegistry::with_registered`] to offer them
+//!
+//! Enabled by the `inventory` feature. [`register_converter!`](crate::register_converter) submits
+//! a converter from anywhere in a crate, the [inventory](https://docs.rs/inventory) crate gathers
+//! all of them before `main` runs, and the `tconv` binary lists them with the built-in ones
+//!
+//! # Examples
+//!
+//! ```
+//! use text_converter::{register_converter, ConverterInfo, ConverterRegistry, TextConverter};
+//!
+//! struct Shout;
+//!
+//! impl TextConverter for Shout {
+//!     fn converter(&self, input: impl AsRef<str>) -> String {
+//!         input.as_ref().to_uppercase() + "!"
+//!     }
+//! }
+//!
+//! register_converter!("shout", Shout, ConverterInfo::new("Shout the text").category("fun"));
+//!
+//! let registry = ConverterRegistry::with_registered();
+//! assert_eq!("HEY!", registry.convert("shout", "hey").unwrap());
+//! assert_eq!("Shout the text", registry.info("shout").unwrap().description());
+//! ```
+
+use crate::{ConverterInfo, ConverterRegistry, DynTextConverter};
+use std::sync::{Arc, LazyLock};
+
+/// A converter submitted with [`register_converter!`](crate::register_converter), built when a
+/// registry picks it up
+#[derive(Debug)]
+pub struct ConverterRegistration {
+    name: &'static str,
+    build: fn() -> Arc<dyn DynTextConverter + Send + Sync>,
+    info: Option<fn() -> ConverterInfo>,
+}
+
+inventory::collect!(ConverterRegistration);
+
+impl ConverterRegistration {
+    /// Registration of the converter `build` returns under `name`, described by what `info`
+    /// returns if given
+    ///
+    /// Usually called through [`register_converter!`](crate::register_converter)
+    pub const fn new(
+        name: &'static str,
+        build: fn() -> Arc<dyn DynTextConverter + Send + Sync>,
+        info: Option<fn() -> ConverterInfo>,
+    ) -> Self {
+        Self { name, build, info }
+    }
+
+    /// Name the converter is registered under
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl ConverterRegistry {
+    /// Creates a registry with all the [built-in converters](crate::converters) if the
+    /// `converters` feature is on, and every converter submitted with
+    /// [`register_converter!`](crate::register_converter) by the crates linked in
+    ///
+    /// Enabled by the `inventory` feature. Submitted converters replace built-in ones with the
+    /// same name
+    pub fn with_registered() -> Self {
+        #[cfg(feature = "converters")]
+        let mut registry = Self::with_builtins();
+        #[cfg(not(feature = "converters"))]
+        let mut registry = Self::new();
+        registry.register_submitted();
+        registry
+    }
+
+    /// Registry made by [with_registered](Self::with_registered()) the first time it is needed,
+    /// shared by the whole program
+    pub fn global() -> &'static Self {
+        static GLOBAL: LazyLock<ConverterRegistry> =
+            LazyLock::new(ConverterRegistry::with_registered);
+        &GLOBAL
+    }
+
+    /// Registers every converter submitted with
+    /// [`register_converter!`](crate::register_converter), replacing converters already
+    /// registered under their names
+    ///
+    /// # Returns
+    /// Names of the converters registered, in alphabetical order
+    pub fn register_submitted(&mut self) -> Vec<&'static str> {
+        let mut registrations: Vec<_> = inventory::iter::<ConverterRegistration>().collect();
+        registrations.sort_by_key(|registration| registration.name);
+
+        for registration in &registrations {
+            self.register_shared(registration.name, (registration.build)());
+            if let Some(info) = registration.info {
+                self.describe(registration.name, info());
+            }
+        }
+
+        registrations
+            .iter()
+            .map(|registration| registration.name)
+            .collect()
+    }
+}
+
+/// Submits a converter for every registry made with
+/// [`ConverterRegistry::with_registered`] to pick up, under a name and optionally with a
+/// [`ConverterInfo`](crate::ConverterInfo)
+///
+/// Enabled by the `inventory` feature. Used where items are, outside of functions or inside
+/// them, and the converter is only built when a registry picks it up, see the
+/// [`registration`](crate::registration) module
+///
+/// ```
+/// use text_converter::{converters::CaesarCipher, register_converter};
+///
+/// register_converter!("caesar-3", CaesarCipher::new(3));
+/// ```
+#[macro_export]
+macro_rules! register_converter {
+    ($name:expr, $converter:expr $(,)?) => {
+        $crate::inventory::submit! {
+            $crate::registration::ConverterRegistration::new(
+                $name,
+                || ::std::sync::Arc::new($converter),
+                ::core::option::Option::None,
+            )
+        }
+    };
+    ($name:expr, $converter:expr, $info:expr $(,)?) => {
+        $crate::inventory::submit! {
+            $crate::registration::ConverterRegistration::new(
+                $name,
+                || ::std::sync::Arc::new($converter),
+                ::core::option::Option::Some(|| $info),
+            )
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ConverterInfo, ConverterRegistry, TextConverter};
+
+    struct Exclaim;
+
+    impl TextConverter for Exclaim {
+        fn converter(&self, input: impl AsRef<str>) -> String {
+            input.as_ref().to_owned() + "!"
+        }
+    }
+
+    register_converter!("test-exclaim", Exclaim);
+    register_converter!(
+        "test-exclaim-described",
+        Exclaim,
+        ConverterInfo::new("Add an exclamation mark").category("test")
+    );
+
+    #[test]
+    fn registers_submitted_converters() {
+        let mut registry = ConverterRegistry::new();
+        let names = registry.register_submitted();
+        assert_eq!(vec!["test-exclaim", "test-exclaim-described"], names);
+        assert_eq!("hi!", registry.convert("test-exclaim", "hi").unwrap());
+        assert!(registry
+            .info("test-exclaim-described")
+            .unwrap()
+            .in_category("test"));
+
+        let global = ConverterRegistry::global();
+        assert!(global.contains("test-exclaim"));
+        #[cfg(feature = "converters")]
+        assert!(global.contains("upper"));
+    }
+}
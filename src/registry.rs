@@ -0,0 +1,668 @@
+use crate::{ConversionError, ConverterInfo, DynTextConverter, TextConverter};
+use alloc::{borrow::ToOwned, collections::BTreeMap, string::String, sync::Arc};
+use core::fmt;
+#[cfg(feature = "config")]
+use serde::{de::DeserializeOwned, Serialize};
+#[cfg(feature = "config")]
+use serde_json::{Map, Value};
+
+pub(crate) type SharedConverter = Arc<dyn DynTextConverter + Send + Sync>;
+
+/// Builds a converter from the parameters given in a config file
+#[cfg(feature = "config")]
+type Factory =
+    Box<dyn Fn(&Map<String, Value>) -> Result<SharedConverter, ConversionError> + Send + Sync>;
+
+/// Converters registered under names, to pick one at runtime
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{converters::Uppercase, ConverterRegistry};
+///
+/// let mut registry = ConverterRegistry::new();
+/// registry.register("shout", Uppercase);
+///
+/// assert_eq!("HEY", registry.convert("shout", "hey").unwrap());
+/// assert!(registry.convert("whisper", "hey").is_err());
+/// ```
+#[derive(Default)]
+pub struct ConverterRegistry {
+    converters: BTreeMap<String, SharedConverter>,
+    infos: BTreeMap<String, ConverterInfo>,
+    #[cfg(feature = "config")]
+    factories: BTreeMap<String, Factory>,
+}
+
+impl ConverterRegistry {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry with all the [built-in converters](crate::converters) registered under
+    /// kebab-case names, such as `upper`, `title` or `reverse-lines`
+    #[cfg(feature = "converters")]
+    pub fn with_builtins() -> Self {
+        use crate::{converters::*, Decode};
+
+        let mut registry = Self::new();
+        registry
+            .register_builtin("upper", Uppercase)
+            .register_builtin("lower", Lowercase)
+            .register_builtin("title", TitleCase)
+            .register_builtin("swap-case", SwapCase)
+            .register_builtin("mocking", MockingCase::Alternate)
+            .register_builtin("snake", SnakeCase)
+            .register_builtin("screaming-snake", ScreamingSnakeCase)
+            .register_builtin("kebab", KebabCase)
+            .register_builtin("camel", CamelCase)
+            .register_builtin("pascal", PascalCase)
+            .register_builtin("trim", Trim)
+            .register_builtin("trim-lines", TrimLines)
+            .register_builtin("collapse-whitespace", CollapseWhitespace)
+            .register_builtin("normalize-whitespace", NormalizeWhitespace::default())
+            .register_builtin("remove-whitespace", RemoveWhitespace)
+            .register_builtin("remove-empty-lines", RemoveEmptyLines)
+            .register_builtin("wrap", WordWrap::default())
+            .register_builtin("to-lf", LineEndings::new(LineEnding::Lf))
+            .register_builtin("to-crlf", LineEndings::new(LineEnding::CrLf))
+            .register_builtin("to-cr", LineEndings::new(LineEnding::Cr))
+            .register_builtin("reverse", Reverse)
+            .register_builtin("reverse-words", ReverseWords)
+            .register_builtin("reverse-lines", ReverseLines)
+            .register_builtin("sort-lines", SortLines::default())
+            .register_builtin("dedup-lines", DedupLines::default())
+            .register_builtin("number-lines", NumberLines::default())
+            .register_builtin("strip-line-numbers", StripLineNumbers)
+            .register_builtin("pig-latin", PigLatin)
+            .register_builtin("rot13", Rot13)
+            .register_builtin("vigenere", Vigenere::default())
+            .register_builtin("vigenere-decode", Decode(Vigenere::default()))
+            .register_builtin("base64", Base64Encode::new())
+            .register_builtin("base64-decode", Base64Decode::default())
+            .register_builtin("hex-dump", HexDump::default())
+            .register_builtin("hex-decode", HexDecode)
+            .register_builtin("binary", Binary::default())
+            .register_builtin("binary-decode", Decode(Binary::default()))
+            .register_builtin("html-escape", HtmlEscape)
+            .register_builtin("html-unescape", HtmlUnescape)
+            .register_builtin("html-to-text", HtmlToText)
+            .register_builtin("csv-to-markdown", CsvToMarkdown::default())
+            .register_builtin("markdown-to-csv", MarkdownToCsv::default())
+            .register_builtin("url-encode", UrlEncode::default())
+            .register_builtin("url-decode", UrlDecode::default())
+            .register_builtin("leet", Leet::new(LeetIntensity::Advanced))
+            .register_builtin("emojize", Emojize)
+            .register_builtin("demojize", Decode(Emojize))
+            .register_builtin("fullwidth", Fullwidth::default())
+            .register_builtin("fullwidth-decode", Decode(Fullwidth::default()))
+            .register_builtin("bold", UnicodeStyle::new(MathStyle::Bold))
+            .register_builtin("italic", UnicodeStyle::new(MathStyle::Italic))
+            .register_builtin("monospace", UnicodeStyle::new(MathStyle::Monospace))
+            .register_builtin("unstyle", UnicodeUnstyle)
+            .register_builtin("zalgo", Zalgo::new(ZalgoIntensity::Moderate))
+            .register_builtin("zalgo-strip", ZalgoStrip)
+            .register_builtin("morse", Morse::default())
+            .register_builtin("morse-decode", Decode(Morse::default()))
+            .register_builtin("nato", Nato::default())
+            .register_builtin("nato-decode", Decode(Nato::default()))
+            .register_builtin("braille", Braille::default())
+            .register_builtin("braille-decode", Decode(Braille::default()));
+        #[cfg(feature = "pulldown-cmark")]
+        registry.register_builtin("markdown", MarkdownToHtml);
+        #[cfg(feature = "deunicode")]
+        registry.register_builtin("transliterate", Transliterate::default());
+        #[cfg(feature = "figlet-rs")]
+        registry.register_builtin("banner", Banner::default());
+        #[cfg(feature = "unicode-normalization")]
+        registry
+            .register_builtin("nfc", Normalize::new(NormalizationForm::Nfc))
+            .register_builtin("nfd", Normalize::new(NormalizationForm::Nfd))
+            .register_builtin("nfkc", Normalize::new(NormalizationForm::Nfkc))
+            .register_builtin("nfkd", Normalize::new(NormalizationForm::Nfkd));
+        #[cfg(feature = "serde_json")]
+        registry
+            .register_builtin("json-pretty", JsonPretty::default())
+            .register_builtin("json-minify", JsonMinify);
+        #[cfg(feature = "serde_yaml")]
+        registry
+            .register_builtin("yaml-to-json", YamlToJson)
+            .register_builtin("json-to-yaml", JsonToYaml);
+        #[cfg(feature = "rhai")]
+        registry.register_builtin("script", ScriptConverter::default());
+
+        for &(category, converters) in BUILTIN_INFOS {
+            for &(name, description) in converters {
+                let info = ConverterInfo::new(description)
+                    .category(category)
+                    .reversible(REVERSIBLE_BUILTINS.contains(&name));
+                registry.describe(name, info);
+            }
+        }
+        registry
+    }
+
+    /// Registers a converter under `name`, replacing any converter already registered under it
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        converter: impl TextConverter + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.register_shared(name, Arc::new(converter))
+    }
+
+    /// Same as [register](Self::register()), for a converter that is already shared
+    pub(crate) fn register_shared(
+        &mut self,
+        name: impl Into<String>,
+        converter: SharedConverter,
+    ) -> &mut Self {
+        let name = name.into();
+        #[cfg(feature = "config")]
+        self.factories.remove(&name);
+        self.infos
+            .insert(name.clone(), ConverterInfo::registered(&name, false));
+        self.converters.insert(name, converter);
+        self
+    }
+
+    /// Registers a converter under `name` like [register](Self::register()), letting
+    /// [pipeline configs](crate::pipeline::Pipeline::from_config()) override its fields
+    ///
+    /// Enabled by the `config` feature. The parameters of a config step are written over the
+    /// serialized `converter`, and the result is deserialized into the converter for that step
+    #[cfg(feature = "config")]
+    pub fn register_configurable<T>(&mut self, name: impl Into<String>, converter: T) -> &mut Self
+    where
+        T: TextConverter + Serialize + DeserializeOwned + Send + Sync + 'static,
+    {
+        let name = name.into();
+        let base = serde_json::to_value(&converter).unwrap_or(Value::Null);
+        let factory_name = name.clone();
+        let factory = move |params: &Map<String, Value>| {
+            let Value::Object(mut fields) = base.clone() else {
+                return Err(ConversionError::InvalidInput(format!(
+                    "converter `{factory_name}` takes no parameters"
+                )));
+            };
+            fields.extend(params.clone());
+
+            let converter = T::deserialize(Value::Object(fields)).map_err(|e| {
+                ConversionError::InvalidInput(format!(
+                    "invalid parameters for converter `{factory_name}`: {e}"
+                ))
+            })?;
+            Ok(Arc::new(converter) as SharedConverter)
+        };
+
+        self.infos
+            .insert(name.clone(), ConverterInfo::registered(&name, true));
+        self.converters.insert(name.clone(), Arc::new(converter));
+        self.factories.insert(name, Box::new(factory));
+        self
+    }
+
+    /// Registers a built-in converter, letting configs override its fields when they can
+    #[cfg(all(feature = "converters", not(feature = "config")))]
+    fn register_builtin(
+        &mut self,
+        name: &str,
+        converter: impl TextConverter + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.register(name, converter)
+    }
+
+    #[cfg(all(feature = "converters", feature = "config"))]
+    fn register_builtin<T>(&mut self, name: &str, converter: T) -> &mut Self
+    where
+        T: TextConverter + Serialize + DeserializeOwned + Send + Sync + 'static,
+    {
+        self.register_configurable(name, converter)
+    }
+
+    /// Whether a converter is registered under `name`
+    pub fn contains(&self, name: &str) -> bool {
+        self.converters.contains_key(name)
+    }
+
+    /// The converter registered under `name`
+    pub fn get(&self, name: &str) -> Option<&(dyn DynTextConverter + Send + Sync)> {
+        self.converters.get(name).map(Arc::as_ref)
+    }
+
+    /// Same as [get](Self::get()), sharing the converter instead of borrowing it
+    #[cfg(feature = "std")]
+    pub(crate) fn get_shared(&self, name: &str) -> Option<SharedConverter> {
+        self.converters.get(name).cloned()
+    }
+
+    /// The converter registered under `name`, with its fields overridden by `params` if there
+    /// are any
+    ///
+    /// Enabled by the `config` feature
+    ///
+    /// # Errors
+    /// - [`ConversionError::UnknownConverter`] if no converter is registered under `name`
+    /// - [`ConversionError::InvalidInput`] if there are parameters and the converter was not
+    ///   [registered as configurable](Self::register_configurable()), or they do not fit it
+    #[cfg(feature = "config")]
+    pub fn configure(
+        &self,
+        name: &str,
+        params: &Map<String, Value>,
+    ) -> Result<Arc<dyn DynTextConverter + Send + Sync>, ConversionError> {
+        let converter = self
+            .converters
+            .get(name)
+            .ok_or_else(|| ConversionError::UnknownConverter(name.to_owned()))?;
+
+        match (params.is_empty(), self.factories.get(name)) {
+            (true, _) => Ok(Arc::clone(converter)),
+            (false, Some(factory)) => factory(params),
+            (false, None) => Err(ConversionError::InvalidInput(format!(
+                "converter `{name}` takes no parameters"
+            ))),
+        }
+    }
+
+    /// Names of all registered converters, in alphabetical order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.converters.keys().map(String::as_str)
+    }
+
+    /// Sets the description, categories and reversibility of the converter registered under
+    /// `name`, doing nothing if there is none
+    ///
+    /// Registering another converter under `name` clears them
+    pub fn describe(&mut self, name: &str, info: ConverterInfo) -> &mut Self {
+        if let Some(registered) = self.infos.get_mut(name) {
+            registered.describe(info);
+        }
+        self
+    }
+
+    /// What the converter registered under `name` does
+    pub fn info(&self, name: &str) -> Option<&ConverterInfo> {
+        self.infos.get(name)
+    }
+
+    /// What every registered converter does, in the alphabetical order of their names
+    pub fn infos(&self) -> impl Iterator<Item = &ConverterInfo> {
+        self.infos.values()
+    }
+
+    /// Converts `input` with the converter registered under `name`, using its
+    /// [try_converter](TextConverter::try_converter()) method
+    ///
+    /// # Errors
+    /// - [`ConversionError::UnknownConverter`] if no converter is registered under `name`
+    /// - Whatever error the converter reports
+    pub fn convert(&self, name: &str, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.get(name)
+            .ok_or_else(|| ConversionError::UnknownConverter(name.to_owned()))?
+            .try_convert(input.as_ref())
+    }
+}
+
+/// Descriptions of the built-in converters, by category
+#[cfg(feature = "converters")]
+const BUILTIN_INFOS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "case",
+        &[
+            ("upper", "Convert every character to uppercase"),
+            ("lower", "Convert every character to lowercase"),
+            ("title", "Capitalize the first letter of every word"),
+            ("swap-case", "Swap uppercase and lowercase characters"),
+            (
+                "mocking",
+                "Alternate between lowercase and uppercase letters",
+            ),
+            ("snake", "Convert identifiers to snake_case"),
+            (
+                "screaming-snake",
+                "Convert identifiers to SCREAMING_SNAKE_CASE",
+            ),
+            ("kebab", "Convert identifiers to kebab-case"),
+            ("camel", "Convert identifiers to camelCase"),
+            ("pascal", "Convert identifiers to PascalCase"),
+        ],
+    ),
+    (
+        "whitespace",
+        &[
+            ("trim", "Remove leading and trailing whitespace"),
+            (
+                "trim-lines",
+                "Remove leading and trailing whitespace from every line",
+            ),
+            (
+                "collapse-whitespace",
+                "Replace every run of whitespace with a single space",
+            ),
+            (
+                "normalize-whitespace",
+                "Collapse runs of spaces and tabs and trim every line",
+            ),
+            ("remove-whitespace", "Remove all whitespace"),
+        ],
+    ),
+    (
+        "lines",
+        &[
+            ("remove-empty-lines", "Remove empty lines"),
+            ("wrap", "Reflow paragraphs to a maximum line width"),
+            ("to-lf", "Make every line end with LF"),
+            ("to-crlf", "Make every line end with CRLF"),
+            ("to-cr", "Make every line end with CR"),
+            ("dedup-lines", "Remove repeated lines"),
+            ("number-lines", "Put the line number before every line"),
+            ("strip-line-numbers", "Remove the numbers lines start with"),
+        ],
+    ),
+    (
+        "order",
+        &[
+            ("reverse", "Reverse the order of the characters"),
+            ("reverse-words", "Reverse the order of the words"),
+            ("reverse-lines", "Reverse the order of the lines"),
+            ("sort-lines", "Sort the lines"),
+        ],
+    ),
+    (
+        "fun",
+        &[
+            ("pig-latin", "Translate words to Pig Latin"),
+            ("leet", "Replace letters with look-alike digits and symbols"),
+            ("zalgo", "Stack random combining marks on every character"),
+            ("banner", "Draw the text as a large ASCII-art banner"),
+        ],
+    ),
+    (
+        "cipher",
+        &[
+            ("rot13", "Shift letters 13 places in the alphabet"),
+            (
+                "vigenere",
+                "Shift letters by the letters of a keyword, given as the `key` parameter",
+            ),
+            (
+                "vigenere-decode",
+                "Shift letters back by the letters of the `key` parameter",
+            ),
+        ],
+    ),
+    (
+        "encoding",
+        &[
+            ("base64", "Encode text as Base64"),
+            ("base64-decode", "Decode Base64 text"),
+            (
+                "hex-dump",
+                "Dump the bytes with their offsets, hex values and ASCII characters",
+            ),
+            ("hex-decode", "Decode a string of hexadecimal digits"),
+            (
+                "binary",
+                "Write the bytes of the text as groups of binary digits",
+            ),
+            ("binary-decode", "Decode groups of binary digits"),
+            (
+                "url-encode",
+                "Percent-encode characters that aren't allowed in URLs",
+            ),
+            ("url-decode", "Decode percent-encoded text"),
+            ("morse", "Encode text as Morse code"),
+            ("morse-decode", "Decode Morse code"),
+            ("nato", "Spell text out in the NATO phonetic alphabet"),
+            (
+                "nato-decode",
+                "Turn NATO phonetic alphabet words back into text",
+            ),
+            ("braille", "Write text as Unicode Grade 1 Braille patterns"),
+            (
+                "braille-decode",
+                "Turn Unicode Grade 1 Braille back into text",
+            ),
+        ],
+    ),
+    (
+        "markup",
+        &[
+            (
+                "html-escape",
+                "Escape characters that have a meaning in HTML",
+            ),
+            (
+                "html-unescape",
+                "Replace HTML character references with their characters",
+            ),
+            ("html-to-text", "Turn HTML into plain text"),
+            ("markdown", "Render Markdown as HTML"),
+        ],
+    ),
+    (
+        "data",
+        &[
+            ("csv-to-markdown", "Turn CSV into an aligned Markdown table"),
+            ("markdown-to-csv", "Turn Markdown tables into CSV"),
+            ("json-pretty", "Pretty-print JSON"),
+            ("json-minify", "Remove all whitespace from JSON"),
+            ("yaml-to-json", "Convert YAML to JSON"),
+            ("json-to-yaml", "Convert JSON to YAML"),
+        ],
+    ),
+    (
+        "unicode",
+        &[
+            ("emojize", "Replace :shortcodes: with emoji"),
+            ("demojize", "Replace emoji with :shortcodes:"),
+            (
+                "fullwidth",
+                "Convert ASCII characters to their fullwidth forms",
+            ),
+            ("fullwidth-decode", "Convert fullwidth forms back to ASCII"),
+            ("bold", "Style letters and digits with bold Unicode symbols"),
+            (
+                "italic",
+                "Style letters and digits with italic Unicode symbols",
+            ),
+            (
+                "monospace",
+                "Style letters and digits with monospace Unicode symbols",
+            ),
+            (
+                "unstyle",
+                "Turn styled Unicode letters and digits back into plain ones",
+            ),
+            ("zalgo-strip", "Remove combining marks"),
+            (
+                "transliterate",
+                "Replace accented and non-Latin characters with ASCII",
+            ),
+            ("nfc", "Bring text to Unicode normalization form C"),
+            ("nfd", "Bring text to Unicode normalization form D"),
+            ("nfkc", "Bring text to Unicode normalization form KC"),
+            ("nfkd", "Bring text to Unicode normalization form KD"),
+        ],
+    ),
+    (
+        "script",
+        &[(
+            "script",
+            "Convert with a Rhai script defining `convert(input)`",
+        )],
+    ),
+];
+
+/// Built-in converters with an inverse
+#[cfg(feature = "converters")]
+const REVERSIBLE_BUILTINS: &[&str] = &[
+    "number-lines",
+    "rot13",
+    "vigenere",
+    "vigenere-decode",
+    "base64",
+    "base64-decode",
+    "hex-decode",
+    "binary",
+    "binary-decode",
+    "html-escape",
+    "html-unescape",
+    "csv-to-markdown",
+    "markdown-to-csv",
+    "url-encode",
+    "url-decode",
+    "emojize",
+    "demojize",
+    "fullwidth",
+    "fullwidth-decode",
+    "bold",
+    "italic",
+    "monospace",
+    "zalgo",
+    "morse",
+    "morse-decode",
+    "nato",
+    "nato-decode",
+    "braille",
+    "braille-decode",
+    "yaml-to-json",
+    "json-to-yaml",
+];
+
+impl fmt::Debug for ConverterRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.names()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Exclaim(usize);
+
+    impl TextConverter for Exclaim {
+        fn converter(&self, input: impl AsRef<str>) -> String {
+            input.as_ref().to_owned() + "!".repeat(self.0).as_str()
+        }
+    }
+
+    #[test]
+    fn register_and_convert() {
+        let mut registry = ConverterRegistry::new();
+        registry
+            .register("excited", Exclaim(1))
+            .register("very-excited", Exclaim(3));
+
+        assert_eq!(
+            vec!["excited", "very-excited"],
+            registry.names().collect::<Vec<_>>()
+        );
+        assert_eq!("hi!!!", registry.convert("very-excited", "hi").unwrap());
+
+        registry.register("excited", Exclaim(2));
+        assert_eq!("hi!!", registry.convert("excited", "hi").unwrap());
+    }
+
+    #[test]
+    fn unknown_converter() {
+        let registry = ConverterRegistry::new();
+        assert!(matches!(
+            registry.convert("missing", "hi"),
+            Err(ConversionError::UnknownConverter(name)) if name == "missing"
+        ));
+    }
+
+    #[cfg(feature = "converters")]
+    #[test]
+    fn builtins() {
+        let registry = ConverterRegistry::with_builtins();
+        assert_eq!(
+            "Hello World",
+            registry.convert("title", "hello world").unwrap()
+        );
+        assert!(registry.contains("reverse-lines"));
+
+        let trim = registry.get("trim").unwrap();
+        assert_eq!(
+            "HI",
+            (trim, registry.get("upper").unwrap()).new_from_text(" hi ")
+        );
+    }
+
+    #[test]
+    fn describe_converters() {
+        let mut registry = ConverterRegistry::new();
+        registry
+            .register("excited", Exclaim(1))
+            .describe("excited", ConverterInfo::new("Add an exclamation mark"))
+            .describe("missing", ConverterInfo::new("Never registered"));
+
+        let info = registry.info("excited").unwrap();
+        assert_eq!(
+            ("excited", "Add an exclamation mark"),
+            (info.name(), info.description())
+        );
+        assert!(!info.is_configurable());
+        assert!(registry.info("missing").is_none());
+
+        registry.register("excited", Exclaim(2));
+        assert_eq!("", registry.info("excited").unwrap().description());
+    }
+
+    #[cfg(feature = "converters")]
+    #[test]
+    fn builtins_are_described() {
+        let registry = ConverterRegistry::with_builtins();
+        for info in registry.infos() {
+            assert!(!info.description().is_empty(), "{}", info.name());
+            assert_eq!(1, info.categories().len(), "{}", info.name());
+        }
+        assert_eq!(registry.names().count(), registry.infos().count());
+
+        let rot13 = registry.info("rot13").unwrap();
+        assert!(rot13.in_category("cipher") && rot13.is_reversible());
+        assert!(!registry.info("upper").unwrap().is_reversible());
+        #[cfg(feature = "config")]
+        assert!(registry.info("wrap").unwrap().is_configurable());
+    }
+
+    #[cfg(feature = "converters")]
+    #[test]
+    fn builtins_convert_into_buffers() {
+        let registry = ConverterRegistry::with_builtins();
+        let input = "  Hello, Wörld ΑΣ\r\n\tsecond  line \n";
+        let mut output = String::from("left over from before");
+
+        for name in registry.names() {
+            let converter = registry.get(name).unwrap();
+            // Decoders reject this input, and random converters like zalgo cannot be compared
+            let Ok(expected) = converter.try_convert(input) else {
+                continue;
+            };
+            if expected != converter.convert(input) {
+                continue;
+            }
+
+            converter.convert_into(input, &mut output);
+            assert_eq!(expected, output, "{name}");
+        }
+    }
+
+    #[cfg(all(feature = "converters", feature = "config"))]
+    #[test]
+    fn configures_vigenere_key() {
+        let registry = ConverterRegistry::with_builtins();
+        let mut params = Map::new();
+        params.insert("key".to_owned(), "lemon".into());
+
+        let encode = registry.configure("vigenere", &params).unwrap();
+        let decode = registry.configure("vigenere-decode", &params).unwrap();
+        assert_eq!("Lxfopv", encode.convert("Attack"));
+        assert_eq!("Attack", decode.convert("Lxfopv"));
+        assert_eq!("Attack", registry.convert("vigenere", "Attack").unwrap());
+    }
+}
@@ -0,0 +1,258 @@
+//! Interactive mode, converting every line typed with the selected pipeline
+//!
+//! Lines starting with `:` are commands, every other line is converted and the output printed:
+//!
+//! - `:use NAME...` selects the converters to run, in order, by their name in the registry
+//! - `:set KEY VALUE` overrides a field of the last converter selected, with `VALUE` read as
+//!   JSON or as a string otherwise. Needs the `config` feature
+//! - `:copy` puts the last output into the clipboard. Needs the `clipboard` feature
+//! - `:list` lists the converters that can be selected, `:show` the ones selected
+//! - `:help` lists the commands, and `:quit` leaves
+//!
+//! # Examples
+//!
+//! ```
+//! use text_converter::{repl::Repl, ConverterRegistry};
+//!
+//! let mut repl = Repl::new(ConverterRegistry::with_builtins());
+//! repl.eval(":use trim upper");
+//! assert_eq!(Some("HELLO".to_owned()), repl.eval("  hello  "));
+//! assert_eq!(None, repl.eval(":quit"));
+//! ```
+
+#[cfg(feature = "clipboard")]
+use crate::TextClipboard;
+use crate::{pipeline::Pipeline, ConversionError, ConverterRegistry, TextConverter};
+#[cfg(feature = "config")]
+use serde_json::{Map, Value};
+use std::io::{BufRead, Write};
+
+const HELP: &str = "\
+:use NAME...     convert with these converters, in order
+:set KEY VALUE   set a field of the last converter
+:copy            put the last output into the clipboard
+:list            list the converters
+:show            show the converters in use
+:help            show this help
+:quit            leave";
+
+/// A converter selected with `:use`, and the fields set on it with `:set`
+#[derive(Debug, Clone)]
+struct Stage {
+    name: String,
+    #[cfg(feature = "config")]
+    params: Map<String, Value>,
+}
+
+/// State of an interactive session: the converters that can be selected, the ones that are, and
+/// the last output
+#[derive(Debug)]
+pub struct Repl {
+    registry: ConverterRegistry,
+    stages: Vec<Stage>,
+    pipeline: Pipeline,
+    last_output: Option<String>,
+}
+
+impl Repl {
+    /// Starts a session with the converters of `registry`, where none is selected yet so lines
+    /// are printed unchanged
+    pub fn new(registry: ConverterRegistry) -> Self {
+        Self {
+            registry,
+            stages: Vec::new(),
+            pipeline: Pipeline::new(),
+            last_output: None,
+        }
+    }
+
+    /// Reads lines from `input` until it ends or `:quit` is typed, writing a `> ` prompt to
+    /// `output` before each of them and what [eval](Self::eval()) returns after
+    ///
+    /// # Errors
+    /// [`ConversionError::Io`] if reading `input` or writing `output` fails. Failed conversions
+    /// and commands are written to `output` instead
+    pub fn run(
+        &mut self,
+        input: impl BufRead,
+        mut output: impl Write,
+    ) -> Result<(), ConversionError> {
+        let mut lines = input.lines();
+        loop {
+            write!(output, "> ")?;
+            output.flush()?;
+
+            let Some(line) = lines.next().transpose()? else {
+                return Ok(writeln!(output)?);
+            };
+            match self.eval(&line) {
+                Some(reply) => writeln!(output, "{reply}")?,
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Runs the command on `line`, or converts it if it is not one
+    ///
+    /// # Returns
+    /// What to print: the output of the conversion, the reply to the command, or the error
+    /// that happened. `None` if the line is `:quit`
+    pub fn eval(&mut self, line: &str) -> Option<String> {
+        let Some(command) = line.strip_prefix(':') else {
+            return Some(match self.pipeline.try_new_from_text(line) {
+                Ok(output) => self.last_output.insert(output).clone(),
+                Err(e) => format!("error: {e}"),
+            });
+        };
+
+        let mut words = command.split_whitespace();
+        let reply = match words.next().unwrap_or_default() {
+            "use" => self.select(words.map(str::to_owned).collect()),
+            "set" => self.set(words.next(), words.collect::<Vec<_>>().join(" ")),
+            "copy" => self.copy(),
+            "list" => Ok(self.registry.names().collect::<Vec<_>>().join(" ")),
+            "show" => Ok(self.describe()),
+            "help" => Ok(HELP.to_owned()),
+            "quit" | "q" => return None,
+            other => Err(ConversionError::InvalidInput(format!(
+                "unknown command `:{other}`, see `:help`"
+            ))),
+        };
+
+        Some(reply.unwrap_or_else(|e| format!("error: {e}")))
+    }
+
+    fn select(&mut self, names: Vec<String>) -> Result<String, ConversionError> {
+        let stages = names
+            .into_iter()
+            .map(|name| Stage {
+                name,
+                #[cfg(feature = "config")]
+                params: Map::new(),
+            })
+            .collect();
+        self.rebuild(stages)?;
+        Ok(self.describe())
+    }
+
+    #[cfg(feature = "config")]
+    fn set(&mut self, key: Option<&str>, value: String) -> Result<String, ConversionError> {
+        let (Some(key), false) = (key, value.is_empty()) else {
+            return Err(ConversionError::InvalidInput(
+                "expected `:set KEY VALUE`".to_owned(),
+            ));
+        };
+        let value = serde_json::from_str(&value).unwrap_or(Value::String(value));
+
+        let mut stages = self.stages.clone();
+        stages
+            .last_mut()
+            .ok_or_else(|| ConversionError::InvalidInput("no converter in use".to_owned()))?
+            .params
+            .insert(key.to_owned(), value);
+        self.rebuild(stages)?;
+        Ok(self.describe())
+    }
+
+    #[cfg(not(feature = "config"))]
+    fn set(&mut self, _key: Option<&str>, _value: String) -> Result<String, ConversionError> {
+        Err(ConversionError::InvalidInput(
+            "setting fields needs the `config` feature".to_owned(),
+        ))
+    }
+
+    #[cfg(feature = "clipboard")]
+    fn copy(&self) -> Result<String, ConversionError> {
+        let output = self
+            .last_output
+            .as_ref()
+            .ok_or_else(|| ConversionError::InvalidInput("nothing converted yet".to_owned()))?;
+        TextClipboard::new()?.set_text(output)?;
+        Ok("copied".to_owned())
+    }
+
+    #[cfg(not(feature = "clipboard"))]
+    fn copy(&self) -> Result<String, ConversionError> {
+        Err(ConversionError::InvalidInput(
+            "copying needs the `clipboard` feature".to_owned(),
+        ))
+    }
+
+    /// Replaces the pipeline with `stages`, keeping the current one if one of them does not fit
+    fn rebuild(&mut self, stages: Vec<Stage>) -> Result<(), ConversionError> {
+        let mut pipeline = Pipeline::new();
+        for stage in &stages {
+            let converter = self
+                .registry
+                .get_shared(&stage.name)
+                .ok_or_else(|| ConversionError::UnknownConverter(stage.name.clone()))?;
+            #[cfg(feature = "config")]
+            let converter = if stage.params.is_empty() {
+                converter
+            } else {
+                self.registry.configure(&stage.name, &stage.params)?
+            };
+            pipeline.push_shared(converter);
+        }
+
+        self.stages = stages;
+        self.pipeline = pipeline;
+        Ok(())
+    }
+
+    /// The converters in use, with the fields set on them
+    fn describe(&self) -> String {
+        if self.stages.is_empty() {
+            return "no converter in use, lines are printed unchanged".to_owned();
+        }
+
+        let stages: Vec<_> = self
+            .stages
+            .iter()
+            .map(|stage| {
+                #[cfg(feature = "config")]
+                if !stage.params.is_empty() {
+                    return format!("{} {}", stage.name, Value::Object(stage.params.clone()));
+                }
+                stage.name.clone()
+            })
+            .collect();
+        format!("using {}", stages.join(" | "))
+    }
+}
+
+#[cfg(all(test, feature = "converters"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_commands_and_conversions() {
+        let input = ":use trim upper\n  hey  \n:use shout\nhey\n:quit\nnever read\n";
+        let mut output = Vec::new();
+        Repl::new(ConverterRegistry::with_builtins())
+            .run(input.as_bytes(), &mut output)
+            .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(
+            "> using trim | upper\n> HEY\n> error: unknown converter `shout`\n> HEY\n> ",
+            output
+        );
+    }
+
+    #[cfg(feature = "config")]
+    #[test]
+    fn sets_fields() {
+        let mut repl = Repl::new(ConverterRegistry::with_builtins());
+        assert!(repl.eval(":set width 3").unwrap().starts_with("error"));
+
+        repl.eval(":use wrap");
+        assert_eq!(
+            Some(r#"using wrap {"width":5}"#.to_owned()),
+            repl.eval(":set width 5")
+        );
+        assert_eq!(Some("ab cd\nef".to_owned()), repl.eval("ab cd ef"));
+        assert!(repl.eval(":set width wide").unwrap().starts_with("error"));
+        assert_eq!(Some("ab cd\nef".to_owned()), repl.eval("ab cd ef"));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::Bom;
+use std::{fmt, time::Duration};
+
+/// Statistics about a conversion, from the `*_with_report` methods of
+/// [`TextConverter`](crate::TextConverter)
+///
+/// Displays as a one-line summary, handy for logs
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{converters::Uppercase, TextConverter};
+///
+/// let (output, report) = Uppercase.new_from_text_with_report("Hello\nWorld");
+/// assert_eq!("HELLO\nWORLD", output);
+/// assert_eq!((11, 11), (report.input_chars, report.output_chars));
+/// assert_eq!((2, 2), (report.input_lines, report.output_lines));
+/// assert_eq!(8, report.changed_chars);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ConversionReport {
+    /// Length of the input in bytes
+    pub input_bytes: usize,
+    /// Length of the output in bytes
+    pub output_bytes: usize,
+    /// Length of the input in characters
+    pub input_chars: usize,
+    /// Length of the output in characters
+    pub output_chars: usize,
+    /// Number of lines in the input
+    pub input_lines: usize,
+    /// Number of lines in the output
+    pub output_lines: usize,
+    /// Number of characters changed, added or removed. Leaving out what the input and output
+    /// start and end with, characters that differ at the same position count, and so does the
+    /// difference in length
+    pub changed_chars: usize,
+    /// Time the conversion itself took
+    pub elapsed: Duration,
+    /// Byte order mark the input file started with, which the converter did not see. Always
+    /// `None` for inputs that are not read from a file
+    pub bom: Option<Bom>,
+}
+
+impl ConversionReport {
+    pub(crate) fn new(input: &str, output: &str, elapsed: Duration) -> Self {
+        Self {
+            input_bytes: input.len(),
+            output_bytes: output.len(),
+            input_chars: input.chars().count(),
+            output_chars: output.chars().count(),
+            input_lines: input.lines().count(),
+            output_lines: output.lines().count(),
+            changed_chars: changed_chars(input, output),
+            elapsed,
+            bom: None,
+        }
+    }
+}
+
+impl fmt::Display for ConversionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} -> {} characters, {} -> {} lines, {} changed in {:.2?}",
+            self.input_chars,
+            self.output_chars,
+            self.input_lines,
+            self.output_lines,
+            self.changed_chars,
+            self.elapsed
+        )?;
+
+        match self.bom {
+            Some(bom) => write!(f, ", input had a {bom} byte order mark"),
+            None => Ok(()),
+        }
+    }
+}
+
+fn changed_chars(input: &str, output: &str) -> usize {
+    let prefix = input
+        .chars()
+        .zip(output.chars())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (input, output): (Vec<_>, Vec<_>) = (
+        input.chars().skip(prefix).collect(),
+        output.chars().skip(prefix).collect(),
+    );
+    let suffix = input
+        .iter()
+        .rev()
+        .zip(output.iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (input, output) = (
+        &input[..input.len() - suffix],
+        &output[..output.len() - suffix],
+    );
+
+    let differing = input.iter().zip(output).filter(|(a, b)| a != b).count();
+    differing + input.len().abs_diff(output.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_changes() {
+        assert_eq!(0, changed_chars("same", "same"));
+        assert_eq!(1, changed_chars("abc", "xabc"));
+        assert_eq!(8, changed_chars("Hello World", "HELLO WORLD"));
+        assert_eq!(3, changed_chars("", "new"));
+
+        let report = ConversionReport::new("é\n", "e", Duration::from_millis(2));
+        assert_eq!(
+            (3, 1, 1, 1),
+            (
+                report.input_bytes,
+                report.output_bytes,
+                report.input_lines,
+                report.output_lines
+            )
+        );
+        assert_eq!(
+            "2 -> 1 characters, 1 -> 1 lines, 2 changed in 2.00ms",
+            report.to_string()
+        );
+
+        let report = ConversionReport {
+            bom: Some(Bom::Utf8),
+            ..report
+        };
+        assert!(report
+            .to_string()
+            .ends_with(", input had a UTF-8 byte order mark"));
+    }
+}
@@ -0,0 +1,234 @@
+#[cfg(feature = "std")]
+use crate::OutputOptions;
+use crate::{ConversionError, TextConverter};
+use alloc::string::String;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// Converters with a well-defined inverse, such as ciphers and encodings
+///
+/// The `decode_*` methods mirror the `new_from_*` ones from [`TextConverter`]. Every other input
+/// source is available for decoding through the [decoder](Self::decoder()) adapter
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{ReversibleConverter, TextConverter};
+///
+/// struct Quote;
+///
+/// impl TextConverter for Quote {
+///     fn converter(&self, input: impl AsRef<str>) -> String {
+///         input.as_ref().lines().map(|line| format!("> {line}\n")).collect()
+///     }
+/// }
+///
+/// impl ReversibleConverter for Quote {
+///     fn deconverter(&self, input: impl AsRef<str>) -> String {
+///         let lines = input.as_ref().lines();
+///         lines.map(|line| line.trim_start_matches("> ").to_owned() + "\n").collect()
+///     }
+/// }
+///
+/// let quoted = Quote.new_from_text("hello\nworld\n");
+/// assert_eq!("> hello\n> world\n", quoted);
+/// assert_eq!("hello\nworld\n", Quote.decode_from_text(quoted));
+/// ```
+pub trait ReversibleConverter: TextConverter {
+    /// Undoes the transformation done by [converter](TextConverter::converter())
+    fn deconverter(&self, input: impl AsRef<str>) -> String;
+
+    /// Fallible version of the [deconverter](Self::deconverter()) method, used by all
+    /// `try_decode_*` methods
+    ///
+    /// Converters whose inverse can fail on some inputs, like decoders given text they did not
+    /// encode, should override this. Defaults to calling [deconverter](Self::deconverter())
+    fn try_deconverter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        Ok(self.deconverter(input))
+    }
+
+    /// Adapter whose [converter](TextConverter::converter()) is this converter's
+    /// [deconverter](Self::deconverter()), to decode from any input source
+    fn decoder(&self) -> Decode<&Self> {
+        Decode(self)
+    }
+
+    /// Decodes given input with the [deconverter](Self::deconverter()) method
+    fn decode_from_text(&self, input: impl AsRef<str>) -> String {
+        self.deconverter(input)
+    }
+
+    /// Decodes given input with the [try_deconverter](Self::try_deconverter()) method
+    ///
+    /// # Errors
+    /// Returns whatever error the converter reports
+    fn try_decode_from_text(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.try_deconverter(input)
+    }
+
+    /// Fetches clipboard contents and decodes them with the [deconverter](Self::deconverter())
+    /// method
+    ///
+    /// # Panics
+    /// Same as [new_from_clipboard](TextConverter::new_from_clipboard())
+    #[cfg(feature = "clipboard")]
+    fn decode_from_clipboard(&self) -> String {
+        self.decoder().new_from_clipboard()
+    }
+
+    /// Fetches clipboard contents and decodes them with the
+    /// [try_deconverter](Self::try_deconverter()) method
+    ///
+    /// # Errors
+    /// Same as [try_new_from_clipboard](TextConverter::try_new_from_clipboard())
+    #[cfg(feature = "clipboard")]
+    fn try_decode_from_clipboard(&self) -> Result<String, ConversionError> {
+        self.decoder().try_new_from_clipboard()
+    }
+
+    /// Fetches file contents and decodes them with the [deconverter](Self::deconverter()) method
+    ///
+    /// # Panics
+    /// Same as [new_from_file](TextConverter::new_from_file())
+    ///
+    /// # Returns
+    /// - The decoded string from the file contents
+    /// - Outputs the decoded text next to the original, in a file called originalname_decoded
+    ///   with the original extension
+    #[cfg(feature = "std")]
+    fn decode_from_file(&self, path: impl AsRef<Path>) -> String {
+        self.decoder()
+            .new_from_file_with(path, &OutputOptions::new().suffix("_decoded"))
+    }
+
+    /// Fetches file contents and decodes them with the
+    /// [try_deconverter](Self::try_deconverter()) method
+    ///
+    /// # Errors
+    /// Same as [try_new_from_file](TextConverter::try_new_from_file())
+    ///
+    /// # Returns
+    /// - The decoded string from the file contents
+    /// - Outputs the decoded text next to the original, in a file called originalname_decoded
+    ///   with the original extension
+    #[cfg(feature = "std")]
+    fn try_decode_from_file(&self, path: impl AsRef<Path>) -> Result<String, ConversionError> {
+        self.decoder()
+            .try_new_from_file_with(path, &OutputOptions::new().suffix("_decoded"))
+    }
+}
+
+impl<T: ReversibleConverter + ?Sized> ReversibleConverter for &T {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        (**self).deconverter(input)
+    }
+
+    fn try_deconverter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        (**self).try_deconverter(input)
+    }
+}
+
+/// Swaps the two directions of a [`ReversibleConverter`], so that converting decodes
+///
+/// Usually created with [`ReversibleConverter::decoder`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Decode<C>(pub C);
+
+impl<C: ReversibleConverter> TextConverter for Decode<C> {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.0.deconverter(input)
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.0.try_deconverter(input)
+    }
+}
+
+impl<C: ReversibleConverter> ReversibleConverter for Decode<C> {
+    fn deconverter(&self, input: impl AsRef<str>) -> String {
+        self.0.converter(input)
+    }
+
+    fn try_deconverter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.0.try_converter(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use std::{env, fs};
+
+    struct Shift;
+
+    impl TextConverter for Shift {
+        fn converter(&self, input: impl AsRef<str>) -> String {
+            input
+                .as_ref()
+                .chars()
+                .map(|c| char::from_u32(c as u32 + 1).unwrap())
+                .collect()
+        }
+    }
+
+    impl ReversibleConverter for Shift {
+        fn deconverter(&self, input: impl AsRef<str>) -> String {
+            input
+                .as_ref()
+                .chars()
+                .map(|c| char::from_u32(c as u32 - 1).unwrap())
+                .collect()
+        }
+    }
+
+    struct Exclaim;
+
+    impl TextConverter for Exclaim {
+        fn converter(&self, input: impl AsRef<str>) -> String {
+            input.as_ref().to_owned() + "!"
+        }
+    }
+
+    impl ReversibleConverter for Exclaim {
+        fn deconverter(&self, input: impl AsRef<str>) -> String {
+            input
+                .as_ref()
+                .strip_suffix('!')
+                .unwrap_or(input.as_ref())
+                .to_owned()
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let encoded = Shift.new_from_text("HAL");
+        assert_eq!("IBM", encoded);
+        assert_eq!("HAL", Shift.decode_from_text(&encoded));
+        assert_eq!("HAL", Decode(Shift).new_from_text(&encoded));
+        assert_eq!("IBM", Decode(Shift).decode_from_text("HAL"));
+    }
+
+    #[test]
+    fn pipelines_decode_in_reverse() {
+        let pipeline = (Shift, Exclaim);
+        let encoded = pipeline.new_from_text("abc");
+        assert_eq!("bcd!", encoded);
+        assert_eq!("abc", pipeline.decode_from_text(&encoded));
+
+        let stages = vec![Shift, Shift];
+        assert_eq!("abc", stages.decode_from_text(stages.new_from_text("abc")));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn decode_from_file() {
+        let path = env::temp_dir().join("text_converter_decode.txt");
+        fs::write(&path, "IBM").unwrap();
+
+        assert_eq!("HAL", Shift.try_decode_from_file(&path).unwrap());
+        let output = env::temp_dir().join("text_converter_decode_decoded.txt");
+        assert_eq!("HAL", fs::read_to_string(output).unwrap());
+    }
+}
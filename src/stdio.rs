@@ -0,0 +1,49 @@
+use crate::ConversionError;
+use std::io::{self, ErrorKind, IsTerminal, Read, Write};
+
+/// Writes `text` to the locked stdout and flushes it
+///
+/// A closed pipe on the other end (e.g. when piping into `head`) is not treated as an error
+///
+/// # Errors
+/// [`ConversionError::Io`] if writing to stdout fails
+pub fn write_to_stdout(text: impl AsRef<str>) -> Result<(), ConversionError> {
+    let mut stdout = io::stdout().lock();
+    let result = stdout
+        .write_all(text.as_ref().as_bytes())
+        .and_then(|()| stdout.flush());
+
+    ignore_broken_pipe(result)
+}
+
+/// Reads the whole locked stdin, refusing to wait on an interactive terminal
+///
+/// # Errors
+/// - [`ConversionError::Io`] if stdin is an interactive terminal or if reading fails
+/// - [`ConversionError::Encoding`] if stdin is not valid UTF-8
+pub fn read_stdin() -> Result<String, ConversionError> {
+    let stdin = io::stdin();
+    if stdin.is_terminal() {
+        return Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            "stdin is a terminal, pipe some input into it instead",
+        )
+        .into());
+    }
+
+    let mut bytes = Vec::new();
+    stdin.lock().read_to_end(&mut bytes)?;
+    let input = String::from_utf8(bytes).map_err(|e| e.utf8_error())?;
+
+    Ok(input)
+}
+
+/// Turns an error caused by the reader of stdout going away into a success
+pub(crate) fn ignore_broken_pipe<T: Default>(
+    result: Result<T, impl Into<ConversionError>>,
+) -> Result<T, ConversionError> {
+    match result.map_err(Into::into) {
+        Err(ConversionError::Io(e)) if e.kind() == ErrorKind::BrokenPipe => Ok(T::default()),
+        result => result,
+    }
+}
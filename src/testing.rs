@@ -0,0 +1,244 @@
+//! [proptest](https://docs.rs/proptest) strategies and properties for checking custom converters
+//!
+//! The strategies generate the inputs converters tend to get wrong: any Unicode text, characters
+//! piled up with combining marks and joiners, and lines long enough to hit buffer sizes. The
+//! properties return a [`TestCaseError`] when they do not hold, so they can be used with `?`
+//! inside [`proptest!`](proptest::proptest)
+//!
+//! Enabled by the `proptest` feature
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use text_converter::{testing, TextConverter};
+//!
+//! struct Shout;
+//!
+//! impl TextConverter for Shout {
+//!     fn converter(&self, input: impl AsRef<str>) -> String {
+//!         input.as_ref().to_uppercase()
+//!     }
+//! }
+//!
+//! proptest! {
+//!     fn shout_holds_up(input in testing::text()) {
+//!         testing::assert_idempotent(&Shout, &input)?;
+//!         testing::assert_length_bounds(&Shout, &input, 1..=3)?;
+//!         testing::assert_valid_utf8(&Shout, &input)?;
+//!     }
+//! }
+//! # shout_holds_up();
+//! ```
+
+use crate::{ReversibleConverter, TextConverter};
+use core::{ops::RangeInclusive, str};
+use proptest::{
+    collection::vec,
+    prelude::*,
+    test_runner::{TestCaseError, TestCaseResult},
+};
+
+/// First and last combining marks, piled on characters for accents and zalgo
+const COMBINING: (char, char) = ('\u{300}', '\u{36f}');
+
+/// Characters with no width of their own, which change how their neighbours are shown
+const INVISIBLE: [char; 6] = [
+    '\u{200b}', // zero width space
+    '\u{200d}', // zero width joiner
+    '\u{200e}', // left-to-right mark
+    '\u{200f}', // right-to-left mark
+    '\u{fe0f}', // emoji presentation selector
+    '\u{feff}', // byte order mark
+];
+
+/// Any text, made of any Unicode characters mixed with the whitespace and line breaks
+/// converters usually split on
+///
+/// # Examples
+///
+/// ```
+/// use proptest::{strategy::{Strategy, ValueTree}, test_runner::TestRunner};
+/// use text_converter::testing;
+///
+/// let input = testing::arbitrary_text().new_tree(&mut TestRunner::default()).unwrap().current();
+/// assert!(input.chars().count() < 256);
+/// ```
+pub fn arbitrary_text() -> impl Strategy<Value = String> {
+    let char = prop_oneof![
+        3 => any::<char>(),
+        1 => prop::sample::select(vec![' ', '\t', '\n', '\r']),
+    ];
+    vec(char, 0..256).prop_map(String::from_iter)
+}
+
+/// Text made of clusters that render as one character but hold many: bases with up to 32
+/// combining marks, emoji joined with zero width joiners, and bidirectional marks
+pub fn combining_sequences() -> impl Strategy<Value = String> {
+    let combining = (
+        any::<char>(),
+        vec(prop::char::range(COMBINING.0, COMBINING.1), 1..32),
+    )
+        .prop_map(|(base, marks)| core::iter::once(base).chain(marks).collect::<String>());
+    let joined =
+        vec(prop::sample::select(vec!['👩', '👨', '👧', '❤', '🏳']), 2..6).prop_map(|emoji| {
+            emoji
+                .iter()
+                .map(char::to_string)
+                .collect::<Vec<_>>()
+                .join("\u{200d}")
+        });
+    let invisible = prop::sample::select(INVISIBLE.to_vec()).prop_map(String::from);
+
+    vec(prop_oneof![combining, joined, invisible], 1..16).prop_map(|clusters| clusters.concat())
+}
+
+/// A single line of 16 KiB to 1 MiB, repeating a short piece of text, to catch converters that
+/// slow down badly or mishandle lines longer than their buffers
+pub fn huge_lines() -> impl Strategy<Value = String> {
+    let piece = vec(
+        any::<char>().prop_filter("on one line", |c| *c != '\n' && *c != '\r'),
+        1..8,
+    )
+    .prop_map(String::from_iter);
+
+    (piece, 16 * 1024..1024 * 1024usize).prop_map(|(piece, len)| {
+        let mut line = piece.repeat(len / piece.len() + 1);
+        let end = (len..)
+            .find(|&end| line.is_char_boundary(end))
+            .unwrap_or(line.len());
+        line.truncate(end);
+        line
+    })
+}
+
+/// Any of the inputs above, mostly [arbitrary_text] with some of the others
+pub fn text() -> impl Strategy<Value = String> {
+    prop_oneof![
+        8 => arbitrary_text(),
+        3 => combining_sequences(),
+        1 => huge_lines(),
+    ]
+}
+
+/// Checks that converting the output of `converter` again leaves it unchanged, as expected from
+/// case changes and cleanups
+///
+/// # Errors
+/// A [`TestCaseError`] showing both outputs if they differ
+pub fn assert_idempotent(converter: &impl TextConverter, input: &str) -> TestCaseResult {
+    let once = converter.converter(input);
+    let twice = converter.converter(&once);
+    prop_assert_eq!(once, twice, "converting again changed the output");
+
+    Ok(())
+}
+
+/// Checks that the output of `converter` has between `per_char.start()` and `per_char.end()`
+/// characters for each character of `input`, so an empty input must give an empty output
+///
+/// # Errors
+/// A [`TestCaseError`] with both lengths if the output is too short or too long
+pub fn assert_length_bounds(
+    converter: &impl TextConverter,
+    input: &str,
+    per_char: RangeInclusive<usize>,
+) -> TestCaseResult {
+    let input_len = input.chars().count();
+    let output_len = converter.converter(input).chars().count();
+    let bounds = input_len * per_char.start()..=input_len * per_char.end();
+    prop_assert!(
+        bounds.contains(&output_len),
+        "{} characters converted into {}, expected {:?}",
+        input_len,
+        output_len,
+        bounds
+    );
+
+    Ok(())
+}
+
+/// Checks that [converter](TextConverter::converter()),
+/// [converter_into](TextConverter::converter_into()) and
+/// [converter_in_place](TextConverter::converter_in_place()) all give the same valid UTF-8, as
+/// the ones working on bytes directly can break it
+///
+/// # Errors
+/// A [`TestCaseError`] if one of the outputs is not valid UTF-8 or differs from the others
+pub fn assert_valid_utf8(converter: &impl TextConverter, input: &str) -> TestCaseResult {
+    let output = converter.converter(input);
+    prop_assert!(
+        str::from_utf8(output.as_bytes()).is_ok(),
+        "invalid UTF-8 from converter"
+    );
+
+    let mut into = String::from("left over");
+    converter.converter_into(input, &mut into);
+    prop_assert!(
+        str::from_utf8(into.as_bytes()).is_ok(),
+        "invalid UTF-8 from converter_into"
+    );
+    prop_assert_eq!(&output, &into, "converter_into differs from converter");
+
+    let mut in_place = input.to_owned();
+    if converter.converter_in_place(&mut in_place) {
+        prop_assert!(
+            str::from_utf8(in_place.as_bytes()).is_ok(),
+            "invalid UTF-8 from converter_in_place"
+        );
+        prop_assert_eq!(
+            &output,
+            &in_place,
+            "converter_in_place differs from converter"
+        );
+    }
+
+    Ok(())
+}
+
+/// Checks that [deconverter](ReversibleConverter::deconverter()) gives back `input` from the
+/// output of `converter`
+///
+/// # Errors
+/// A [`TestCaseError`] if decoding fails or gives back something else
+pub fn assert_round_trip(converter: &impl ReversibleConverter, input: &str) -> TestCaseResult {
+    let encoded = converter.converter(input);
+    let decoded = converter
+        .try_deconverter(&encoded)
+        .map_err(|e| TestCaseError::fail(format!("decoding {encoded:?} failed: {e}")))?;
+    prop_assert_eq!(input, decoded, "decoding did not give back the input");
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "converters"))]
+mod tests {
+    use super::*;
+    use crate::converters::{CaesarCipher, Rot13, Trim, Uppercase, UrlEncode};
+
+    proptest! {
+        #[test]
+        fn builtins_hold_up(input in text()) {
+            assert_idempotent(&Trim, &input)?;
+            assert_length_bounds(&Trim, &input, 0..=1)?;
+            assert_valid_utf8(&Uppercase, &input)?;
+            assert_valid_utf8(&Rot13, &input)?;
+            assert_round_trip(&Rot13, &input)?;
+            assert_round_trip(&UrlEncode::default(), &input)?;
+        }
+
+        #[test]
+        fn generates_pathological_text(clusters in combining_sequences(), line in huge_lines()) {
+            prop_assert!(!clusters.is_ascii());
+            prop_assert!((16 * 1024..1024 * 1024 + 4).contains(&line.len()));
+            prop_assert!(!line.contains('\n'));
+        }
+    }
+
+    #[test]
+    fn reports_broken_properties() {
+        assert!(assert_idempotent(&CaesarCipher::new(1), "abc").is_err());
+        assert!(assert_length_bounds(&Uppercase, "ß", 1..=1).is_err());
+        assert!(assert_round_trip(&CaesarCipher::new(1), "abc").is_ok());
+    }
+}
@@ -0,0 +1,151 @@
+//! Spans and events for the `tracing` feature, which expand to nothing without it
+//!
+//! Every span is at the debug level, with the module it comes from as its target, like
+//! `text_converter::pipeline`. It ends with a `finished` event recording how long it took in
+//! `elapsed_us`
+
+#[cfg(feature = "tracing")]
+use std::time::Instant;
+#[cfg(feature = "std")]
+use std::{fs, io, path::Path};
+
+/// Enters a debug span named `$name` with the given fields until the end of the scope, and
+/// emits a `finished` event with its duration when leaving it
+macro_rules! timed {
+    ($name:literal $(, $($fields:tt)*)?) => {
+        #[cfg(feature = "tracing")]
+        let _timed = $crate::trace::Timed::new(tracing::debug_span!($name $(, $($fields)*)?));
+    };
+}
+
+/// Emits a debug event with the given fields and message
+#[cfg(feature = "std")]
+macro_rules! event {
+    ($($fields:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($fields)*);
+    };
+}
+
+#[cfg(feature = "clipboard")]
+pub(crate) use event;
+pub(crate) use timed;
+
+#[cfg(feature = "tracing")]
+pub(crate) struct Timed {
+    _span: tracing::span::EnteredSpan,
+    start: Instant,
+}
+
+#[cfg(feature = "tracing")]
+impl Timed {
+    pub(crate) fn new(span: tracing::Span) -> Self {
+        Self {
+            _span: span.entered(),
+            start: Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Drop for Timed {
+    // Runs before the span is exited, so the event belongs to it
+    fn drop(&mut self) {
+        let elapsed_us = u64::try_from(self.start.elapsed().as_micros()).unwrap_or(u64::MAX);
+        tracing::debug!(elapsed_us, "finished");
+    }
+}
+
+/// Runs stage number `index` of a pipeline on `input`, in a `stage` span
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+pub(crate) fn stage<T>(index: usize, input: &str, run: impl FnOnce(&str) -> T) -> T {
+    timed!("stage", index, bytes = input.len());
+    run(input)
+}
+
+/// Reads the file at `path`, in a `read_file` span
+#[cfg(feature = "std")]
+pub(crate) fn read_file(path: &Path) -> io::Result<Vec<u8>> {
+    timed!("read_file", path = %path.display());
+    let bytes = fs::read(path)?;
+    event!(bytes = bytes.len(), "read");
+
+    Ok(bytes)
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use super::*;
+    use crate::{fixtures, pipeline::Pipeline, DirOptions, TextConverter};
+    use std::sync::{Arc, Mutex};
+    use tracing::{span, Event, Metadata, Subscriber};
+
+    /// Remembers the names of the spans it sees and counts the `finished` events
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<(Vec<&'static str>, usize)>>);
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+            let mut recorded = self.0.lock().unwrap();
+            recorded.0.push(span.metadata().name());
+            span::Id::from_u64(recorded.0.len() as u64)
+        }
+
+        fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            if event.metadata().fields().field("elapsed_us").is_some() {
+                self.0.lock().unwrap().1 += 1;
+            }
+        }
+
+        fn enter(&self, _: &span::Id) {}
+
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    struct Exclaim;
+
+    impl TextConverter for Exclaim {
+        fn converter(&self, input: impl AsRef<str>) -> String {
+            input.as_ref().to_owned() + "!"
+        }
+    }
+
+    #[test]
+    fn spans_stages_and_files() {
+        let dir = fixtures::temp_dir("trace");
+        let path = dir.join("a.txt");
+        fs::write(&path, "hi").unwrap();
+        let mut pipeline = Pipeline::new();
+        pipeline.push(Exclaim).push(Exclaim);
+
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            assert_eq!("hi!!", pipeline.new_from_text("hi"));
+            assert_eq!(b"hi".to_vec(), read_file(&path).unwrap());
+            let options = DirOptions::new().dry_run(true);
+            assert_eq!(
+                1,
+                Exclaim
+                    .new_from_dir(&dir, &options)
+                    .unwrap()
+                    .converted
+                    .len()
+            );
+        });
+
+        let (spans, finished) = recorder.0.lock().unwrap().clone();
+        assert_eq!(
+            vec!["pipeline", "stage", "stage", "read_file", "read_file"],
+            spans
+        );
+        assert_eq!(5, finished);
+    }
+}
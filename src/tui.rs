@@ -0,0 +1,281 @@
+//! Terminal interface for trying the converters of a registry on the clipboard or a file
+//!
+//! The converters are listed on the left, and the text converted by the highlighted one is shown
+//! on the right as a preview, above what the converter does. Moving with the arrow keys or `j`
+//! and `k` changes the preview, `Enter` writes it back to where the text came from, `r` reads the
+//! text again and `q` or `Esc` leaves
+
+use crate::{trace, write_atomic, Bom, ConversionError, ConverterRegistry, TextClipboard};
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    widgets::{Block, List, ListState, Paragraph, Wrap},
+    Frame,
+};
+use std::path::PathBuf;
+
+/// Where a [`Tui`] reads the text to convert, and writes the output of the converter applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TuiSource {
+    /// The text in the clipboard
+    Clipboard,
+    /// The contents of a file, replaced atomically when applying a converter
+    File(PathBuf),
+}
+
+/// Terminal interface listing converters and previewing their output, started with
+/// [run](Self::run())
+///
+/// Enabled by the `tui` feature
+///
+/// # Examples
+///
+/// ```no_run
+/// use text_converter::{ConverterRegistry, Tui, TuiSource};
+///
+/// Tui::new(ConverterRegistry::with_builtins(), TuiSource::File("notes.md".into()))
+///     .unwrap()
+///     .run()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Tui {
+    registry: ConverterRegistry,
+    names: Vec<String>,
+    list: ListState,
+    source: TuiSource,
+    input: String,
+    bom: Option<Bom>,
+    status: String,
+}
+
+impl Tui {
+    /// Reads the text to convert from `source`, highlighting the first converter of `registry`
+    ///
+    /// # Errors
+    /// Whatever error reading `source` reports
+    pub fn new(registry: ConverterRegistry, source: TuiSource) -> Result<Self, ConversionError> {
+        let names = registry.names().map(str::to_owned).collect();
+        let mut tui = Self {
+            registry,
+            names,
+            list: ListState::default().with_selected(Some(0)),
+            source,
+            input: String::new(),
+            bom: None,
+            status: String::new(),
+        };
+        tui.reload()?;
+
+        Ok(tui)
+    }
+
+    /// Takes over the terminal until `q` or `Esc` is pressed, restoring it before returning
+    ///
+    /// # Errors
+    /// [`ConversionError::Io`] if the terminal cannot be drawn on or read from. Failing to apply
+    /// a converter is shown at the bottom instead
+    pub fn run(mut self) -> Result<(), ConversionError> {
+        let mut terminal = ratatui::try_init()?;
+        let result = self.event_loop(&mut terminal);
+        ratatui::try_restore()?;
+
+        result
+    }
+
+    fn event_loop(
+        &mut self,
+        terminal: &mut ratatui::DefaultTerminal,
+    ) -> Result<(), ConversionError> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !self.on_key(key.code) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Handles a key press, returning whether to keep running
+    fn on_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                let last = self.names.len().saturating_sub(1);
+                self.list
+                    .select(self.list.selected().map(|i| (i + 1).min(last)));
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.list.select_previous(),
+            KeyCode::Enter => {
+                self.status = self.apply().unwrap_or_else(|e| format!("error: {e}"));
+            }
+            KeyCode::Char('r') => {
+                self.status = match self.reload() {
+                    Ok(()) => "reloaded".to_owned(),
+                    Err(e) => format!("error: {e}"),
+                };
+            }
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            _ => {}
+        }
+        true
+    }
+
+    /// Name of the highlighted converter
+    fn selected(&self) -> Option<&str> {
+        self.names.get(self.list.selected()?).map(String::as_str)
+    }
+
+    fn preview(&self) -> Result<String, ConversionError> {
+        match self.selected() {
+            Some(name) => self.registry.convert(name, &self.input),
+            None => Ok(self.input.clone()),
+        }
+    }
+
+    /// Writes the preview back to the source, which the next previews then start from
+    fn apply(&mut self) -> Result<String, ConversionError> {
+        let Some(name) = self.selected() else {
+            return Ok("no converter to apply".to_owned());
+        };
+        let name = name.to_owned();
+        let output = self.registry.convert(&name, &self.input)?;
+
+        match &self.source {
+            TuiSource::Clipboard => TextClipboard::new()?.set_text(&output)?,
+            TuiSource::File(path) => {
+                let bytes = match self.bom {
+                    Some(bom) => bom.encode(&output),
+                    None => output.clone().into_bytes(),
+                };
+                write_atomic(path, bytes)?;
+            }
+        }
+        self.input = output;
+
+        Ok(format!("applied `{name}`"))
+    }
+
+    fn reload(&mut self) -> Result<(), ConversionError> {
+        match &self.source {
+            TuiSource::Clipboard => self.input = TextClipboard::new()?.get_text()?,
+            TuiSource::File(path) => {
+                let bytes = trace::read_file(path)?;
+                let (input, bom) = Bom::decode(&bytes)?;
+                self.input = input.into_owned();
+                self.bom = bom;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, status] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(frame.area());
+        let [list, preview] =
+            Layout::horizontal([Constraint::Length(24), Constraint::Fill(1)]).areas(main);
+
+        let (text, style) = match self.preview() {
+            Ok(output) => (output, Style::default()),
+            Err(e) => (format!("error: {e}"), Style::default().fg(Color::Red)),
+        };
+        let title = match &self.source {
+            TuiSource::Clipboard => "Clipboard".to_owned(),
+            TuiSource::File(path) => path.display().to_string(),
+        };
+        let description = self
+            .selected()
+            .and_then(|name| self.registry.info(name))
+            .map_or("", |info| info.description());
+        frame.render_widget(
+            Paragraph::new(text)
+                .style(style)
+                .wrap(Wrap { trim: false })
+                .block(
+                    Block::bordered()
+                        .title(title)
+                        .title_bottom(description.to_owned()),
+                ),
+            preview,
+        );
+
+        let converters = List::new(self.names.iter().map(String::as_str))
+            .block(Block::bordered().title("Converters"))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(converters, list, &mut self.list);
+
+        let help = "↑↓ choose  Enter apply  r reload  q quit";
+        let line = match self.status.is_empty() {
+            true => help.to_owned(),
+            false => format!("{}  ·  {help}", self.status),
+        };
+        frame.render_widget(Paragraph::new(line), status);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+    use std::{env, fs};
+
+    fn registry() -> ConverterRegistry {
+        let mut registry = ConverterRegistry::new();
+        registry
+            .register("lower", crate::converters::Lowercase)
+            .register("upper", crate::converters::Uppercase)
+            .describe("lower", crate::ConverterInfo::new("Lowercase it"));
+        registry
+    }
+
+    #[test]
+    fn previews_and_applies_to_files() {
+        let path = env::temp_dir().join("text_converter_tui.txt");
+        fs::write(&path, "Hello").unwrap();
+        let mut tui = Tui::new(registry(), TuiSource::File(path.clone())).unwrap();
+
+        assert_eq!("hello", tui.preview().unwrap());
+        assert!(tui.on_key(KeyCode::Down));
+        assert_eq!("HELLO", tui.preview().unwrap());
+        assert!(tui.on_key(KeyCode::Down));
+        assert_eq!("HELLO", tui.preview().unwrap());
+
+        assert!(tui.on_key(KeyCode::Enter));
+        assert_eq!("applied `upper`", tui.status);
+        assert_eq!("HELLO", fs::read_to_string(&path).unwrap());
+
+        fs::write(&path, "Again").unwrap();
+        assert!(tui.on_key(KeyCode::Char('r')));
+        assert!(tui.on_key(KeyCode::Up));
+        assert_eq!("again", tui.preview().unwrap());
+        assert!(!tui.on_key(KeyCode::Char('q')));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn draws_list_and_preview() {
+        let path = env::temp_dir().join("text_converter_tui_draw.txt");
+        fs::write(&path, "Hello").unwrap();
+        let mut tui = Tui::new(registry(), TuiSource::File(path.clone())).unwrap();
+        fs::remove_file(path).unwrap();
+
+        let mut terminal = Terminal::new(TestBackend::new(60, 6)).unwrap();
+        terminal.draw(|frame| tui.draw(frame)).unwrap();
+
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("Converters"));
+        assert!(screen.contains("lower"));
+        assert!(screen.contains("upper"));
+        assert!(screen.contains("hello"));
+        assert!(screen.contains("Lowercase it"));
+        assert!(screen.contains("Enter apply"));
+    }
+}
@@ -0,0 +1,260 @@
+use crate::{ConversionError, ConverterRegistry, TextConverter};
+use std::{ffi::OsStr, fs, path::Path};
+use wasmtime::{Config, Engine, Instance, Module, Store, StoreLimits, StoreLimitsBuilder};
+
+/// Resources a [`WasmConverter`] may use for each conversion
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::WasmLimits;
+///
+/// let tight = WasmLimits {
+///     memory_bytes: 1 << 20,
+///     ..WasmLimits::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WasmLimits {
+    /// Largest the linear memory of the module may grow to, 16 MiB by default
+    pub memory_bytes: usize,
+    /// Fuel a conversion may burn, roughly one unit per instruction, which bounds how long it
+    /// runs. 1 billion by default
+    pub fuel: u64,
+}
+
+impl Default for WasmLimits {
+    fn default() -> Self {
+        Self {
+            memory_bytes: 16 << 20,
+            fuel: 1_000_000_000,
+        }
+    }
+}
+
+/// Converter compiled to WebAssembly, run in a sandbox
+///
+/// Enabled by the `wasm` feature. The module can only touch its own memory, does not get any
+/// import and is stopped once it goes over its [limits](WasmLimits), so it is safe to run
+/// converters that are not trusted. Every conversion runs in a fresh instance of the module.
+/// The module must export:
+///
+/// - `memory`, its linear memory
+/// - `alloc(len: i32) -> i32`, returning where to write an input of `len` bytes
+/// - `convert(ptr: i32, len: i32) -> (i32, i32)`, converting the UTF-8 input at `ptr` and
+///   returning where its UTF-8 output is and how long it is
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{TextConverter, WasmConverter};
+///
+/// // Returns the input unchanged
+/// let identity = WasmConverter::new(
+///     r#"(module
+///         (memory (export "memory") 1)
+///         (func (export "alloc") (param i32) (result i32) i32.const 0)
+///         (func (export "convert") (param i32 i32) (result i32 i32)
+///             local.get 0
+///             local.get 1))"#,
+/// )
+/// .unwrap();
+/// assert_eq!("same", identity.new_from_text("same"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct WasmConverter {
+    engine: Engine,
+    module: Module,
+    limits: WasmLimits,
+}
+
+struct State {
+    limits: StoreLimits,
+}
+
+impl WasmConverter {
+    /// Compiles a module from its binary format, or from its text format
+    ///
+    /// # Errors
+    /// [`ConversionError::Plugin`] if it is not a valid module
+    pub fn new(module: impl AsRef<[u8]>) -> Result<Self, ConversionError> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(wasm_error)?;
+        let module = Module::new(&engine, module).map_err(wasm_error)?;
+
+        Ok(Self {
+            engine,
+            module,
+            limits: WasmLimits::default(),
+        })
+    }
+
+    /// Compiles the module in the file at `path`
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if the file is inaccessible
+    /// - [`ConversionError::Plugin`] if it is not a valid module
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConversionError> {
+        Self::new(fs::read(path)?)
+    }
+
+    /// Runs conversions within `limits` instead of the default ones
+    pub fn limits(mut self, limits: WasmLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    fn run(&self, input: &str) -> Result<String, wasmtime::Error> {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(self.limits.memory_bytes)
+            .instances(1)
+            .build();
+        let mut store = Store::new(&self.engine, State { limits });
+        store.limiter(|state| &mut state.limits);
+        store.set_fuel(self.limits.fuel)?;
+
+        let instance = Instance::new(&mut store, &self.module, &[])?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| wasmtime::Error::msg("the module does not export its memory"))?;
+        let alloc = instance.get_typed_func::<i32, i32>(&mut store, "alloc")?;
+        let convert = instance.get_typed_func::<(i32, i32), (i32, i32)>(&mut store, "convert")?;
+
+        let len = i32::try_from(input.len())?;
+        let ptr = alloc.call(&mut store, len)?;
+        memory.write(&mut store, ptr as u32 as usize, input.as_bytes())?;
+
+        let (ptr, len) = convert.call(&mut store, (ptr, len))?;
+        let start = ptr as u32 as usize;
+        let output = start
+            .checked_add(len as u32 as usize)
+            .filter(|&end| end <= memory.data_size(&store))
+            .and_then(|end| memory.data(&store).get(start..end))
+            .ok_or_else(|| wasmtime::Error::msg("the output is out of the module memory"))?;
+
+        Ok(String::from_utf8(output.to_vec())?)
+    }
+}
+
+impl TextConverter for WasmConverter {
+    fn converter(&self, input: impl AsRef<str>) -> String {
+        self.try_converter(input)
+            .unwrap_or_else(|e| panic!("WebAssembly converter failed: {e}"))
+    }
+
+    fn try_converter(&self, input: impl AsRef<str>) -> Result<String, ConversionError> {
+        self.run(input.as_ref()).map_err(wasm_error)
+    }
+}
+
+impl ConverterRegistry {
+    /// Compiles every `.wasm` file in `dir` into a [`WasmConverter`] and registers it under
+    /// the file name without its extension, replacing any converter already registered under it
+    ///
+    /// Enabled by the `wasm` feature. The converters run with the default [`WasmLimits`]
+    ///
+    /// # Errors
+    /// - [`ConversionError::Io`] if `dir` or a module cannot be read
+    /// - [`ConversionError::Plugin`] if a file is not a valid module
+    ///
+    /// # Returns
+    /// Names of the converters registered, in alphabetical order
+    pub fn load_wasm_plugins(
+        &mut self,
+        dir: impl AsRef<Path>,
+    ) -> Result<Vec<String>, ConversionError> {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_file() && path.extension() == Some(OsStr::new("wasm")) {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut names = Vec::with_capacity(paths.len());
+        for path in paths {
+            let name = path
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned();
+            self.register(name.clone(), WasmConverter::from_file(&path)?);
+            names.push(name);
+        }
+
+        Ok(names)
+    }
+}
+
+fn wasm_error(e: wasmtime::Error) -> ConversionError {
+    ConversionError::Plugin(format!("{e:#}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Uppercases ASCII letters in place, then spins forever if the input starts with `!`
+    const UPPERCASE: &str = r#"(module
+        (memory (export "memory") 1)
+        (func (export "alloc") (param i32) (result i32) i32.const 16)
+        (func (export "convert") (param $ptr i32) (param $len i32) (result i32 i32)
+            (local $i i32)
+            (local $c i32)
+            (if (i32.eq (i32.load8_u (local.get $ptr)) (i32.const 33))
+                (then (loop $spin (br $spin))))
+            (block $done
+                (loop $next
+                    (br_if $done (i32.ge_u (local.get $i) (local.get $len)))
+                    (local.set $c (i32.load8_u (i32.add (local.get $ptr) (local.get $i))))
+                    (if (i32.and (i32.ge_u (local.get $c) (i32.const 97))
+                                 (i32.le_u (local.get $c) (i32.const 122)))
+                        (then (i32.store8 (i32.add (local.get $ptr) (local.get $i))
+                                          (i32.sub (local.get $c) (i32.const 32)))))
+                    (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                    (br $next)))
+            (local.get $ptr)
+            (local.get $len)))"#;
+
+    // Claims an output of 4 GiB, far more than its memory holds
+    const OVERSIZED: &str = r#"(module
+        (memory (export "memory") 1)
+        (func (export "alloc") (param i32) (result i32) i32.const 0)
+        (func (export "convert") (param i32 i32) (result i32 i32)
+            i32.const 0
+            i32.const -1))"#;
+
+    #[test]
+    fn runs_within_limits() {
+        let uppercase = WasmConverter::new(UPPERCASE).unwrap();
+        assert_eq!("HELLO, WORLD", uppercase.new_from_text("hello, world"));
+
+        let limited = uppercase.clone().limits(WasmLimits {
+            fuel: 10_000,
+            ..WasmLimits::default()
+        });
+        assert!(matches!(
+            limited.try_new_from_text("!forever"),
+            Err(ConversionError::Plugin(_))
+        ));
+        assert_eq!("STILL FINE", limited.new_from_text("still fine"));
+
+        let no_memory = uppercase.limits(WasmLimits {
+            memory_bytes: 0,
+            ..WasmLimits::default()
+        });
+        assert!(no_memory.try_new_from_text("hi").is_err());
+        assert!(WasmConverter::new("(module").is_err());
+    }
+
+    #[test]
+    fn rejects_output_out_of_memory() {
+        let oversized = WasmConverter::new(OVERSIZED).unwrap();
+        assert!(matches!(
+            oversized.try_new_from_text("hi"),
+            Err(ConversionError::Plugin(message)) if message.contains("out of the module memory")
+        ));
+    }
+}
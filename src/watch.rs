@@ -0,0 +1,220 @@
+//! Re-converting files whenever they change
+//!
+//! Enabled by the `notify` feature
+
+use crate::{trace, Bom, CancellationToken, ConversionError, OutputOptions, TextConverter};
+use notify::{event::ModifyKind, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::{
+    collections::VecDeque,
+    fs,
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    time::Duration,
+};
+
+/// How often a watch with a [`CancellationToken`] checks whether it was cancelled
+const CANCELLATION_POLL: Duration = Duration::from_millis(100);
+
+/// A conversion triggered by a change to a watched file
+#[derive(Debug, Clone)]
+pub struct WatchedConversion {
+    /// The file that changed
+    pub input: PathBuf,
+    /// The converted contents of the file
+    pub output: String,
+    /// Where the output was written to, if it was written at all
+    pub output_path: Option<PathBuf>,
+}
+
+/// Iterator over the conversions made while watching a file or directory, returned by
+/// [`TextConverter::watch_file`]
+///
+/// Each call to [`next`](Iterator::next) blocks until a watched file changes, converts it and
+/// writes the output. Failing to convert a file does not stop the watch, so the next change is
+/// picked up as usual. Watching stops when the iterator is dropped, or after yielding
+/// [`ConversionError::Cancelled`] once its [cancellation token](Self::cancellation()) is cancelled
+pub struct FileWatcher<'a, C: ?Sized> {
+    converter: &'a C,
+    target: PathBuf,
+    recursive: bool,
+    options: OutputOptions,
+    events: Receiver<notify::Result<Event>>,
+    pending: VecDeque<PathBuf>,
+    cancellation: Option<CancellationToken>,
+    stopped: bool,
+    // Kept alive for as long as events should keep coming
+    _watcher: RecommendedWatcher,
+}
+
+impl<'a, C: TextConverter + ?Sized> FileWatcher<'a, C> {
+    pub(crate) fn new(
+        converter: &'a C,
+        path: &Path,
+        options: OutputOptions,
+    ) -> Result<Self, ConversionError> {
+        let target = fs::canonicalize(path)?;
+        let recursive = target.is_dir();
+
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+
+        if recursive {
+            watcher.watch(&target, RecursiveMode::Recursive)?;
+        } else {
+            // Editors often save by replacing the file, which would end a watch on the file itself
+            let parent = target.parent().unwrap_or(&target);
+            watcher.watch(parent, RecursiveMode::NonRecursive)?;
+        }
+
+        Ok(Self {
+            converter,
+            target,
+            recursive,
+            options,
+            events,
+            pending: VecDeque::new(),
+            cancellation: None,
+            stopped: false,
+            _watcher: watcher,
+        })
+    }
+
+    /// Stops watching once `token` is cancelled, even while waiting for a change
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    fn is_watched(&self, path: &Path) -> bool {
+        if self.recursive {
+            path.starts_with(&self.target) && path.is_file() && !self.options.is_output(path)
+        } else {
+            path == self.target
+        }
+    }
+
+    fn convert(&self, input: PathBuf) -> Result<WatchedConversion, ConversionError> {
+        let bytes = trace::read_file(&input)?;
+        let (text, bom) = Bom::decode(&bytes)?;
+        let output = self.converter.try_converter(text)?;
+        let output_path = self.options.write_text(&input, &output, bom)?;
+
+        Ok(WatchedConversion {
+            input,
+            output,
+            output_path,
+        })
+    }
+}
+
+impl<C: TextConverter + ?Sized> Iterator for FileWatcher<'_, C> {
+    type Item = Result<WatchedConversion, ConversionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stopped {
+            return None;
+        }
+
+        loop {
+            if self
+                .cancellation
+                .as_ref()
+                .is_some_and(CancellationToken::is_cancelled)
+            {
+                self.stopped = true;
+                return Some(Err(ConversionError::Cancelled));
+            }
+
+            if let Some(path) = self.pending.pop_front() {
+                return Some(self.convert(path));
+            }
+
+            let event = match self.cancellation {
+                Some(_) => match self.events.recv_timeout(CANCELLATION_POLL) {
+                    Ok(event) => event,
+                    Err(RecvTimeoutError::Timeout) => continue,
+                    Err(RecvTimeoutError::Disconnected) => return None,
+                },
+                None => self.events.recv().ok()?,
+            };
+
+            match event {
+                Ok(event) if is_change(&event.kind) => {
+                    for path in event.paths {
+                        if self.is_watched(&path) && !self.pending.contains(&path) {
+                            self.pending.push_back(path);
+                        }
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+    }
+}
+
+fn is_change(kind: &EventKind) -> bool {
+    match kind {
+        EventKind::Create(_) => true,
+        EventKind::Modify(modify) => !matches!(modify, ModifyKind::Metadata(_)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, Uppercase};
+    use std::{thread, time::Duration};
+
+    #[test]
+    fn converts_on_change() {
+        let dir = fixtures::temp_dir("watch");
+        let input = dir.join("notes.txt");
+        fs::write(&input, "").unwrap();
+
+        let mut watcher = Uppercase.watch_file(&dir, &OutputOptions::new()).unwrap();
+
+        let writer = {
+            let input = input.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(100));
+                fs::write(input, "hello").unwrap();
+            })
+        };
+
+        // Skips the events for writes that happened before the contents were in place
+        let conversion = watcher
+            .find(|conversion| conversion.as_ref().is_ok_and(|c| !c.output.is_empty()))
+            .unwrap()
+            .unwrap();
+        writer.join().unwrap();
+
+        assert_eq!(fs::canonicalize(&input).unwrap(), conversion.input);
+        assert_eq!("HELLO", conversion.output);
+        let output_path = conversion.output_path.unwrap();
+        assert_eq!("HELLO", fs::read_to_string(output_path).unwrap());
+    }
+
+    #[test]
+    fn stops_when_cancelled() {
+        let dir = fixtures::temp_dir("watch_cancel");
+
+        let token = CancellationToken::new();
+        let mut watcher = Uppercase
+            .watch_file(&dir, &OutputOptions::new())
+            .unwrap()
+            .cancellation(token.clone());
+
+        let canceller = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            token.cancel();
+        });
+        assert!(matches!(
+            watcher.next(),
+            Some(Err(ConversionError::Cancelled))
+        ));
+        assert!(watcher.next().is_none());
+        canceller.join().unwrap();
+    }
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "cli")]
+
+use std::{
+    env, fs,
+    path::PathBuf,
+    process::{self, Command},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Fresh directory for one test, unique across concurrent test runs
+fn temp_dir(name: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos());
+    let dir = env::temp_dir().join(format!(
+        "text_converter_cli_{name}_{}_{nanos}",
+        process::id()
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn lines_streams_files() {
+    let dir = temp_dir("lines");
+    let path = dir.join("input.txt");
+    fs::write(&path, "first line\r\nsecond\nlast").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_tconv"))
+        .args(["reverse", "--lines"])
+        .arg(&path)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{output:?}");
+    // Converted one line at a time, so the lines keep their order and endings
+    assert_eq!(b"enil tsrif\r\ndnoces\ntsal", output.stdout.as_slice());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn lines_rejects_options_it_ignores() {
+    for option in ["--verbose", "--html", "--primary"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_tconv"))
+            .args(["reverse", "--lines", option])
+            .output()
+            .unwrap();
+        assert_eq!(Some(2), output.status.code(), "{option}: {output:?}");
+    }
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn lines_rejects_urls() {
+    let output = Command::new(env!("CARGO_BIN_EXE_tconv"))
+        .args(["reverse", "--url", "http://localhost/", "--lines"])
+        .output()
+        .unwrap();
+
+    assert_eq!(Some(2), output.status.code(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("cannot be used with"));
+}
@@ -0,0 +1,189 @@
+//! Attribute macro for defining [`text_converter`](https://docs.rs/text_converter) converters
+//! from plain functions
+//!
+//! Use it through the `derive` feature of `text_converter`, which re-exports it
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, spanned::Spanned, Expr, ExprLit, ItemFn, Lit, LitStr, Meta, ReturnType, Type,
+};
+
+/// Turns a `fn(&str) -> String` into a zero-sized converter type implementing `TextConverter`
+///
+/// The type is named after the function in `PascalCase` and gets two associated constants, `NAME`
+/// (the function name in `kebab-case`, or the one given with `#[converter(name = "...")]`) and
+/// `DESCRIPTION` (the function's doc comment), for registering it under a name. The function
+/// itself is kept as it is
+///
+/// Functions returning a `Result` implement `try_converter` instead, with `converter` panicking on
+/// errors. The error type has to convert into `ConversionError`
+///
+/// # Examples
+///
+/// ```
+/// use text_converter::{converter, ConverterRegistry, TextConverter};
+///
+/// /// Shouts the input
+/// #[converter]
+/// fn shout(input: &str) -> String {
+///     input.to_uppercase() + "!"
+/// }
+///
+/// assert_eq!("HEY!", Shout.new_from_text("hey"));
+/// assert_eq!("shout", Shout::NAME);
+/// assert_eq!("Shouts the input", Shout::DESCRIPTION);
+///
+/// let mut registry = ConverterRegistry::new();
+/// registry.register(Shout::NAME, Shout);
+/// ```
+///
+/// ```
+/// use text_converter::{converter, ConversionError, TextConverter};
+///
+/// #[converter(name = "strict-ascii")]
+/// fn ascii_only(input: &str) -> Result<String, ConversionError> {
+///     match input.is_ascii() {
+///         true => Ok(input.to_owned()),
+///         false => Err(ConversionError::InvalidInput("input is not ASCII".to_owned())),
+///     }
+/// }
+///
+/// assert_eq!("strict-ascii", AsciiOnly::NAME);
+/// assert!(AsciiOnly.try_new_from_text("héllo").is_err());
+/// ```
+#[proc_macro_attribute]
+pub fn converter(args: TokenStream, item: TokenStream) -> TokenStream {
+    let function = parse_macro_input!(item as ItemFn);
+
+    let mut name = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("name") {
+            name = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
+        } else {
+            Err(meta.error("unsupported converter argument, expected `name`"))
+        }
+    });
+    parse_macro_input!(args with parser);
+
+    expand(function, name)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(function: ItemFn, name: Option<String>) -> syn::Result<proc_macro2::TokenStream> {
+    let signature = &function.sig;
+    if signature.inputs.len() != 1 {
+        return Err(syn::Error::new(
+            signature.inputs.span(),
+            "converter functions take exactly one `&str` argument",
+        ));
+    }
+    if !signature.generics.params.is_empty() || signature.asyncness.is_some() {
+        return Err(syn::Error::new(
+            signature.span(),
+            "converter functions cannot be generic or async",
+        ));
+    }
+
+    let function_name = &signature.ident;
+    let fn_name = function_name.to_string();
+    let fn_name = fn_name.trim_start_matches("r#");
+    let type_name = format_ident!("{}", pascal_case(fn_name), span = function_name.span());
+    let name = name.unwrap_or_else(|| fn_name.replace('_', "-"));
+    let description = doc_comment(&function);
+    let docs = function
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"));
+    let vis = &function.vis;
+
+    let methods = if returns_result(&signature.output) {
+        quote! {
+            fn converter(&self, input: impl ::core::convert::AsRef<str>) -> ::std::string::String {
+                ::text_converter::TextConverter::try_converter(self, input)
+                    .unwrap_or_else(|e| panic!("{}", e))
+            }
+
+            fn try_converter(
+                &self,
+                input: impl ::core::convert::AsRef<str>,
+            ) -> ::core::result::Result<::std::string::String, ::text_converter::ConversionError> {
+                #function_name(input.as_ref()).map_err(::core::convert::Into::into)
+            }
+        }
+    } else {
+        quote! {
+            fn converter(&self, input: impl ::core::convert::AsRef<str>) -> ::std::string::String {
+                #function_name(input.as_ref())
+            }
+        }
+    };
+
+    Ok(quote! {
+        #function
+
+        #(#docs)*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        #vis struct #type_name;
+
+        impl #type_name {
+            /// Name to register the converter under
+            pub const NAME: &'static str = #name;
+            /// Description of the converter, taken from its doc comment
+            pub const DESCRIPTION: &'static str = #description;
+        }
+
+        impl ::text_converter::TextConverter for #type_name {
+            #methods
+        }
+    })
+}
+
+fn pascal_case(snake_case: &str) -> String {
+    snake_case
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn doc_comment(function: &ItemFn) -> LitStr {
+    let lines: Vec<_> = function
+        .attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(doc) if doc.path.is_ident("doc") => match &doc.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(line),
+                    ..
+                }) => Some(line.value().trim().to_owned()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    LitStr::new(lines.join("\n").trim(), Span::call_site())
+}
+
+fn returns_result(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Type(_, ty) => match ty.as_ref() {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Result"),
+            _ => false,
+        },
+        ReturnType::Default => false,
+    }
+}